@@ -2,11 +2,15 @@ use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
 
 /// Configuration for a single Docker host
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
 pub struct HostConfig {
     /// Docker host connection string (e.g., "local", "ssh://user@host")
     pub host: String,
 
+    /// Optional name shown for this host instead of its ID (e.g. "prod")
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
     /// Optional Dozzle URL for this host
     #[serde(skip_serializing_if = "Option::is_none")]
     pub dozzle: Option<String>,
@@ -14,9 +18,34 @@ pub struct HostConfig {
     /// Optional filters for this host (e.g., ["status=running", "name=nginx"])
     #[serde(skip_serializing_if = "Option::is_none")]
     pub filter: Option<Vec<String>>,
+
+    /// Optional minimum interval between stats updates for this host's containers,
+    /// in milliseconds (e.g. to be gentle on slow remote links)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub refresh_interval_ms: Option<u64>,
+
+    /// Ask for confirmation before opening a shell in this host's containers
+    /// (e.g. for production hosts)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confirm_shell: Option<bool>,
+
+    /// Shell to run in this host's containers (e.g. "ash" or "/busybox/sh") instead
+    /// of bash, falling back to sh
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shell: Option<String>,
+
+    /// Client certificate for a `tls://` host (default: `cert.pem` in `DOCKER_CERT_PATH`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cert_path: Option<PathBuf>,
+
+    /// Client private key for a `tls://` host (default: `key.pem` in `DOCKER_CERT_PATH`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub key_path: Option<PathBuf>,
+
+    /// CA certificate for a `tls://` host (default: `ca.pem` in `DOCKER_CERT_PATH`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ca_path: Option<PathBuf>,
     // Future fields can be added here as optional fields
-    // #[serde(skip_serializing_if = "Option::is_none")]
-    // pub custom_name: Option<String>,
 }
 
 /// Configuration that can be loaded from a YAML file
@@ -45,9 +74,193 @@ pub struct Config {
     /// Visible columns in order
     #[serde(skip_serializing_if = "Option::is_none")]
     pub columns: Option<Vec<String>>,
+
+    /// How memory usage is calculated: "docker" (default, matches `docker stats`)
+    /// or "rss" (resident memory only, excludes all page cache)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub memory_mode: Option<String>,
+
+    /// How stats are collected: "stream" (default, one open connection per container)
+    /// or "poll" (one request per container every refresh interval)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stats_mode: Option<String>,
+
+    /// Quit when every host has disconnected instead of showing stale data (default: false)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quit_on_all_disconnected: Option<bool>,
+
+    /// Collect container sizes (`docker ps --size`) for the Size column (default: false)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub container_size: Option<bool>,
+
+    /// Request logs with the logging driver's attributes (labels, env) and
+    /// show them before each line (default: false)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub log_details: Option<bool>,
+
+    /// Show a one-line key legend below the container list (default: false)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub show_footer: Option<bool>,
+
+    /// Maximum width of the Name column in characters (default: unlimited)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_name_width: Option<u16>,
+
+    /// Which count comes first in the table title: "running" (default) or "total"
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title_count: Option<String>,
+
+    /// Show CPU and memory totals across all hosts in the table title (default: false)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub show_fleet_totals: Option<bool>,
+
+    /// Keep showing stopped containers' last CPU, memory and network values, dimmed
+    /// (default: false)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub show_last_stats: Option<bool>,
+
+    /// Match every search query as a case-insensitive regex, not only ones
+    /// starting with '/' (default: false)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub search_regex: Option<bool>,
+
+    /// Show a line summing CPU, memory and network below the table (default: true)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub show_summary: Option<bool>,
+
+    /// Ask before removing a container from the action menu (default: true)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confirm_destructive: Option<bool>,
+
+    /// Seconds without stats after which a host with running containers is
+    /// marked stale; 0 disables (default: 30)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stale_after_secs: Option<u64>,
+
+    /// How the Created column shows times: "relative" (default, "2 hours ago")
+    /// or "absolute" ("Oct 16 14:03")
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_format: Option<String>,
+
+    /// Time zone for log timestamps and absolute Created times: "local" (default) or "utc"
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timezone: Option<String>,
+
+    /// Set the terminal window title to "dtop — N containers" (default: false)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub terminal_title: Option<bool>,
+
+    /// Number the container list rows, for jumping with digits + Enter (default: false)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub row_numbers: Option<bool>,
+
+    /// Group multi-host lists by host with a separator row between hosts (default: false)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub host_separators: Option<bool>,
+
+    /// Most log lines kept in the log view before the oldest are dropped (default: 10000)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub log_max_lines: Option<usize>,
+
+    /// CPU/memory samples kept per container for the inspect view's sparklines (default: 60)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stats_history: Option<usize>,
+
+    /// Show each log line's timestamp in the log view (default: true)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub log_timestamps: Option<bool>,
+
+    /// Wrap long log lines in the log view (default: true)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub log_wrap: Option<bool>,
+
+    /// Show line numbers in the log view (default: false)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub log_line_numbers: Option<bool>,
+
+    /// How often the UI redraws, in milliseconds (default: 500, clamped to 100-5000)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub refresh_ms: Option<u64>,
+
+    /// Seconds a connection error stays on screen (default: 10)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub notification_ttl_secs: Option<u64>,
+
+    /// Capture the mouse for clicking column headers (default: true).
+    /// Disable to select and copy text with the terminal instead.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mouse: Option<bool>,
+
+    /// Lines scrolled per mouse wheel notch in lists and logs (default: 3)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scroll_lines: Option<usize>,
+
+    /// Directory to save a transcript of each shell session to (default: none)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shell_transcript_dir: Option<std::path::PathBuf>,
+
+    /// Extra commands listed in the action menu of running containers
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_actions: Option<Vec<crate::core::types::CustomAction>>,
+
+    /// CPU % above which usage turns yellow/red (default: warn 50, critical 80)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cpu_thresholds: Option<crate::core::types::Thresholds>,
+
+    /// Memory % above which usage turns yellow/red (default: warn 50, critical 80)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub memory_thresholds: Option<crate::core::types::Thresholds>,
+
+    /// Color overrides for the UI (default: built-in colors)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub theme: Option<ThemeConfig>,
 }
 
+/// UI color overrides. Each color is a name ("red", "lightblue"), a 256-color
+/// index ("208") or hex ("#ff8700"); unset colors keep their defaults.
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(deny_unknown_fields)]
+pub struct ThemeConfig {
+    /// High CPU/memory usage
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub high: Option<String>,
+
+    /// Medium CPU/memory usage
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub medium: Option<String>,
+
+    /// Low CPU/memory usage
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub low: Option<String>,
+
+    /// Table headers and titles
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub header: Option<String>,
+
+    /// Borders
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub border: Option<String>,
+
+    /// Background of the selected row
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub selected: Option<String>,
+}
+
+/// UI redraw interval when `refresh_ms` is unset
+const DEFAULT_REFRESH_MS: u64 = 500;
+/// Bounds for `refresh_ms`: faster burns CPU, slower makes the UI feel stuck
+const REFRESH_MS_RANGE: std::ops::RangeInclusive<u64> = 100..=5000;
+
 impl Config {
+    /// Returns the UI redraw interval from `refresh_ms`, clamped to a sane range
+    pub fn draw_interval(&self) -> std::time::Duration {
+        let ms = self
+            .refresh_ms
+            .unwrap_or(DEFAULT_REFRESH_MS)
+            .clamp(*REFRESH_MS_RANGE.start(), *REFRESH_MS_RANGE.end());
+        std::time::Duration::from_millis(ms)
+    }
+
     /// Find and load config file from the following locations (in priority order):
     /// 1. ./config.yaml or ./config.yml
     /// 2. ./.dtop.yaml or ./.dtop.yml
@@ -116,6 +329,7 @@ impl Config {
                     } else {
                         Some(cli_filters.clone())
                     },
+                    ..Default::default()
                 })
                 .collect();
         } else if !cli_filters.is_empty() {
@@ -153,19 +367,30 @@ mod tests {
         assert!(config.hosts.is_empty());
     }
 
+    #[test]
+    fn test_draw_interval_is_clamped() {
+        let interval = |refresh_ms| {
+            Config {
+                refresh_ms,
+                ..Default::default()
+            }
+            .draw_interval()
+            .as_millis()
+        };
+        assert_eq!(interval(None), 500);
+        assert_eq!(interval(Some(250)), 250);
+        assert_eq!(interval(Some(10)), 100);
+        assert_eq!(interval(Some(60_000)), 5000);
+    }
+
     #[test]
     fn test_merge_with_cli_hosts_uses_cli_when_provided() {
         let config = Config {
             hosts: vec![HostConfig {
                 host: "ssh://user@server1".to_string(),
-                dozzle: None,
-                filter: None,
+                ..Default::default()
             }],
-            icons: None,
-            all: None,
-            sort: None,
-            sort_direction: None,
-            columns: None,
+            ..Default::default()
         };
 
         let merged = config.merge_with_cli_hosts(
@@ -185,13 +410,9 @@ mod tests {
             hosts: vec![HostConfig {
                 host: "ssh://user@server1".to_string(),
                 dozzle: Some("https://dozzle.example.com".to_string()),
-                filter: None,
+                ..Default::default()
             }],
-            icons: None,
-            all: None,
-            sort: None,
-            sort_direction: None,
-            columns: None,
+            ..Default::default()
         };
 
         let merged =
@@ -209,11 +430,7 @@ mod tests {
     fn test_merge_with_cli_hosts_defaults_to_local() {
         let config = Config {
             hosts: vec![],
-            icons: None,
-            all: None,
-            sort: None,
-            sort_direction: None,
-            columns: None,
+            ..Default::default()
         };
 
         let merged =
@@ -257,12 +474,53 @@ hosts:
         assert_eq!(config.hosts[1].dozzle, None);
     }
 
+    #[test]
+    fn test_yaml_deserialization_with_refresh_interval() {
+        let yaml = r#"
+hosts:
+  - host: ssh://user@slow-link
+    refresh_interval_ms: 5000
+  - host: local
+"#;
+        let config: Config = serde_yaml::from_str(yaml).unwrap();
+        assert_eq!(config.hosts[0].refresh_interval_ms, Some(5000));
+        assert_eq!(config.hosts[1].refresh_interval_ms, None);
+    }
+
+    #[test]
+    fn test_yaml_deserialization_with_shell() {
+        let yaml = r#"
+hosts:
+  - host: tcp://alpine-box:2375
+    shell: /bin/ash
+  - host: local
+"#;
+        let config: Config = serde_yaml::from_str(yaml).unwrap();
+        assert_eq!(config.hosts[0].shell.as_deref(), Some("/bin/ash"));
+        assert_eq!(config.hosts[1].shell, None);
+    }
+
+    #[test]
+    fn test_yaml_deserialization_with_custom_actions() {
+        let yaml = r#"
+hosts:
+  - host: local
+custom_actions:
+  - label: Tail access log
+    command: tail -n 50 /var/log/nginx/access.log
+"#;
+        let config: Config = serde_yaml::from_str(yaml).unwrap();
+        let actions = config.custom_actions.unwrap();
+        assert_eq!(actions.len(), 1);
+        assert_eq!(actions[0].label, "Tail access log");
+        assert_eq!(actions[0].command, "tail -n 50 /var/log/nginx/access.log");
+    }
+
     #[test]
     fn test_host_config_without_dozzle() {
         let host = HostConfig {
             host: "local".to_string(),
-            dozzle: None,
-            filter: None,
+            ..Default::default()
         };
         assert_eq!(host.host, "local");
         assert_eq!(host.dozzle, None);
@@ -274,7 +532,7 @@ hosts:
         let host = HostConfig {
             host: "ssh://user@host".to_string(),
             dozzle: Some("https://dozzle.example.com".to_string()),
-            filter: None,
+            ..Default::default()
         };
         assert_eq!(host.host, "ssh://user@host");
         assert_eq!(host.dozzle.as_deref(), Some("https://dozzle.example.com"));
@@ -285,14 +543,10 @@ hosts:
         let config = Config {
             hosts: vec![HostConfig {
                 host: "local".to_string(),
-                dozzle: None,
                 filter: Some(vec!["status=running".to_string()]),
+                ..Default::default()
             }],
-            icons: None,
-            all: None,
-            sort: None,
-            sort_direction: None,
-            columns: None,
+            ..Default::default()
         };
 
         let cli_filters = vec!["name=nginx".to_string()];
@@ -310,14 +564,10 @@ hosts:
         let config = Config {
             hosts: vec![HostConfig {
                 host: "local".to_string(),
-                dozzle: None,
                 filter: Some(vec!["status=running".to_string()]),
+                ..Default::default()
             }],
-            icons: None,
-            all: None,
-            sort: None,
-            sort_direction: None,
-            columns: None,
+            ..Default::default()
         };
 
         let merged =
@@ -334,14 +584,10 @@ hosts:
         let config = Config {
             hosts: vec![HostConfig {
                 host: "local".to_string(),
-                dozzle: None,
-                filter: None,
+                ..Default::default()
             }],
-            icons: None,
             all: Some(false), // Config says false
-            sort: None,
-            sort_direction: None,
-            columns: None,
+            ..Default::default()
         };
 
         let merged =
@@ -354,14 +600,10 @@ hosts:
         let config = Config {
             hosts: vec![HostConfig {
                 host: "local".to_string(),
-                dozzle: None,
-                filter: None,
+                ..Default::default()
             }],
-            icons: None,
             all: Some(true), // Config says true
-            sort: None,
-            sort_direction: None,
-            columns: None,
+            ..Default::default()
         };
 
         let merged =
@@ -374,14 +616,10 @@ hosts:
         let config = Config {
             hosts: vec![HostConfig {
                 host: "local".to_string(),
-                dozzle: None,
-                filter: None,
+                ..Default::default()
             }],
-            icons: None,
             all: None, // No config value
-            sort: None,
-            sort_direction: None,
-            columns: None,
+            ..Default::default()
         };
 
         let merged =
@@ -394,14 +632,10 @@ hosts:
         let config = Config {
             hosts: vec![HostConfig {
                 host: "local".to_string(),
-                dozzle: None,
-                filter: None,
+                ..Default::default()
             }],
-            icons: None,
-            all: None,
             sort: Some("name".to_string()), // Config says name
-            sort_direction: None,
-            columns: None,
+            ..Default::default()
         };
 
         let merged = config.merge_with_cli_hosts(
@@ -419,14 +653,10 @@ hosts:
         let config = Config {
             hosts: vec![HostConfig {
                 host: "local".to_string(),
-                dozzle: None,
-                filter: None,
+                ..Default::default()
             }],
-            icons: None,
-            all: None,
             sort: Some("memory".to_string()), // Config says memory
-            sort_direction: None,
-            columns: None,
+            ..Default::default()
         };
 
         let merged =
@@ -478,14 +708,10 @@ hosts:
         let config = Config {
             hosts: vec![HostConfig {
                 host: "local".to_string(),
-                dozzle: None,
-                filter: None,
+                ..Default::default()
             }],
-            icons: None,
-            all: None,
-            sort: None,
-            sort_direction: None,
             columns: Some(vec!["name".to_string(), "cpu".to_string()]),
+            ..Default::default()
         };
         let yaml = serde_yaml::to_string(&config).unwrap();
         assert!(yaml.contains("columns:"));
@@ -511,17 +737,48 @@ sort_direction: asc
         let config = Config {
             hosts: vec![HostConfig {
                 host: "local".to_string(),
-                dozzle: None,
-                filter: None,
+                ..Default::default()
             }],
-            icons: None,
-            all: None,
             sort: Some("memory".to_string()),
             sort_direction: Some("desc".to_string()),
-            columns: None,
+            ..Default::default()
         };
         let yaml = serde_yaml::to_string(&config).unwrap();
         assert!(yaml.contains("sort: memory"));
         assert!(yaml.contains("sort_direction: desc"));
     }
+
+    #[test]
+    fn test_yaml_theme() {
+        use crate::ui::icons::IconStyle;
+        use crate::ui::render::UiStyles;
+        use ratatui::style::Color;
+
+        let yaml = r##"
+theme:
+  high: magenta
+  header: "#ff8700"
+  selected: "24"
+"##;
+        let config: Config = serde_yaml::from_str(yaml).unwrap();
+        let theme = config.theme.unwrap();
+        let styles = UiStyles::with_theme(IconStyle::Unicode, &theme).unwrap();
+        assert_eq!(styles.high.fg, Some(Color::Magenta));
+        assert_eq!(styles.header.fg, Some(Color::Rgb(0xff, 0x87, 0x00)));
+        assert_eq!(styles.selected.bg, Some(Color::Indexed(24)));
+        // Unset colors keep their defaults
+        assert_eq!(styles.low.fg, Some(Color::Green));
+
+        let bad = ThemeConfig {
+            medium: Some("not-a-color".to_string()),
+            ..Default::default()
+        };
+        let err = UiStyles::with_theme(IconStyle::Unicode, &bad)
+            .err()
+            .unwrap();
+        assert!(err.contains("'not-a-color' for theme.medium"));
+
+        // Misspelled keys are rejected rather than silently ignored
+        assert!(serde_yaml::from_str::<Config>("theme:\n  hihg: red\n").is_err());
+    }
 }
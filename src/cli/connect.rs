@@ -5,135 +5,62 @@ use url::Url;
 
 use crate::cli::config::{Config, HostConfig};
 use crate::cli::filters::parse_filters;
-use crate::core::types::AppEvent;
-use crate::docker::connection::{DockerHost, connect_docker, container_manager};
-
-/// Result of establishing connections to Docker hosts
-pub struct ConnectionResult {
-    /// The first successfully connected host
-    pub first_host: DockerHost,
-    /// Receiver for additional hosts that connect after the first
-    pub remaining_rx: mpsc::Receiver<DockerHost>,
-}
+use crate::core::types::{AppEvent, MemoryMode, StatsMode};
+use crate::docker::connection::{DockerHost, TlsFiles, connect_docker, container_manager};
+
+/// Connects to all configured Docker hosts in parallel, in the background.
+/// Each host reports back through the event channel as it finishes: `HostConnected`
+/// (and its container manager is started) or `ConnectionError`. The UI shows their
+/// progress until the first containers arrive.
+pub fn spawn_connections(config: &Config, event_tx: mpsc::Sender<AppEvent>) {
+    let memory_mode = match config.memory_mode.as_deref() {
+        Some(mode) => mode.parse().unwrap_or_else(|_| {
+            tracing::warn!("Unknown memory_mode '{}', using 'docker'", mode);
+            MemoryMode::default()
+        }),
+        None => MemoryMode::default(),
+    };
 
-/// Establishes connections to all configured Docker hosts in parallel.
-/// Returns as soon as the first host connects successfully.
-/// Remaining connections continue in the background.
-pub async fn establish_connections(
-    config: &Config,
-    event_tx: mpsc::Sender<AppEvent>,
-) -> Result<ConnectionResult, Box<dyn std::error::Error>> {
-    let total_hosts = config.hosts.len();
-
-    // Create a channel for receiving successful connections
-    let (conn_tx, mut conn_rx) = mpsc::channel::<DockerHost>(total_hosts);
-
-    // Spawn all connection attempts in parallel
-    let connection_handles: Vec<_> = config
-        .hosts
-        .iter()
-        .map(|host_config| {
-            let host_config = host_config.clone();
-            let conn_tx = conn_tx.clone();
-            let error_tx = event_tx.clone();
-
-            tokio::spawn(async move {
-                match connect_and_verify_host(&host_config).await {
-                    Ok(docker_host) => {
-                        let _ = conn_tx.send(docker_host).await;
-                    }
-                    Err(e) => {
-                        use tracing::error;
-                        error!("{}", e);
-
-                        // Create host_id for the error event
-                        let host_id = create_host_id(&host_config.host);
-
-                        // Send error event to UI
-                        let _ = error_tx
-                            .send(AppEvent::ConnectionError(host_id, e.clone()))
-                            .await;
-
-                        if total_hosts == 1 {
-                            eprintln!("Failed to connect to Docker host: {:?}", e);
-                        }
-                    }
-                }
-            })
+    // Left unset, each host picks a mode once it knows its container count
+    let stats_mode = config.stats_mode.as_deref().map(|mode| {
+        mode.parse().unwrap_or_else(|_| {
+            tracing::warn!("Unknown stats_mode '{}', using 'stream'", mode);
+            StatsMode::default()
         })
-        .collect();
-
-    // Drop the original sender so the channel closes when all tasks complete
-    drop(conn_tx);
+    });
 
-    // Try to get the first connection with a reasonable timeout
-    let first_host = match tokio::time::timeout(Duration::from_secs(30), conn_rx.recv()).await {
-        Ok(Some(docker_host)) => {
-            use tracing::debug;
+    let container_size = config.container_size.unwrap_or(false);
+    let log_details = config.log_details.unwrap_or(false);
 
-            if total_hosts > 1 {
-                debug!("Connected to host 1/{}, starting UI...", total_hosts);
-            }
+    for host_config in &config.hosts {
+        let host_config = host_config.clone();
+        let event_tx = event_tx.clone();
 
-            docker_host
-        }
-        Ok(None) => {
-            // Channel closed without any connections
-            return Err("Failed to connect to any Docker hosts. Please check your configuration and connection settings. Set DEBUG=1 to see detailed logs in debug.log".into());
-        }
-        Err(_) => {
-            // Timeout waiting for first connection
-            return Err("Timeout waiting for Docker host connections (30s). Please check your network and Docker daemon status.".into());
-        }
-    };
+        tokio::spawn(async move {
+            match connect_and_verify_host(&host_config).await {
+                Ok(mut docker_host) => {
+                    docker_host.memory_mode = memory_mode;
+                    docker_host.stats_mode = stats_mode;
+                    docker_host.container_size = container_size;
+                    docker_host.log_details = log_details;
 
-    // Create a new channel to forward remaining connections
-    let (remaining_tx, remaining_rx) = mpsc::channel::<DockerHost>(total_hosts);
+                    // Send HostConnected event so AppState can track this host for log streaming
+                    let _ = event_tx
+                        .send(AppEvent::HostConnected(docker_host.clone()))
+                        .await;
 
-    // Spawn task to collect remaining connections and forward them
-    tokio::spawn(async move {
-        use tracing::debug;
-        let mut remaining_count = 1; // Already got one
+                    container_manager(docker_host, event_tx).await;
+                }
+                Err(e) => {
+                    tracing::error!("{}", e);
 
-        while let Some(docker_host) = conn_rx.recv().await {
-            let _ = remaining_tx.send(docker_host).await;
-            remaining_count += 1;
-            if total_hosts > 1 {
-                debug!("Connected to host {}/{}", remaining_count, total_hosts);
+                    // Send error event to UI
+                    let host_id = create_host_id(&host_config.host);
+                    let _ = event_tx.send(AppEvent::ConnectionError(host_id, e)).await;
+                }
             }
-        }
-
-        // Wait for all connection attempts to complete
-        for handle in connection_handles {
-            let _ = handle.await;
-        }
-    });
-
-    Ok(ConnectionResult {
-        first_host,
-        remaining_rx,
-    })
-}
-
-/// Spawns background task to handle remaining host connections
-pub fn spawn_remaining_connections_handler(
-    mut remaining_rx: mpsc::Receiver<DockerHost>,
-    event_tx: mpsc::Sender<AppEvent>,
-) {
-    tokio::spawn(async move {
-        while let Some(docker_host) = remaining_rx.recv().await {
-            // Send HostConnected event so AppState can track this host for log streaming
-            let _ = event_tx
-                .send(AppEvent::HostConnected(docker_host.clone()))
-                .await;
-
-            // Spawn container manager for this host
-            let tx_clone = event_tx.clone();
-            tokio::spawn(async move {
-                container_manager(docker_host, tx_clone).await;
-            });
-        }
-    });
+        });
+    }
 }
 
 /// Connects to a Docker host and verifies the connection works
@@ -146,7 +73,12 @@ pub async fn connect_and_verify_host(host_config: &HostConfig) -> Result<DockerH
     debug!("Attempting to connect to host: {}", host_spec);
 
     // Attempt to connect
-    let docker = connect_docker(host_spec).map_err(|e| {
+    let tls = TlsFiles {
+        cert: host_config.cert_path.clone(),
+        key: host_config.key_path.clone(),
+        ca: host_config.ca_path.clone(),
+    };
+    let docker = connect_docker(host_spec, &tls).map_err(|e| {
         format!(
             "Failed to create Docker client for host '{}': {}",
             host_spec, e
@@ -165,7 +97,12 @@ pub async fn connect_and_verify_host(host_config: &HostConfig) -> Result<DockerH
 
     // Create host ID and DockerHost instance
     let host_id = create_host_id(host_spec);
-    let docker_host = DockerHost::new(host_id, docker, host_config.dozzle.clone(), filters);
+    let mut docker_host = DockerHost::new(host_id, docker, host_config.dozzle.clone(), filters);
+    docker_host.refresh_interval = host_config.refresh_interval_ms.map(Duration::from_millis);
+    docker_host.name = host_config.name.clone();
+    docker_host.confirm_shell = host_config.confirm_shell.unwrap_or(false);
+    docker_host.shell = host_config.shell.clone();
+    docker_host.is_ssh = host_spec.starts_with("ssh://");
 
     // Verify the connection actually works by pinging Docker with timeout
     debug!("Pinging Docker daemon at host: {}", host_spec);
@@ -174,6 +111,7 @@ pub async fn connect_and_verify_host(host_config: &HostConfig) -> Result<DockerH
     match tokio::time::timeout(ping_timeout, docker_host.docker.ping()).await {
         Ok(Ok(_)) => {
             debug!("Successfully pinged Docker daemon at host: {}", host_spec);
+            negotiate_api_version(&mut docker_host, ping_timeout).await;
             Ok(docker_host)
         }
         Ok(Err(e)) => {
@@ -198,6 +136,36 @@ pub async fn connect_and_verify_host(host_config: &HostConfig) -> Result<DockerH
     }
 }
 
+/// Agrees an API version with the daemon, downgrading the client for older
+/// daemons that would otherwise reject its requests. Failure isn't fatal: the
+/// host keeps bollard's default version, as before negotiation existed.
+async fn negotiate_api_version(docker_host: &mut DockerHost, timeout: Duration) {
+    // The client shares its version between clones, so negotiating on a clone
+    // updates `docker_host.docker` too
+    let negotiated = tokio::time::timeout(timeout, docker_host.docker.clone().negotiate_version());
+    match negotiated.await {
+        Ok(Ok(docker)) => {
+            let version = docker.client_version();
+            let version = format!("{}.{}", version.major_version, version.minor_version);
+            tracing::debug!(
+                "Using API version {} for host {}",
+                version,
+                docker_host.host_id
+            );
+            docker_host.api_version = Some(version);
+        }
+        Ok(Err(e)) => tracing::warn!(
+            "API version negotiation failed for host {}: {}",
+            docker_host.host_id,
+            e
+        ),
+        Err(_) => tracing::warn!(
+            "API version negotiation timed out for host {}",
+            docker_host.host_id
+        ),
+    }
+}
+
 /// Creates a unique host identifier from the host specification.
 /// For SSH URLs, includes the username to distinguish `ssh://root@server` from `ssh://deploy@server`.
 pub fn create_host_id(host_spec: &str) -> String {
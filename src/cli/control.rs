@@ -0,0 +1,285 @@
+//! Local control socket (enabled with the `control-socket` feature)
+//!
+//! Listens on a Unix socket and speaks newline-delimited JSON-RPC 2.0, one
+//! request per line. Queries are answered from a snapshot of the container list
+//! that the event loop refreshes periodically; actions are injected into the
+//! event channel as `AppEvent::RequestAction`, exactly as if they had been
+//! picked from the action menu.
+//!
+//! Supported methods:
+//! - `containers` - returns the current container snapshot
+//! - `start` / `stop` / `restart` - params `{"host": "...", "id": "..."}`
+
+use serde::Serialize;
+use serde_json::{Value, json};
+use std::path::Path;
+use std::sync::{Arc, RwLock};
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use tokio::net::{UnixListener, UnixStream};
+
+use crate::core::app_state::AppState;
+use crate::core::types::{AppEvent, ContainerAction, ContainerKey, EventSender};
+
+/// Docker's short container ID length (see `docker/connection.rs`)
+const SHORT_ID_LEN: usize = 12;
+
+// JSON-RPC 2.0 error codes
+const PARSE_ERROR: i64 = -32700;
+const METHOD_NOT_FOUND: i64 = -32601;
+const INVALID_PARAMS: i64 = -32602;
+
+/// Container list shared between the event loop (writer) and socket clients (readers)
+pub type SharedSnapshot = Arc<RwLock<Vec<ContainerSnapshot>>>;
+
+/// Serializable view of a single container
+#[derive(Clone, Debug, Serialize)]
+pub struct ContainerSnapshot {
+    pub host: String,
+    pub id: String,
+    pub name: String,
+    pub state: String,
+    pub cpu: f64,
+    pub memory: f64,
+    pub memory_used_bytes: u64,
+    pub memory_limit_bytes: u64,
+    pub network_tx_bytes_per_sec: f64,
+    pub network_rx_bytes_per_sec: f64,
+}
+
+/// Replaces the shared snapshot with the containers currently known to `state`
+pub fn update_snapshot(snapshot: &SharedSnapshot, state: &AppState) {
+    let containers = state
+        .containers
+        .values()
+        .map(|c| ContainerSnapshot {
+            host: c.host_id.clone(),
+            id: c.id.clone(),
+            name: c.name.clone(),
+            state: format!("{:?}", c.state).to_lowercase(),
+            cpu: c.stats.cpu,
+            memory: c.stats.memory,
+            memory_used_bytes: c.stats.memory_used_bytes,
+            memory_limit_bytes: c.stats.memory_limit_bytes,
+            network_tx_bytes_per_sec: c.stats.network_tx_bytes_per_sec,
+            network_rx_bytes_per_sec: c.stats.network_rx_bytes_per_sec,
+        })
+        .collect();
+
+    if let Ok(mut guard) = snapshot.write() {
+        *guard = containers;
+    }
+}
+
+/// Binds the control socket at `path` and serves clients in the background.
+/// A stale socket left behind by a previous run is replaced.
+pub fn spawn_control_socket(
+    path: &Path,
+    snapshot: SharedSnapshot,
+    tx: EventSender,
+) -> std::io::Result<()> {
+    if path.exists() {
+        std::fs::remove_file(path)?;
+    }
+    let listener = UnixListener::bind(path)?;
+
+    tokio::spawn(async move {
+        loop {
+            match listener.accept().await {
+                Ok((stream, _)) => {
+                    let snapshot = snapshot.clone();
+                    let tx = tx.clone();
+                    tokio::spawn(async move {
+                        if let Err(e) = serve_client(stream, snapshot, tx).await {
+                            tracing::debug!("Control socket client error: {}", e);
+                        }
+                    });
+                }
+                Err(e) => {
+                    tracing::error!("Control socket accept failed: {}", e);
+                    break;
+                }
+            }
+        }
+    });
+
+    Ok(())
+}
+
+/// Removes the socket file on shutdown
+pub fn remove_control_socket(path: &Path) {
+    let _ = std::fs::remove_file(path);
+}
+
+/// Answers requests from one client until it disconnects
+async fn serve_client(
+    stream: UnixStream,
+    snapshot: SharedSnapshot,
+    tx: EventSender,
+) -> std::io::Result<()> {
+    let (reader, mut writer) = stream.into_split();
+    let mut lines = BufReader::new(reader).lines();
+
+    while let Some(line) = lines.next_line().await? {
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let (response, event) = {
+            let containers = snapshot.read().map(|c| c.clone()).unwrap_or_default();
+            handle_request(&line, &containers)
+        };
+
+        if let Some(event) = event {
+            let _ = tx.send(event).await;
+        }
+
+        let mut out = response.to_string();
+        out.push('\n');
+        writer.write_all(out.as_bytes()).await?;
+    }
+
+    Ok(())
+}
+
+/// Handles a single JSON-RPC request line.
+/// Returns the response and, for actions, the event to inject into the app.
+fn handle_request(line: &str, containers: &[ContainerSnapshot]) -> (Value, Option<AppEvent>) {
+    let request: Value = match serde_json::from_str(line) {
+        Ok(request) => request,
+        Err(e) => {
+            return (
+                error_response(Value::Null, PARSE_ERROR, &e.to_string()),
+                None,
+            );
+        }
+    };
+
+    let id = request.get("id").cloned().unwrap_or(Value::Null);
+    let method = request.get("method").and_then(Value::as_str).unwrap_or("");
+
+    let action = match method {
+        "containers" => {
+            return (
+                json!({"jsonrpc": "2.0", "id": id, "result": containers}),
+                None,
+            );
+        }
+        "start" => ContainerAction::Start,
+        "stop" => ContainerAction::Stop,
+        "restart" => ContainerAction::Restart,
+        _ => {
+            return (
+                error_response(
+                    id,
+                    METHOD_NOT_FOUND,
+                    &format!("Unknown method '{}'", method),
+                ),
+                None,
+            );
+        }
+    };
+
+    let params = request.get("params");
+    let host = params.and_then(|p| p.get("host")).and_then(Value::as_str);
+    let container_id = params.and_then(|p| p.get("id")).and_then(Value::as_str);
+    let (Some(host), Some(container_id)) = (host, container_id) else {
+        return (
+            error_response(id, INVALID_PARAMS, "Expected params {\"host\", \"id\"}"),
+            None,
+        );
+    };
+
+    // Accept full IDs as well as the short IDs dtop uses as keys
+    let container_id = container_id.get(..SHORT_ID_LEN).unwrap_or(container_id);
+    if !containers
+        .iter()
+        .any(|c| c.host == host && c.id == container_id)
+    {
+        return (
+            error_response(id, INVALID_PARAMS, "No such container"),
+            None,
+        );
+    }
+
+    let key = ContainerKey::new(host.to_string(), container_id.to_string());
+    (
+        json!({"jsonrpc": "2.0", "id": id, "result": "accepted"}),
+        Some(AppEvent::RequestAction(key, action)),
+    )
+}
+
+fn error_response(id: Value, code: i64, message: &str) -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "id": id,
+        "error": {"code": code, "message": message},
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn snapshot() -> Vec<ContainerSnapshot> {
+        vec![ContainerSnapshot {
+            host: "local".to_string(),
+            id: "abc123def456".to_string(),
+            name: "nginx".to_string(),
+            state: "running".to_string(),
+            cpu: 12.5,
+            memory: 40.0,
+            memory_used_bytes: 1024,
+            memory_limit_bytes: 4096,
+            network_tx_bytes_per_sec: 0.0,
+            network_rx_bytes_per_sec: 0.0,
+        }]
+    }
+
+    #[test]
+    fn test_containers_returns_snapshot() {
+        let (response, event) = handle_request(
+            r#"{"jsonrpc":"2.0","id":1,"method":"containers"}"#,
+            &snapshot(),
+        );
+        assert!(event.is_none());
+        assert_eq!(response["id"], 1);
+        assert_eq!(response["result"][0]["name"], "nginx");
+        assert_eq!(response["result"][0]["state"], "running");
+    }
+
+    #[test]
+    fn test_action_injects_event() {
+        let (response, event) = handle_request(
+            r#"{"jsonrpc":"2.0","id":2,"method":"restart","params":{"host":"local","id":"abc123def456789"}}"#,
+            &snapshot(),
+        );
+        assert_eq!(response["result"], "accepted");
+        match event {
+            Some(AppEvent::RequestAction(key, ContainerAction::Restart)) => {
+                assert_eq!(key.host_id, "local");
+                assert_eq!(key.container_id, "abc123def456");
+            }
+            other => panic!("unexpected event: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_action_on_unknown_container_is_rejected() {
+        let (response, event) = handle_request(
+            r#"{"jsonrpc":"2.0","id":3,"method":"stop","params":{"host":"local","id":"nope"}}"#,
+            &snapshot(),
+        );
+        assert!(event.is_none());
+        assert_eq!(response["error"]["code"], INVALID_PARAMS);
+    }
+
+    #[test]
+    fn test_invalid_requests() {
+        let (response, _) = handle_request("not json", &snapshot());
+        assert_eq!(response["error"]["code"], PARSE_ERROR);
+
+        let (response, _) = handle_request(r#"{"id":4,"method":"shell"}"#, &snapshot());
+        assert_eq!(response["error"]["code"], METHOD_NOT_FOUND);
+        assert_eq!(response["id"], 4);
+    }
+}
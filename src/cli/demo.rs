@@ -0,0 +1,281 @@
+//! Demo mode (hidden `--demo` flag)
+//!
+//! Feeds synthetic hosts and containers with fluctuating stats into the event
+//! channel, exactly as real hosts would, so the UI can be explored, demoed or
+//! screenshotted without a Docker daemon. The stats come from a fixed-seed
+//! generator, so every run looks the same.
+//!
+//! Demo hosts are never connected, so anything that needs Docker (logs, shell,
+//! actions) does nothing.
+
+use chrono::{Duration as ChronoDuration, Utc};
+use std::time::Duration;
+
+use crate::core::types::{
+    AppEvent, Container, ContainerKey, ContainerState, ContainerStats, EventSender, HealthStatus,
+};
+
+/// Hosts the demo containers are spread across
+const DEMO_HOSTS: [&str; 2] = ["demo-prod", "demo-staging"];
+
+/// How often the demo stats change
+const DEMO_TICK: Duration = Duration::from_secs(1);
+
+/// Memory limit reported for every demo container
+const DEMO_MEMORY_LIMIT: u64 = 2 * 1024 * 1024 * 1024;
+
+/// (host index, name, compose project, state, health, base CPU %, base memory %)
+type DemoSpec = (
+    usize,
+    &'static str,
+    Option<&'static str>,
+    ContainerState,
+    Option<HealthStatus>,
+    f64,
+    f64,
+);
+
+const DEMO_CONTAINERS: [DemoSpec; 10] = [
+    (
+        0,
+        "web",
+        Some("shop"),
+        ContainerState::Running,
+        Some(HealthStatus::Healthy),
+        12.0,
+        35.0,
+    ),
+    (
+        0,
+        "api",
+        Some("shop"),
+        ContainerState::Running,
+        Some(HealthStatus::Healthy),
+        45.0,
+        52.0,
+    ),
+    (
+        0,
+        "postgres",
+        Some("shop"),
+        ContainerState::Running,
+        None,
+        8.0,
+        70.0,
+    ),
+    (
+        0,
+        "redis",
+        Some("shop"),
+        ContainerState::Running,
+        None,
+        3.0,
+        12.0,
+    ),
+    (
+        0,
+        "worker",
+        Some("shop"),
+        ContainerState::Running,
+        Some(HealthStatus::Unhealthy),
+        88.0,
+        64.0,
+    ),
+    (
+        0,
+        "migrate",
+        Some("shop"),
+        ContainerState::Exited,
+        None,
+        0.0,
+        0.0,
+    ),
+    (
+        1,
+        "web",
+        Some("shop-staging"),
+        ContainerState::Running,
+        Some(HealthStatus::Starting),
+        4.0,
+        20.0,
+    ),
+    (
+        1,
+        "api",
+        Some("shop-staging"),
+        ContainerState::Running,
+        None,
+        9.0,
+        28.0,
+    ),
+    (1, "grafana", None, ContainerState::Paused, None, 0.0, 18.0),
+    (
+        1,
+        "nightly-backup",
+        None,
+        ContainerState::Exited,
+        None,
+        0.0,
+        0.0,
+    ),
+];
+
+/// Returns the demo host IDs, for the connection-progress screen
+pub fn demo_host_ids() -> Vec<String> {
+    DEMO_HOSTS.iter().map(|host| host.to_string()).collect()
+}
+
+/// Sends the demo containers, then keeps sending stats updates until the app exits
+pub fn spawn_demo(tx: EventSender) {
+    tokio::spawn(async move {
+        let containers = demo_containers();
+
+        for (i, host) in DEMO_HOSTS.iter().enumerate() {
+            let host_containers = containers
+                .iter()
+                .zip(DEMO_CONTAINERS.iter())
+                .filter(|(_, spec)| spec.0 == i)
+                .map(|(container, _)| container.clone())
+                .collect();
+            if tx
+                .send(AppEvent::InitialContainerList(
+                    host.to_string(),
+                    host_containers,
+                ))
+                .await
+                .is_err()
+            {
+                return;
+            }
+        }
+
+        let mut rng = Rng(0x5eed_d70b);
+        let mut interval = tokio::time::interval(DEMO_TICK);
+        loop {
+            interval.tick().await;
+            for (container, spec) in containers.iter().zip(DEMO_CONTAINERS.iter()) {
+                if container.state != ContainerState::Running {
+                    continue;
+                }
+                let key = ContainerKey::new(container.host_id.clone(), container.id.clone());
+                let stats = demo_stats(spec.5, spec.6, &mut rng);
+                if tx.send(AppEvent::ContainerStat(key, stats)).await.is_err() {
+                    return;
+                }
+            }
+        }
+    });
+}
+
+/// Builds the demo containers from [`DEMO_CONTAINERS`]
+fn demo_containers() -> Vec<Container> {
+    let mut rng = Rng(0xd0c_4e55);
+    DEMO_CONTAINERS
+        .iter()
+        .enumerate()
+        .map(|(i, (host, name, compose, state, health, cpu, memory))| {
+            let id = format!("{:012x}", 0xdead_0000_0000u64 + i as u64 * 0x1_0203_0405);
+            let stats = if *state == ContainerState::Running {
+                demo_stats(*cpu, *memory, &mut rng)
+            } else {
+                ContainerStats::default()
+            };
+            Container {
+                full_id: format!("{:0<64}", id),
+                id,
+                name: name.to_string(),
+                image: format!("demo/{}:latest", name),
+                state: state.clone(),
+                health: health.clone(),
+                created: Some(Utc::now() - ChronoDuration::hours(3 + 7 * i as i64)),
+                stats,
+                host_id: DEMO_HOSTS[*host].to_string(),
+                dozzle_url: None,
+                restart_count: Some((i % 3) as i64),
+                compose_project: compose.map(str::to_string),
+                size: None,
+                health_check: health
+                    .as_ref()
+                    .map(|_| "curl -f http://localhost/health".to_string()),
+                last_stats: None,
+                // One clean exit and one OOM kill
+                exit_code: (*state == ContainerState::Exited).then_some(if *name == "migrate" {
+                    0
+                } else {
+                    137
+                }),
+                ports: Vec::new(),
+            }
+        })
+        .collect()
+}
+
+/// Stats that wander around a container's base CPU and memory
+fn demo_stats(base_cpu: f64, base_memory: f64, rng: &mut Rng) -> ContainerStats {
+    let jitter = |rng: &mut Rng, base: f64, spread: f64| {
+        (base + (rng.next_f64() - 0.5) * spread).clamp(0.0, 100.0)
+    };
+    let memory = jitter(rng, base_memory, 4.0);
+    ContainerStats {
+        cpu: jitter(rng, base_cpu, base_cpu.max(2.0) * 0.6),
+        memory,
+        memory_used_bytes: (DEMO_MEMORY_LIMIT as f64 * memory / 100.0) as u64,
+        memory_limit_bytes: DEMO_MEMORY_LIMIT,
+        network_tx_bytes_per_sec: base_cpu * 2048.0 * rng.next_f64(),
+        network_rx_bytes_per_sec: base_cpu * 4096.0 * rng.next_f64(),
+        disk_read_bytes_per_sec: base_memory * 512.0 * rng.next_f64(),
+        disk_write_bytes_per_sec: base_memory * 256.0 * rng.next_f64(),
+        disk_io_unavailable: false,
+    }
+}
+
+/// Small xorshift generator: repeatable demo data without a `rand` dependency
+struct Rng(u64);
+
+impl Rng {
+    /// Returns the next value in [0, 1)
+    fn next_f64(&mut self) -> f64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        (self.0 >> 11) as f64 / (1u64 << 53) as f64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_demo_containers() {
+        let containers = demo_containers();
+        assert_eq!(containers.len(), DEMO_CONTAINERS.len());
+
+        // IDs are unique, short-ID sized and every host has containers
+        let mut ids: Vec<_> = containers.iter().map(|c| c.id.as_str()).collect();
+        ids.sort();
+        ids.dedup();
+        assert_eq!(ids.len(), containers.len());
+        assert!(ids.iter().all(|id| id.len() == 12));
+        for host in DEMO_HOSTS {
+            assert!(containers.iter().any(|c| c.host_id == host));
+        }
+
+        // Only running containers have stats
+        for container in &containers {
+            let running = container.state == ContainerState::Running;
+            assert_eq!(container.stats.memory_limit_bytes > 0, running);
+        }
+    }
+
+    #[test]
+    fn test_demo_stats_stay_in_range() {
+        let mut rng = Rng(1);
+        for _ in 0..1000 {
+            let stats = demo_stats(95.0, 98.0, &mut rng);
+            assert!((0.0..=100.0).contains(&stats.cpu));
+            assert!((0.0..=100.0).contains(&stats.memory));
+            assert!(stats.memory_used_bytes <= stats.memory_limit_bytes);
+        }
+    }
+}
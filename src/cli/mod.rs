@@ -1,5 +1,9 @@
 pub mod config;
 pub mod connect;
+#[cfg(feature = "control-socket")]
+pub mod control;
+pub mod demo;
 pub mod filters;
+pub mod once;
 #[cfg(feature = "self-update")]
 pub mod update;
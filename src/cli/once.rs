@@ -0,0 +1,241 @@
+//! One-shot snapshot (`dtop --once`): connects to the configured hosts, waits for
+//! their containers and stats, prints them to stdout and exits without the UI
+
+use chrono::Utc;
+use std::collections::{HashMap, HashSet};
+use std::fmt::Write;
+use std::time::Duration;
+use tokio::sync::mpsc;
+
+use crate::cli::config::Config;
+use crate::cli::connect::{create_host_id, spawn_connections};
+use crate::core::app_state::AppState;
+use crate::core::types::{AppEvent, Column, ColumnConfig, ContainerKey, ContainerState, HostId};
+use crate::ui::formatters::{format_bytes_per_sec, write_bytes};
+
+/// Longest to wait for hosts and stats before printing whatever has arrived
+const ONCE_TIMEOUT: Duration = Duration::from_secs(15);
+
+/// Stats samples to wait for per running container. Network rates are computed
+/// between two samples, so the first one reports them as zero.
+const SAMPLES_NEEDED: usize = 2;
+
+/// Runs `dtop --once`: prints a table (or, with `json`, the same document as the
+/// 'x' export) of the containers on every host in `config`
+pub async fn run_once(config: &Config, json: bool) -> Result<(), Box<dyn std::error::Error>> {
+    // Polled stats come with a real CPU delta in the first sample, and no
+    // stream is opened per container for a single reading
+    let mut config = config.clone();
+    config.stats_mode = Some("poll".to_string());
+
+    let (tx, mut rx) = mpsc::channel::<AppEvent>(1000);
+    let mut pending_hosts: HashSet<HostId> = config
+        .hosts
+        .iter()
+        .map(|host_config| create_host_id(&host_config.host))
+        .collect();
+    spawn_connections(&config, tx.clone());
+
+    let mut state = AppState::new(
+        HashMap::new(),
+        tx,
+        config.all.unwrap_or(false),
+        Column::Name,
+        None,
+        ColumnConfig::default(),
+        None,
+    );
+    // Keep each host's containers together in the output
+    state.host_separators = true;
+
+    let mut samples: HashMap<ContainerKey, usize> = HashMap::new();
+    let deadline = tokio::time::sleep(ONCE_TIMEOUT);
+    tokio::pin!(deadline);
+
+    while !pending_hosts.is_empty() || !all_sampled(&state, &samples) {
+        let event = tokio::select! {
+            _ = &mut deadline => {
+                tracing::warn!("--once timed out waiting for hosts or stats");
+                break;
+            }
+            event = rx.recv() => match event {
+                Some(event) => event,
+                None => break,
+            },
+        };
+
+        match &event {
+            AppEvent::InitialContainerList(host_id, _) | AppEvent::ConnectionError(host_id, _) => {
+                pending_hosts.remove(host_id);
+            }
+            AppEvent::ContainerStat(key, _) => *samples.entry(key.clone()).or_default() += 1,
+            AppEvent::ContainerStatBatch(host_id, stats) => {
+                for (id, _) in stats {
+                    *samples
+                        .entry(ContainerKey::new(host_id.clone(), id.clone()))
+                        .or_default() += 1;
+                }
+            }
+            _ => {}
+        }
+        state.handle_event(event);
+    }
+
+    for (host_id, (error, _)) in &state.connection_errors {
+        eprintln!("{}: {}", host_id, error);
+    }
+    if state.connected_hosts.is_empty() {
+        return Err("Could not connect to any Docker host".into());
+    }
+
+    if json {
+        println!("{}", state.snapshot_json(Utc::now())?);
+    } else {
+        state.force_sort_containers();
+        print!("{}", format_table(&state));
+    }
+    Ok(())
+}
+
+/// Whether every running container has its [`SAMPLES_NEEDED`] stats samples
+fn all_sampled(state: &AppState, samples: &HashMap<ContainerKey, usize>) -> bool {
+    state
+        .containers
+        .iter()
+        .filter(|(_, c)| c.state == ContainerState::Running)
+        .all(|(key, _)| samples.get(key).copied().unwrap_or(0) >= SAMPLES_NEEDED)
+}
+
+/// Formats the listed containers (`sorted_container_keys`, so stopped ones only
+/// with `all`) as a plain-text table with a header line
+fn format_table(state: &AppState) -> String {
+    let rows: Vec<[String; 9]> = state
+        .sorted_container_keys
+        .iter()
+        .filter_map(|key| state.containers.get(key))
+        .map(|c| {
+            let mut memory_usage = String::new();
+            if c.stats.memory_limit_bytes > 0 {
+                write_bytes(&mut memory_usage, c.stats.memory_used_bytes);
+                memory_usage.push_str(" / ");
+                write_bytes(&mut memory_usage, c.stats.memory_limit_bytes);
+            } else {
+                memory_usage.push('-');
+            }
+            [
+                AppState::host_display_name(&state.connected_hosts, &c.host_id).to_string(),
+                c.name.clone(),
+                c.id.clone(),
+                c.state.as_str().to_string(),
+                format!("{:.1}", c.stats.cpu),
+                format!("{:.1}", c.stats.memory),
+                memory_usage,
+                format_bytes_per_sec(c.stats.network_rx_bytes_per_sec),
+                format_bytes_per_sec(c.stats.network_tx_bytes_per_sec),
+            ]
+        })
+        .collect();
+
+    let header = [
+        "HOST",
+        "NAME",
+        "ID",
+        "STATE",
+        "CPU%",
+        "MEM%",
+        "MEM USAGE",
+        "NET RX",
+        "NET TX",
+    ];
+    let widths: Vec<usize> = (0..header.len())
+        .map(|i| {
+            rows.iter()
+                .map(|row| row[i].chars().count())
+                .chain([header[i].len()])
+                .max()
+                .unwrap_or(0)
+        })
+        .collect();
+
+    let mut out = String::new();
+    let mut write_line = |cells: &mut dyn Iterator<Item = &str>| {
+        let mut line = String::new();
+        for (cell, width) in cells.zip(&widths) {
+            let _ = write!(line, "{:<width$}  ", cell, width = width);
+        }
+        out.push_str(line.trim_end());
+        out.push('\n');
+    };
+    write_line(&mut header.iter().copied());
+    for row in &rows {
+        write_line(&mut row.iter().map(String::as_str));
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::types::{Container, ContainerStats};
+
+    fn container(host: &str, id: &str, name: &str, state: ContainerState) -> Container {
+        Container {
+            id: id.to_string(),
+            full_id: id.to_string(),
+            name: name.to_string(),
+            image: "nginx:latest".to_string(),
+            state,
+            health: None,
+            created: None,
+            stats: ContainerStats {
+                cpu: 12.5,
+                memory: 50.0,
+                memory_used_bytes: 512 * 1024 * 1024,
+                memory_limit_bytes: 1024 * 1024 * 1024,
+                ..Default::default()
+            },
+            host_id: host.to_string(),
+            dozzle_url: None,
+            restart_count: None,
+            compose_project: None,
+            size: None,
+            health_check: None,
+            last_stats: None,
+            exit_code: None,
+            ports: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_format_table_lists_running_containers_by_host() {
+        let (tx, _rx) = mpsc::channel(1);
+        let mut state = AppState::new(
+            HashMap::new(),
+            tx,
+            false,
+            Column::Name,
+            None,
+            ColumnConfig::default(),
+            None,
+        );
+        state.host_separators = true;
+        for c in [
+            container("server1", "bbb222222222", "api", ContainerState::Running),
+            container("local", "aaa111111111", "web", ContainerState::Running),
+            container("local", "ccc333333333", "migrate", ContainerState::Exited),
+        ] {
+            state
+                .containers
+                .insert(ContainerKey::new(c.host_id.clone(), c.id.clone()), c);
+        }
+        state.force_sort_containers();
+
+        let table = format_table(&state);
+        let lines: Vec<&str> = table.lines().collect();
+        assert_eq!(lines.len(), 3, "{}", table);
+        assert!(lines[0].starts_with("HOST     NAME  ID            STATE"));
+        assert!(lines[1].starts_with("local    web   aaa111111111  running  12.5"));
+        assert!(lines[1].contains("512M / 1G"), "{}", lines[1]);
+        assert!(lines[2].starts_with("server1  api"));
+    }
+}
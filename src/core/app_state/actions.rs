@@ -1,5 +1,18 @@
+use std::time::{Duration, Instant};
+
+use tui_input::Input;
+
 use crate::core::app_state::AppState;
 use crate::core::types::{ContainerAction, ContainerKey, RenderAction, ViewState};
+use crate::docker::connection::DockerHost;
+
+/// Whether Docker accepts `name` as a container name (`[a-zA-Z0-9][a-zA-Z0-9_.-]+`)
+fn is_valid_container_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars.next().is_some_and(|c| c.is_ascii_alphanumeric())
+        && name.len() >= 2
+        && chars.all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '.' | '-'))
+}
 
 impl AppState {
     pub(super) fn handle_show_action_menu(&mut self) -> RenderAction {
@@ -43,9 +56,15 @@ impl AppState {
                 // Exit log view
                 return self.handle_exit_log_view();
             }
+            ViewState::Inspect(_) => {
+                return self.handle_close_inspect();
+            }
             ViewState::ActionMenu(_) => {
                 // Exit action menu
             }
+            ViewState::ContainerList if self.host_filter.is_some() => {
+                return self.set_host_filter(None);
+            }
             ViewState::ColumnSelector => {
                 // Switch back to container list view
                 self.view_state = ViewState::ContainerList;
@@ -78,9 +97,10 @@ impl AppState {
             return RenderAction::None;
         };
 
-        let available_actions = ContainerAction::available_for_state(&container.state);
+        let item_count = ContainerAction::available_for_state(&container.state).len()
+            + self.custom_actions_for(&container.state).len();
 
-        if available_actions.is_empty() {
+        if item_count == 0 {
             return RenderAction::None;
         }
 
@@ -105,15 +125,16 @@ impl AppState {
             return RenderAction::None;
         };
 
-        let available_actions = ContainerAction::available_for_state(&container.state);
+        let item_count = ContainerAction::available_for_state(&container.state).len()
+            + self.custom_actions_for(&container.state).len();
 
-        if available_actions.is_empty() {
+        if item_count == 0 {
             return RenderAction::None;
         }
 
         // Move selection down
         let current = self.action_menu_state.selected().unwrap_or(0);
-        if current < available_actions.len() - 1 {
+        if current < item_count - 1 {
             self.action_menu_state.select(Some(current + 1));
             RenderAction::Render // Force draw
         } else {
@@ -139,7 +160,12 @@ impl AppState {
 
         let available_actions = ContainerAction::available_for_state(&container.state);
 
+        // Custom actions are listed after the built-in ones
         let Some(&action) = available_actions.get(selected_idx) else {
+            let custom_idx = selected_idx - available_actions.len();
+            if custom_idx < self.custom_actions_for(&container.state).len() {
+                return self.run_custom_action(container_key.clone(), custom_idx);
+            }
             return RenderAction::None;
         };
 
@@ -152,28 +178,59 @@ impl AppState {
         // Handle Shell action specially - it needs to take over the terminal
         if action == ContainerAction::Shell {
             let container_key_clone = container_key.clone();
+            let message = format!(
+                "[{}] Open a shell in {}? (y/n)",
+                container_key.host_id, container.name
+            );
+            let confirm = host.confirm_shell;
 
             // Close the action menu immediately
             self.view_state = ViewState::ContainerList;
             self.action_menu_state.select(None);
 
+            // Guarded hosts (e.g. production) need a `y` first
+            if confirm {
+                self.shell_confirm_pending = Some(container_key_clone);
+                self.notification = Some((
+                    message,
+                    Instant::now() + Duration::from_secs(30), // Long timeout for confirmation
+                ));
+                return RenderAction::Render;
+            }
+
             return RenderAction::StartShell(container_key_clone);
         }
 
-        // Spawn async task to execute the action
-        let host_clone = host.clone();
-        let container_key_clone = container_key.clone();
-        let tx_clone = self.event_tx.clone();
+        // Exec and rename ask for their argument first; Enter in the prompt runs them
+        if matches!(action, ContainerAction::Exec | ContainerAction::Rename) {
+            self.action_prompt_input = if action == ContainerAction::Rename {
+                Input::new(container.name.clone())
+            } else {
+                Input::default()
+            };
+            self.action_prompt = Some((container_key.clone(), action));
+            self.view_state = ViewState::ContainerList;
+            self.action_menu_state.select(None);
+            return RenderAction::Render;
+        }
 
-        tokio::spawn(async move {
-            crate::docker::actions::execute_container_action(
-                host_clone,
-                container_key_clone,
-                action,
-                tx_clone,
-            )
-            .await;
-        });
+        // The pager takes over the terminal too, without confirmation
+        if action == ContainerAction::Pager {
+            let container_key_clone = container_key.clone();
+            self.view_state = ViewState::ContainerList;
+            self.action_menu_state.select(None);
+            return RenderAction::OpenPager(container_key_clone);
+        }
+
+        // Removing can't be undone, so it waits for a `y` in the confirmation popup
+        if action.is_destructive() && self.confirm_destructive {
+            self.view_state = ViewState::ConfirmAction(container_key.clone(), action);
+            self.action_menu_state.select(None);
+            return RenderAction::Render;
+        }
+
+        // Spawn async task to execute the action
+        self.spawn_container_action(host.clone(), container_key.clone(), action);
 
         // Close the action menu immediately
         self.view_state = ViewState::ContainerList;
@@ -182,35 +239,224 @@ impl AppState {
         RenderAction::Render // Force draw
     }
 
+    /// Handles keys while a destructive action waits for confirmation: `y` runs
+    /// it, `n` or Esc cancels and anything else is ignored
+    pub(super) fn handle_confirm_action_key(
+        &mut self,
+        key_event: crossterm::event::KeyEvent,
+    ) -> RenderAction {
+        use crossterm::event::KeyCode;
+
+        let ViewState::ConfirmAction(ref container_key, action) = self.view_state else {
+            return RenderAction::None;
+        };
+
+        match key_event.code {
+            KeyCode::Char('y') | KeyCode::Char('Y') => {
+                let container_key = container_key.clone();
+                self.view_state = ViewState::ContainerList;
+                if let Some(host) = self.connected_hosts.get(&container_key.host_id) {
+                    self.spawn_container_action(host.clone(), container_key, action);
+                }
+                RenderAction::Render
+            }
+            KeyCode::Char('n') | KeyCode::Char('N') | KeyCode::Esc => {
+                self.view_state = ViewState::ContainerList;
+                RenderAction::Render
+            }
+            _ => RenderAction::None,
+        }
+    }
+
+    /// Executes an action requested from outside the UI (e.g. the control socket).
+    /// Shell and pager sessions need the terminal and exec and rename need a typed
+    /// argument, so they can only be started interactively.
+    #[cfg(feature = "control-socket")]
+    pub(super) fn handle_request_action(
+        &mut self,
+        key: ContainerKey,
+        action: ContainerAction,
+    ) -> RenderAction {
+        if matches!(
+            action,
+            ContainerAction::Shell
+                | ContainerAction::Exec
+                | ContainerAction::Rename
+                | ContainerAction::Pager
+        ) || !self.containers.contains_key(&key)
+        {
+            return RenderAction::None;
+        }
+
+        let Some(host) = self.connected_hosts.get(&key.host_id) else {
+            return RenderAction::None;
+        };
+
+        self.spawn_container_action(host.clone(), key, action);
+        RenderAction::None // Docker events will update the container state
+    }
+
+    /// Handles keys while the action prompt is open. Enter runs the action with
+    /// the typed argument; Esc cancels.
+    pub(super) fn handle_action_prompt_key(
+        &mut self,
+        key_event: crossterm::event::KeyEvent,
+    ) -> RenderAction {
+        use crossterm::event::KeyCode;
+        use tui_input::backend::crossterm::EventHandler;
+
+        match key_event.code {
+            KeyCode::Enter => {
+                // Invalid names stay in the prompt, which shows why
+                if self.action_prompt_error().is_some() {
+                    return RenderAction::None;
+                }
+                let Some((key, action)) = self.action_prompt.take() else {
+                    return RenderAction::None;
+                };
+                let value = self.action_prompt_input.value().trim().to_string();
+                self.action_prompt_input.reset();
+                if value.is_empty() {
+                    return RenderAction::Render;
+                }
+                let Some(host) = self.connected_hosts.get(&key.host_id) else {
+                    return RenderAction::Render;
+                };
+                let host = host.clone();
+
+                if action == ContainerAction::Exec {
+                    return self.spawn_command_run(host, key, format!("Exec {}", value), value);
+                }
+
+                let tx_clone = self.event_tx.clone();
+                tokio::spawn(async move {
+                    crate::docker::actions::execute_rename_container(host, key, value, tx_clone)
+                        .await;
+                });
+                RenderAction::Render
+            }
+            KeyCode::Esc => {
+                self.action_prompt = None;
+                self.action_prompt_input.reset();
+                RenderAction::Render
+            }
+            _ => {
+                self.action_prompt_input
+                    .handle_event(&crossterm::event::Event::Key(key_event));
+                RenderAction::Render
+            }
+        }
+    }
+
+    /// Why the action prompt's input can't be submitted, shown next to it
+    pub fn action_prompt_error(&self) -> Option<&'static str> {
+        let (_, action) = self.action_prompt.as_ref()?;
+        let value = self.action_prompt_input.value().trim();
+        if *action == ContainerAction::Rename && !is_valid_container_name(value) {
+            return Some("names need 2+ characters: letters, digits, _ . - (not first)");
+        }
+        None
+    }
+
+    /// Applies a successful rename right away, as Docker sends no event the
+    /// list listens to
+    pub(super) fn handle_container_renamed(
+        &mut self,
+        key: ContainerKey,
+        name: String,
+    ) -> RenderAction {
+        let Some(container) = self.containers.get_mut(&key) else {
+            return RenderAction::None;
+        };
+        container.name = name;
+        self.force_sort_containers();
+        RenderAction::Render
+    }
+
+    /// Spawns an async task that runs a container action on its host
+    fn spawn_container_action(
+        &self,
+        host: DockerHost,
+        container_key: ContainerKey,
+        action: ContainerAction,
+    ) {
+        let tx_clone = self.event_tx.clone();
+
+        tokio::spawn(async move {
+            crate::docker::actions::execute_container_action(host, container_key, action, tx_clone)
+                .await;
+        });
+    }
+
     pub(super) fn handle_action_in_progress(
         &mut self,
         _key: ContainerKey,
         _action: ContainerAction,
     ) -> RenderAction {
-        // TODO: Could show a loading indicator in the UI in the future
-        // For now, just let Docker events update the container state
+        // Counted so quitting can warn about it; Docker events update the container state
+        self.actions_in_flight += 1;
         RenderAction::None // Don't force redraw for progress events
     }
 
     pub(super) fn handle_action_success(
         &mut self,
-        _key: ContainerKey,
-        _action: ContainerAction,
+        key: ContainerKey,
+        action: ContainerAction,
     ) -> RenderAction {
-        // TODO: Could show a success toast/notification in the UI in the future
-        // The container state will be updated by Docker events
-        // so we don't need to manually update it here
-        RenderAction::None // Don't force redraw - Docker events will trigger updates
+        self.actions_in_flight = self.actions_in_flight.saturating_sub(1);
+
+        // The container state will be updated by Docker events, so only confirm
+        // that the action went through
+        let name = self
+            .containers
+            .get(&key)
+            .map_or(key.container_id.as_str(), |c| c.name.as_str());
+        let message = format!("[{}] {} {}: done", key.host_id, action.display_name(), name);
+        self.show_notification(&message);
+        RenderAction::Render
+    }
+
+    /// Handles 'q': quits right away unless container actions are still running,
+    /// which could be cut off, in which case it asks first
+    pub(super) fn handle_quit(&mut self) -> RenderAction {
+        if self.actions_in_flight == 0 {
+            self.should_quit = true;
+            return RenderAction::None;
+        }
+
+        self.quit_confirm_pending = true;
+        self.notification = Some((
+            format!(
+                "{} action(s) in progress, quit anyway? (y/n)",
+                self.actions_in_flight
+            ),
+            Instant::now() + Duration::from_secs(30), // Long timeout for confirmation
+        ));
+        RenderAction::Render
     }
 
     pub(super) fn handle_action_error(
         &mut self,
-        _key: ContainerKey,
-        _action: ContainerAction,
-        _error: String,
+        key: ContainerKey,
+        action: ContainerAction,
+        error: String,
     ) -> RenderAction {
-        // TODO: Could show an error toast/notification in the UI in the future
-        // For now, silently fail - the container state won't change on error
-        RenderAction::None // Don't force redraw for error messages
+        self.actions_in_flight = self.actions_in_flight.saturating_sub(1);
+
+        // The container state won't change on error, so tell the user what happened
+        let name = self
+            .containers
+            .get(&key)
+            .map_or(key.container_id.as_str(), |c| c.name.as_str());
+        let message = format!(
+            "[{}] {} {} failed: {}",
+            key.host_id,
+            action.display_name(),
+            name,
+            error
+        );
+        self.show_error_notification(&message);
+        self.record_error(message);
+        RenderAction::Render
     }
 }
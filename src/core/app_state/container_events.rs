@@ -1,6 +1,10 @@
+use chrono::{DateTime, Utc};
+use std::collections::HashMap;
+
 use crate::core::app_state::AppState;
 use crate::core::types::{
-    Container, ContainerKey, ContainerState, ContainerStats, HealthStatus, RenderAction,
+    AppEvent, Container, ContainerKey, ContainerSize, ContainerState, ContainerStats, HealthStatus,
+    HostId, HostStatus, RenderAction, ViewState,
 };
 
 impl AppState {
@@ -9,18 +13,53 @@ impl AppState {
         host_id: String,
         container_list: Vec<Container>,
     ) -> RenderAction {
-        for container in container_list {
+        // The first host to finish loading ends the connection-progress screen
+        self.connecting = false;
+        self.host_last_update
+            .insert(host_id.clone(), std::time::Instant::now());
+
+        // A refresh ('r') lists the host again: replace its previous containers,
+        // keeping the stats of those still there until their streams resume
+        let selected_key = self
+            .table_state
+            .selected()
+            .and_then(|idx| self.sorted_container_keys.get(idx))
+            .cloned();
+        let mut previous: HashMap<ContainerKey, Container> = HashMap::new();
+        self.containers.retain(|key, container| {
+            if key.host_id != host_id {
+                return true;
+            }
+            previous.insert(key.clone(), container.clone());
+            false
+        });
+        self.sorted_container_keys
+            .retain(|key| key.host_id != host_id);
+
+        for mut container in container_list {
             let key = ContainerKey::new(host_id.clone(), container.id.clone());
+            if let Some(old) = previous.remove(&key) {
+                if container.state == ContainerState::Running {
+                    container.stats = old.stats;
+                }
+                container.last_stats = old.last_stats;
+            }
             self.containers.insert(key.clone(), container);
             self.sorted_container_keys.push(key);
         }
+        for key in previous.keys() {
+            self.stats_history.remove(key);
+        }
 
         // Force immediate sort when loading initial container list
+        // (this also selects the first row if nothing was selected yet)
         self.force_sort_containers();
 
-        // Select first row if we have containers
-        if !self.containers.is_empty() {
-            self.table_state.select(Some(0));
+        // Stay on the same container if it is still listed
+        if let Some(idx) =
+            selected_key.and_then(|key| self.sorted_container_keys.iter().position(|k| *k == key))
+        {
+            self.table_state.select(Some(idx));
         }
 
         RenderAction::Render // Force draw - table structure changed
@@ -38,18 +77,16 @@ impl AppState {
         }
 
         // Force immediate sort when new container is added
+        // (this also selects the first row if nothing was selected yet)
         self.force_sort_containers();
 
-        // Select first row if this is the first container
-        if self.containers.len() == 1 {
-            self.table_state.select(Some(0));
-        }
-
         RenderAction::Render // Force draw - table structure changed
     }
 
     pub(super) fn handle_container_destroyed(&mut self, key: ContainerKey) -> RenderAction {
         self.containers.remove(&key);
+        self.stats_history.remove(&key);
+        self.created_lookups.remove(&key);
         self.sorted_container_keys.retain(|k| k != &key);
 
         // Adjust selection if needed
@@ -64,7 +101,29 @@ impl AppState {
         state: ContainerState,
     ) -> RenderAction {
         if let Some(container) = self.containers.get_mut(&key) {
+            // A paused container has no stats stream, so drop the last sample rather
+            // than keep showing (and sorting by) frozen numbers
+            if state == ContainerState::Paused {
+                container.stats = ContainerStats::default();
+            }
+            let started = state == ContainerState::Running;
+
+            // Keep what a container was doing when it stopped, for post-mortems
+            if started {
+                container.last_stats = None;
+                container.exit_code = None;
+            } else if self.show_last_stats
+                && container.state == ContainerState::Running
+                && state != ContainerState::Paused
+            {
+                container.last_stats = Some(container.stats.clone());
+            }
             container.state = state;
+
+            // Pick up logs of a container started while its log view is open
+            if started {
+                self.resume_log_stream(&key);
+            }
             return RenderAction::Render; // Force draw - state changed
         }
         RenderAction::None
@@ -75,12 +134,133 @@ impl AppState {
         key: ContainerKey,
         stats: ContainerStats,
     ) -> RenderAction {
+        // Any sample shows the host is still delivering; only the first one allocates
+        let now = std::time::Instant::now();
+        match self.host_last_update.get_mut(&key.host_id) {
+            Some(last_update) => *last_update = now,
+            None => {
+                self.host_last_update.insert(key.host_id.clone(), now);
+            }
+        }
+
         if let Some(container) = self.containers.get_mut(&key) {
-            container.stats = stats;
+            // Ignore samples still in flight from a stream stopped by a pause
+            if container.state != ContainerState::Paused {
+                if self.stats_history_len > 0 {
+                    self.stats_history
+                        .entry(key)
+                        .or_default()
+                        .push(&stats, self.stats_history_len);
+                }
+                container.stats = stats;
+            }
         }
         RenderAction::None // No force draw - just stats update
     }
 
+    pub(super) fn handle_container_stat_batch(
+        &mut self,
+        host_id: HostId,
+        stats: Vec<(String, ContainerStats)>,
+    ) -> RenderAction {
+        for (container_id, stats) in stats {
+            self.handle_container_stat(ContainerKey::new(host_id.clone(), container_id), stats);
+        }
+        RenderAction::None // No force draw - just stats updates
+    }
+
+    pub(super) fn handle_container_sizes(
+        &mut self,
+        host_id: HostId,
+        sizes: Vec<(String, ContainerSize)>,
+    ) -> RenderAction {
+        for (container_id, size) in sizes {
+            let key = ContainerKey::new(host_id.clone(), container_id);
+            if let Some(container) = self.containers.get_mut(&key) {
+                container.size = Some(size);
+            }
+        }
+        RenderAction::None // Sizes change slowly; the next periodic draw picks them up
+    }
+
+    /// Handles 'r': asks every connected host to list its containers again,
+    /// in case an event was missed and the list went stale
+    pub(super) fn handle_refresh_containers(&mut self) -> RenderAction {
+        if self.view_state != ViewState::ContainerList || self.connected_hosts.is_empty() {
+            return RenderAction::None;
+        }
+
+        for host in self.connected_hosts.values() {
+            host.refresh.notify_one();
+        }
+        self.show_notification("Refreshing containers...");
+        RenderAction::Render
+    }
+
+    /// Forces an immediate one-shot stats sample for the selected container,
+    /// instead of waiting for the next sample of its stats stream
+    pub(super) fn handle_refresh_selected_stats(&mut self) -> RenderAction {
+        if self.view_state != ViewState::ContainerList {
+            return RenderAction::None;
+        }
+
+        let Some(selected_idx) = self.table_state.selected() else {
+            return RenderAction::None;
+        };
+
+        let Some(container_key) = self.sorted_container_keys.get(selected_idx) else {
+            return RenderAction::None;
+        };
+
+        let Some(container) = self.containers.get(container_key) else {
+            return RenderAction::None;
+        };
+
+        // Stopped containers have no stats to refresh
+        if container.state != ContainerState::Running {
+            return RenderAction::None;
+        }
+
+        let Some(host) = self.connected_hosts.get(&container_key.host_id) else {
+            return RenderAction::None;
+        };
+
+        let host_clone = host.clone();
+        let key_clone = container_key.clone();
+        let previous_stats = container.stats.clone();
+        let tx_clone = self.event_tx.clone();
+        let message = format!("Refreshing stats for {}", container.name);
+
+        tokio::spawn(async move {
+            match crate::docker::stats::fetch_container_stats_once(
+                &host_clone,
+                &key_clone.container_id,
+            )
+            .await
+            {
+                Ok(sample) => {
+                    // A single sample can't produce rates; keep the streamed ones
+                    let stats = ContainerStats {
+                        cpu: sample.cpu,
+                        memory: sample.memory,
+                        memory_used_bytes: sample.memory_used_bytes,
+                        memory_limit_bytes: sample.memory_limit_bytes,
+                        ..previous_stats
+                    };
+                    let _ = tx_clone
+                        .send(AppEvent::ContainerStat(key_clone, stats))
+                        .await;
+                }
+                Err(e) => {
+                    tracing::debug!("One-shot stats refresh failed: {}", e);
+                }
+            }
+        });
+
+        self.show_notification(&message);
+        RenderAction::Render
+    }
+
     pub(super) fn handle_container_health_changed(
         &mut self,
         key: ContainerKey,
@@ -91,4 +271,65 @@ impl AppState {
         }
         RenderAction::Render // Force draw - health status changed (visible in UI)
     }
+
+    pub(super) fn handle_container_exit_code(
+        &mut self,
+        key: ContainerKey,
+        code: i64,
+    ) -> RenderAction {
+        if let Some(container) = self.containers.get_mut(&key) {
+            container.exit_code = Some(code);
+        }
+        RenderAction::None // The state change that follows redraws
+    }
+
+    /// Looks up the creation time of the selected container if it was listed
+    /// without one (it shows as "Unknown" and sorts first by uptime). Runs after
+    /// every event; `created_lookups` keeps it to one inspect per container.
+    pub(super) fn look_up_selected_created(&mut self) {
+        let Some(key) = self
+            .table_state
+            .selected()
+            .and_then(|idx| self.sorted_container_keys.get(idx))
+        else {
+            return;
+        };
+        if self
+            .containers
+            .get(key)
+            .is_none_or(|container| container.created.is_some())
+            || self.created_lookups.contains(key)
+            || self.host_status.get(&key.host_id) == Some(&HostStatus::Failed)
+        {
+            return;
+        }
+        let Some(host) = self.connected_hosts.get(&key.host_id) else {
+            return;
+        };
+
+        let host_clone = host.clone();
+        let key_clone = key.clone();
+        let tx_clone = self.event_tx.clone();
+        tokio::spawn(async move {
+            if let Some(created) = host_clone.created_time(&key_clone.container_id).await {
+                let _ = tx_clone
+                    .send(AppEvent::ContainerCreatedAt(key_clone, created))
+                    .await;
+            }
+        });
+        self.created_lookups.insert(key.clone());
+    }
+
+    pub(super) fn handle_container_created_at(
+        &mut self,
+        key: ContainerKey,
+        created: DateTime<Utc>,
+    ) -> RenderAction {
+        let Some(container) = self.containers.get_mut(&key) else {
+            return RenderAction::None;
+        };
+        container.created = Some(created);
+        self.force_sort_containers();
+        RenderAction::Render
+    }
 }
@@ -0,0 +1,149 @@
+//! Custom action handlers
+//!
+//! Commands from the `custom_actions` config are listed after the built-in
+//! actions in the action menu of running containers. Selecting one runs it
+//! non-interactively inside the container and opens a popup that shows its
+//! output once the command finishes (`CustomActionFinished`).
+//!
+//! The built-in Exec action reuses the same popup for a one-off command typed
+//! into the action prompt.
+
+use crate::core::app_state::AppState;
+use crate::core::types::{
+    AppEvent, ContainerKey, ContainerState, CustomAction, CustomActionRun, RenderAction, ViewState,
+};
+use crate::docker::connection::DockerHost;
+
+impl AppState {
+    /// Custom actions offered for a container in this state (commands need a running container)
+    pub fn custom_actions_for(&self, state: &ContainerState) -> &[CustomAction] {
+        if *state == ContainerState::Running {
+            &self.custom_actions
+        } else {
+            &[]
+        }
+    }
+
+    /// Starts the custom action at `index` and opens the output popup
+    pub(super) fn run_custom_action(&mut self, key: ContainerKey, index: usize) -> RenderAction {
+        let Some(action) = self.custom_actions.get(index) else {
+            return RenderAction::None;
+        };
+        let Some(container) = self.containers.get(&key) else {
+            return RenderAction::None;
+        };
+        let Some(host) = self.connected_hosts.get(&key.host_id) else {
+            return RenderAction::None;
+        };
+
+        let label = action.label.clone();
+        let command = action.command_for(&key.container_id, &container.name);
+        let host = host.clone();
+        self.spawn_command_run(host, key, label, command)
+    }
+
+    /// Runs `command` in the container in the background and opens the output popup
+    pub(super) fn spawn_command_run(
+        &mut self,
+        host: DockerHost,
+        key: ContainerKey,
+        label: String,
+        command: String,
+    ) -> RenderAction {
+        let key_clone = key.clone();
+        let label_clone = label.clone();
+        let tx_clone = self.event_tx.clone();
+
+        tokio::spawn(async move {
+            let result = host.run_command(&key_clone.container_id, &command).await;
+            let _ = tx_clone
+                .send(AppEvent::CustomActionFinished(
+                    key_clone,
+                    label_clone,
+                    result,
+                ))
+                .await;
+        });
+
+        self.custom_action_run = Some(CustomActionRun {
+            container_key: key,
+            label,
+            output: None,
+            scroll_back: 0,
+        });
+        self.action_menu_state.select(None);
+        self.view_state = ViewState::CustomActionOutput;
+
+        RenderAction::Render
+    }
+
+    pub(super) fn handle_custom_action_finished(
+        &mut self,
+        key: ContainerKey,
+        label: String,
+        result: Result<String, String>,
+    ) -> RenderAction {
+        if let Err(error) = &result {
+            self.record_error(format!("[{}] {} failed: {}", key.host_id, label, error));
+        }
+
+        // Ignore results of a run whose popup has been closed since
+        let Some(run) = &mut self.custom_action_run else {
+            return RenderAction::None;
+        };
+        if run.container_key != key || run.label != label || run.output.is_some() {
+            return RenderAction::None;
+        }
+
+        run.output = Some(result);
+
+        if self.view_state == ViewState::CustomActionOutput {
+            RenderAction::Render
+        } else {
+            RenderAction::None
+        }
+    }
+
+    pub(super) fn handle_custom_action_output_key(
+        &mut self,
+        key: crossterm::event::KeyEvent,
+    ) -> RenderAction {
+        use crossterm::event::KeyCode;
+
+        match key.code {
+            KeyCode::Esc | KeyCode::Enter | KeyCode::Left | KeyCode::Char('h') => {
+                self.custom_action_run = None;
+                self.view_state = ViewState::ContainerList;
+                RenderAction::Render
+            }
+            KeyCode::Char('q') => self.handle_quit(),
+            KeyCode::Up | KeyCode::Char('k') => self.scroll_custom_action_output(1),
+            KeyCode::Down | KeyCode::Char('j') => self.scroll_custom_action_output(-1),
+            KeyCode::PageUp => self.scroll_custom_action_output(10),
+            KeyCode::PageDown => self.scroll_custom_action_output(-10),
+            KeyCode::Home | KeyCode::Char('g') => self.scroll_custom_action_output(isize::MAX),
+            KeyCode::End | KeyCode::Char('G') => self.scroll_custom_action_output(isize::MIN),
+            _ => RenderAction::None,
+        }
+    }
+
+    /// Scrolls the output popup by `lines` towards the start of the output
+    /// (negative values scroll back towards the end)
+    fn scroll_custom_action_output(&mut self, lines: isize) -> RenderAction {
+        let Some(run) = &mut self.custom_action_run else {
+            return RenderAction::None;
+        };
+        let Some(Ok(output)) = &run.output else {
+            return RenderAction::None;
+        };
+
+        // The popup clamps further so the first line stays at the top
+        let max = output.lines().count();
+        let scroll_back = run.scroll_back.saturating_add_signed(lines).min(max);
+        if scroll_back == run.scroll_back {
+            return RenderAction::None;
+        }
+        run.scroll_back = scroll_back;
+        RenderAction::Render
+    }
+}
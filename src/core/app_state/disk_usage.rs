@@ -0,0 +1,78 @@
+//! Disk usage summary handlers
+//!
+//! The summary is fetched on demand (one `docker system df` call per connected
+//! host) each time the popup is opened; results arrive asynchronously as
+//! `DiskUsageLoaded`/`DiskUsageError` events.
+
+use crate::core::app_state::AppState;
+use crate::core::types::{AppEvent, DiskUsageSummary, HostId, RenderAction, ViewState};
+
+impl AppState {
+    pub(super) fn handle_open_disk_usage(&mut self) -> RenderAction {
+        if self.view_state != ViewState::ContainerList {
+            return RenderAction::None;
+        }
+
+        self.view_state = ViewState::DiskUsage;
+        // Drop results from a previous opening so stale numbers are never shown
+        self.disk_usage.clear();
+
+        for host in self.connected_hosts.values() {
+            let host_clone = host.clone();
+            let tx_clone = self.event_tx.clone();
+
+            tokio::spawn(async move {
+                let event = match host_clone.fetch_disk_usage().await {
+                    Ok(summary) => AppEvent::DiskUsageLoaded(host_clone.host_id.clone(), summary),
+                    Err(e) => AppEvent::DiskUsageError(host_clone.host_id.clone(), e),
+                };
+                let _ = tx_clone.send(event).await;
+            });
+        }
+
+        RenderAction::Render
+    }
+
+    pub(super) fn handle_disk_usage_key(
+        &mut self,
+        key: crossterm::event::KeyEvent,
+    ) -> RenderAction {
+        use crossterm::event::KeyCode;
+
+        match key.code {
+            KeyCode::Esc | KeyCode::Char('d') => {
+                self.view_state = ViewState::ContainerList;
+                RenderAction::Render
+            }
+            KeyCode::Char('q') => self.handle_quit(),
+            _ => RenderAction::None,
+        }
+    }
+
+    pub(super) fn handle_disk_usage_loaded(
+        &mut self,
+        host_id: HostId,
+        summary: DiskUsageSummary,
+    ) -> RenderAction {
+        self.disk_usage.insert(host_id, Ok(summary));
+        self.disk_usage_render_action()
+    }
+
+    pub(super) fn handle_disk_usage_error(
+        &mut self,
+        host_id: HostId,
+        error: String,
+    ) -> RenderAction {
+        self.disk_usage.insert(host_id, Err(error));
+        self.disk_usage_render_action()
+    }
+
+    /// Only redraw for disk usage results while the popup is open
+    fn disk_usage_render_action(&self) -> RenderAction {
+        if self.view_state == ViewState::DiskUsage {
+            RenderAction::Render
+        } else {
+            RenderAction::None
+        }
+    }
+}
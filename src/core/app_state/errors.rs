@@ -0,0 +1,54 @@
+//! Recent error history
+//!
+//! Connection and action errors are only shown briefly, so the most recent ones
+//! are kept here and can be copied to the clipboard ('e' for the latest, 'E' for
+//! all of them) when filing a bug report.
+
+use crate::core::app_state::AppState;
+use crate::core::types::{RenderAction, ViewState};
+
+/// How many errors are remembered for copying
+pub const MAX_RECENT_ERRORS: usize = 20;
+
+impl AppState {
+    /// Remembers an error, dropping the oldest once the history is full
+    pub(super) fn record_error(&mut self, message: String) {
+        if self.recent_errors.len() == MAX_RECENT_ERRORS {
+            self.recent_errors.pop_front();
+        }
+        self.recent_errors.push_back(message);
+    }
+
+    /// Copies the latest error (or every remembered one, oldest first) to the clipboard
+    pub(super) fn handle_copy_errors(&mut self, all: bool) -> RenderAction {
+        if self.view_state != ViewState::ContainerList {
+            return RenderAction::None;
+        }
+
+        let text = if all {
+            self.recent_errors
+                .iter()
+                .map(String::as_str)
+                .collect::<Vec<_>>()
+                .join("\n")
+        } else {
+            match self.recent_errors.back() {
+                Some(error) => error.clone(),
+                None => String::new(),
+            }
+        };
+
+        if text.is_empty() {
+            self.show_notification("No errors to copy");
+            return RenderAction::Render;
+        }
+
+        let count = if all { self.recent_errors.len() } else { 1 };
+        self.show_notification(&format!(
+            "Copied {} error{} to clipboard",
+            count,
+            if count == 1 { "" } else { "s" }
+        ));
+        RenderAction::CopyToClipboard(text)
+    }
+}
@@ -0,0 +1,253 @@
+//! Container snapshot export ('x'): writes every known container's stats to a
+//! timestamped JSON file in the current directory, for sharing the current view.
+//! 's' in the log view likewise saves the loaded log lines to a plain-text file.
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use std::path::{Path, PathBuf};
+
+use crate::core::app_state::AppState;
+use crate::core::types::{Container, LogState, RenderAction, ViewState};
+
+/// Top-level shape of an exported snapshot file
+#[derive(Serialize)]
+struct Snapshot<'a> {
+    exported_at: String,
+    containers: Vec<ExportedContainer<'a>>,
+}
+
+/// One container in a snapshot file
+#[derive(Serialize)]
+struct ExportedContainer<'a> {
+    host: &'a str,
+    id: &'a str,
+    name: &'a str,
+    state: &'static str,
+    cpu: f64,
+    memory: f64,
+    memory_used_bytes: u64,
+    memory_limit_bytes: u64,
+    network_tx_bytes_per_sec: f64,
+    network_rx_bytes_per_sec: f64,
+}
+
+impl<'a> From<&'a Container> for ExportedContainer<'a> {
+    fn from(c: &'a Container) -> Self {
+        Self {
+            host: &c.host_id,
+            id: &c.id,
+            name: &c.name,
+            state: c.state.as_str(),
+            cpu: c.stats.cpu,
+            memory: c.stats.memory,
+            memory_used_bytes: c.stats.memory_used_bytes,
+            memory_limit_bytes: c.stats.memory_limit_bytes,
+            network_tx_bytes_per_sec: c.stats.network_tx_bytes_per_sec,
+            network_rx_bytes_per_sec: c.stats.network_rx_bytes_per_sec,
+        }
+    }
+}
+
+impl AppState {
+    /// Handles 'x': exports all containers (not just the filtered list) to
+    /// `dtop-snapshot-<timestamp>.json` in the current directory
+    pub(super) fn handle_export_snapshot(&mut self) -> RenderAction {
+        if self.view_state != ViewState::ContainerList {
+            return RenderAction::None;
+        }
+
+        match self.write_snapshot(Path::new("."), Utc::now()) {
+            Ok(path) => {
+                self.show_notification(&format!("Exported snapshot to {}", path.display()));
+            }
+            Err(e) => {
+                tracing::error!("Failed to export snapshot: {}", e);
+                self.record_error(format!("Snapshot export failed: {}", e));
+                self.show_error_notification(&format!("Export failed: {}", e));
+            }
+        }
+
+        RenderAction::Render
+    }
+
+    /// Writes the snapshot file into `dir` and returns its path
+    fn write_snapshot(
+        &self,
+        dir: &Path,
+        now: DateTime<Utc>,
+    ) -> Result<PathBuf, Box<dyn std::error::Error + Send + Sync>> {
+        let path = dir.join(format!(
+            "dtop-snapshot-{}.json",
+            now.format("%Y%m%d-%H%M%S")
+        ));
+        std::fs::write(&path, self.snapshot_json(now)?)?;
+        Ok(path)
+    }
+
+    /// Handles 's' in the log view: saves the loaded log lines (all of them, not
+    /// just those matching the filter) to `<container>-<timestamp>.log` in the
+    /// current directory
+    pub(super) fn handle_save_logs(&mut self) -> RenderAction {
+        let Some(log_state) = &self.log_state else {
+            return RenderAction::None;
+        };
+        let name = self
+            .containers
+            .get(&log_state.container_key)
+            .map_or(log_state.container_key.container_id.as_str(), |c| {
+                c.name.as_str()
+            });
+
+        match write_logs(log_state, name, Path::new("."), Utc::now()) {
+            Ok((path, lines)) => {
+                self.show_notification(&format!("Saved {} log lines to {}", lines, path.display()));
+            }
+            Err(e) => {
+                tracing::error!("Failed to save logs: {}", e);
+                self.record_error(format!("Saving logs failed: {}", e));
+                self.show_error_notification(&format!("Saving logs failed: {}", e));
+            }
+        }
+
+        RenderAction::Render
+    }
+
+    /// Returns every known container as a pretty-printed snapshot document, the
+    /// format of both the 'x' export and `dtop --once --format json`
+    pub fn snapshot_json(&self, now: DateTime<Utc>) -> serde_json::Result<String> {
+        // Stable order so snapshots of the same fleet diff cleanly
+        let mut containers: Vec<&Container> = self.containers.values().collect();
+        containers.sort_by(|a, b| (&a.host_id, &a.name).cmp(&(&b.host_id, &b.name)));
+
+        let snapshot = Snapshot {
+            exported_at: now.to_rfc3339(),
+            containers: containers
+                .into_iter()
+                .map(ExportedContainer::from)
+                .collect(),
+        };
+        serde_json::to_string_pretty(&snapshot)
+    }
+}
+
+/// Writes the entries of `log_state` into `dir` as one "<RFC 3339 timestamp> <text>"
+/// line each, without colors, and returns the file's path and line count
+fn write_logs(
+    log_state: &LogState,
+    name: &str,
+    dir: &Path,
+    now: DateTime<Utc>,
+) -> std::io::Result<(PathBuf, usize)> {
+    let path = dir.join(format!("{}-{}.log", name, now.format("%Y%m%d-%H%M%S")));
+    let mut out = String::new();
+    let mut lines = 0;
+    for entry in &log_state.log_entries {
+        for line in &entry.text.lines {
+            out.push_str(&entry.timestamp.to_rfc3339());
+            out.push(' ');
+            out.push_str(&line.to_string());
+            out.push('\n');
+            lines += 1;
+        }
+    }
+    std::fs::write(&path, out)?;
+    Ok((path, lines))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::types::{Column, ColumnConfig, ContainerKey, ContainerState, ContainerStats};
+    use std::collections::HashMap;
+
+    fn container(host: &str, id: &str, name: &str, cpu: f64) -> Container {
+        Container {
+            id: id.to_string(),
+            full_id: id.to_string(),
+            name: name.to_string(),
+            image: "nginx:latest".to_string(),
+            state: ContainerState::Running,
+            health: None,
+            created: None,
+            stats: ContainerStats {
+                cpu,
+                ..Default::default()
+            },
+            host_id: host.to_string(),
+            dozzle_url: None,
+            restart_count: None,
+            compose_project: None,
+            size: None,
+            health_check: None,
+            last_stats: None,
+            exit_code: None,
+            ports: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_write_snapshot() {
+        let (tx, _rx) = tokio::sync::mpsc::channel(1);
+        let mut state = AppState::new(
+            HashMap::new(),
+            tx,
+            false,
+            Column::Uptime,
+            None,
+            ColumnConfig::default(),
+            None,
+        );
+        for c in [
+            container("server1", "bbb222222222", "db", 40.0),
+            container("local", "aaa111111111", "web", 12.5),
+        ] {
+            state
+                .containers
+                .insert(ContainerKey::new(c.host_id.clone(), c.id.clone()), c);
+        }
+
+        let dir = std::env::temp_dir();
+        let now = DateTime::parse_from_rfc3339("2026-01-02T03:04:05Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let path = state.write_snapshot(&dir, now).unwrap();
+        assert_eq!(path, dir.join("dtop-snapshot-20260102-030405.json"));
+
+        let json: serde_json::Value =
+            serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(json["exported_at"], "2026-01-02T03:04:05+00:00");
+        assert_eq!(json["containers"][0]["host"], "local");
+        assert_eq!(json["containers"][0]["cpu"], 12.5);
+        assert_eq!(json["containers"][1]["name"], "db");
+        assert_eq!(json["containers"][1]["state"], "running");
+    }
+
+    #[test]
+    fn test_write_logs_strips_colors() {
+        let mut log_state = LogState::new(ContainerKey::new("local".into(), "abc".into()));
+        log_state.log_entries = [
+            "2026-01-02T03:04:05.5Z \x1b[31merror\x1b[0m: disk full",
+            "2026-01-02T03:04:06Z retrying",
+        ]
+        .iter()
+        .filter_map(|line| crate::docker::logs::LogEntry::parse(line))
+        .collect();
+
+        let dir = std::env::temp_dir();
+        let now = DateTime::parse_from_rfc3339("2026-01-02T03:04:07Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let (path, lines) = write_logs(&log_state, "web", &dir, now).unwrap();
+        assert_eq!(path, dir.join("web-20260102-030407.log"));
+        assert_eq!(lines, 2);
+
+        let contents = std::fs::read_to_string(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(
+            contents,
+            "2026-01-02T03:04:05.500+00:00 error: disk full\n2026-01-02T03:04:06+00:00 retrying\n"
+        );
+    }
+}
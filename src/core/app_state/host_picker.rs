@@ -0,0 +1,126 @@
+//! Host picker handlers
+//!
+//! 'H' opens a popup listing every known host with its connection status,
+//! container counts and CPU/memory usage. Typing fuzzy-filters the list
+//! (the query's characters must appear in the host name in order), and Enter
+//! filters the container list to the chosen host. Choosing the filtered host
+//! again, or Esc in the container list, shows every host again.
+
+use crate::core::app_state::AppState;
+use crate::core::types::{HostId, RenderAction, ViewState};
+
+impl AppState {
+    pub(super) fn handle_open_host_picker(&mut self) -> RenderAction {
+        if self.view_state != ViewState::ContainerList {
+            return RenderAction::None;
+        }
+
+        self.view_state = ViewState::HostPicker;
+        self.host_picker_input.reset();
+        self.host_picker_state.select(Some(0));
+        RenderAction::Render
+    }
+
+    /// Known hosts matching the picker query, in name order
+    pub fn host_picker_matches(&self) -> Vec<&HostId> {
+        let query = self.host_picker_input.value();
+        let mut hosts: Vec<&HostId> = self
+            .host_status
+            .keys()
+            .filter(|host_id| fuzzy_matches(query, host_id))
+            .collect();
+        hosts.sort();
+        hosts
+    }
+
+    pub(super) fn handle_host_picker_key(
+        &mut self,
+        key: crossterm::event::KeyEvent,
+    ) -> RenderAction {
+        use crossterm::event::KeyCode;
+
+        let match_count = self.host_picker_matches().len();
+
+        match key.code {
+            KeyCode::Esc => self.close_host_picker(),
+            KeyCode::Up => {
+                let current = self.host_picker_state.selected().unwrap_or(0);
+                self.host_picker_state
+                    .select(Some(current.saturating_sub(1)));
+                RenderAction::Render
+            }
+            KeyCode::Down | KeyCode::Tab => {
+                let current = self.host_picker_state.selected().unwrap_or(0);
+                let target = (current + 1).min(match_count.saturating_sub(1));
+                self.host_picker_state.select(Some(target));
+                RenderAction::Render
+            }
+            KeyCode::Enter => {
+                let selected = self.host_picker_state.selected().unwrap_or(0);
+                let Some(host_id) = self
+                    .host_picker_matches()
+                    .get(selected)
+                    .map(|h| (*h).clone())
+                else {
+                    return RenderAction::None;
+                };
+
+                // Choosing the host already filtered on shows every host again
+                let filter = (self.host_filter.as_ref() != Some(&host_id)).then_some(host_id);
+                self.close_host_picker();
+                self.set_host_filter(filter)
+            }
+            _ => {
+                use tui_input::backend::crossterm::EventHandler;
+                self.host_picker_input
+                    .handle_event(&crossterm::event::Event::Key(key));
+                // The best match is listed first after the list narrows
+                self.host_picker_state.select(Some(0));
+                RenderAction::Render
+            }
+        }
+    }
+
+    /// Lists only the containers on `host_id`, or every host's with `None`, and
+    /// selects the first row
+    pub(super) fn set_host_filter(&mut self, host_id: Option<HostId>) -> RenderAction {
+        self.host_filter = host_id;
+        self.list_scroll = None;
+        self.force_sort_containers();
+        if !self.sorted_container_keys.is_empty() {
+            self.table_state.select(Some(0));
+        }
+        RenderAction::Render
+    }
+
+    fn close_host_picker(&mut self) -> RenderAction {
+        self.view_state = ViewState::ContainerList;
+        self.host_picker_input.reset();
+        self.host_picker_state.select(None);
+        RenderAction::Render
+    }
+}
+
+/// Case-insensitive subsequence match: every character of `query` appears in
+/// `candidate` in order (e.g. "prd2" matches "ssh://prod-web-2")
+fn fuzzy_matches(query: &str, candidate: &str) -> bool {
+    let mut candidate = candidate.chars().flat_map(char::to_lowercase);
+    query
+        .chars()
+        .flat_map(char::to_lowercase)
+        .all(|q| candidate.any(|c| c == q))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fuzzy_matches() {
+        assert!(fuzzy_matches("", "local"));
+        assert!(fuzzy_matches("prd2", "ssh://prod-web-2"));
+        assert!(fuzzy_matches("WEB", "ssh://prod-web-2"));
+        assert!(!fuzzy_matches("2prd", "ssh://prod-web-2"));
+        assert!(!fuzzy_matches("localx", "local"));
+    }
+}
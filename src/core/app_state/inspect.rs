@@ -0,0 +1,87 @@
+//! Inspect view ('i')
+//!
+//! Shows a summary of `docker inspect` for the selected container: image,
+//! command, ports, environment, mounts and labels. The inspect request runs in
+//! a spawned task and its result arrives as `InspectResult`. Scrolling goes
+//! through the log view's scroll handlers, which move `InspectView::scroll_offset`
+//! while this view is open.
+
+use crate::core::app_state::AppState;
+use crate::core::types::{AppEvent, ContainerKey, InspectView, RenderAction, ViewState};
+
+impl AppState {
+    /// Opens the inspect view for the selected container and fetches its summary
+    pub(super) fn handle_open_inspect(&mut self) -> RenderAction {
+        if self.view_state != ViewState::ContainerList {
+            return RenderAction::None;
+        }
+        let Some(key) = self
+            .table_state
+            .selected()
+            .and_then(|i| self.sorted_container_keys.get(i))
+            .cloned()
+        else {
+            return RenderAction::None;
+        };
+        let Some(host) = self.connected_hosts.get(&key.host_id) else {
+            return RenderAction::None;
+        };
+
+        let host_clone = host.clone();
+        let key_clone = key.clone();
+        let tx_clone = self.event_tx.clone();
+        tokio::spawn(async move {
+            let text = host_clone
+                .inspect_summary(&key_clone.container_id)
+                .await
+                .unwrap_or_else(|e| e);
+            let _ = tx_clone
+                .send(AppEvent::InspectResult(key_clone, text))
+                .await;
+        });
+
+        self.inspect_view = Some(InspectView::default());
+        self.view_state = ViewState::Inspect(key);
+
+        RenderAction::Render
+    }
+
+    pub(super) fn handle_inspect_result(
+        &mut self,
+        key: ContainerKey,
+        text: String,
+    ) -> RenderAction {
+        // Ignore results for a view that has been closed (or moved on) since
+        if self.view_state != ViewState::Inspect(key) {
+            return RenderAction::None;
+        }
+        let Some(view) = &mut self.inspect_view else {
+            return RenderAction::None;
+        };
+
+        view.text = Some(text);
+        RenderAction::Render
+    }
+
+    /// Closes the inspect view, back to the container list
+    pub(super) fn handle_close_inspect(&mut self) -> RenderAction {
+        if !matches!(self.view_state, ViewState::Inspect(_)) {
+            return RenderAction::None;
+        }
+
+        self.inspect_view = None;
+        self.view_state = ViewState::ContainerList;
+        RenderAction::Render
+    }
+
+    /// Scrolls the inspect view by `lines` (negative is up); the offset is
+    /// clamped to the text when rendering
+    pub(super) fn scroll_inspect(&mut self, lines: isize) -> RenderAction {
+        let Some(view) = &mut self.inspect_view else {
+            return RenderAction::None;
+        };
+
+        view.scroll_offset = view.scroll_offset.saturating_add_signed(lines);
+        RenderAction::Render
+    }
+}
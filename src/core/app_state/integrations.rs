@@ -43,4 +43,25 @@ impl AppState {
 
         RenderAction::None // No need to force draw
     }
+
+    /// Copies the selected container's full ID to the clipboard (OSC 52, so it
+    /// also works over SSH)
+    pub(super) fn handle_copy_container_id(&mut self) -> RenderAction {
+        if self.view_state != ViewState::ContainerList {
+            return RenderAction::None;
+        }
+
+        let Some(container) = self
+            .table_state
+            .selected()
+            .and_then(|i| self.sorted_container_keys.get(i))
+            .and_then(|key| self.containers.get(key))
+        else {
+            return RenderAction::None;
+        };
+
+        let full_id = container.full_id.clone();
+        self.show_notification(&format!("Copied ID of {} to clipboard", container.name));
+        RenderAction::CopyToClipboard(full_id)
+    }
 }
@@ -1,5 +1,7 @@
+use tui_input::Input;
+
 use crate::core::app_state::AppState;
-use crate::core::types::{ContainerKey, LogState, RenderAction, ViewState};
+use crate::core::types::{ContainerKey, HostStatus, LogState, RenderAction, ViewState};
 use crate::docker::logs::{LogEntry, fetch_older_logs};
 
 impl AppState {
@@ -41,14 +43,38 @@ impl AppState {
             return RenderAction::None;
         };
 
-        // Get container creation time for progress calculation
-        let container_created_at = self.containers.get(container_key).and_then(|c| c.created);
+        let container_key = container_key.clone();
+
+        // The split pane may already be following this container
+        if self
+            .log_state
+            .as_ref()
+            .is_none_or(|state| state.container_key != container_key)
+        {
+            self.open_log_state(container_key.clone());
+        }
+
+        // Reset scroll state - start at bottom, following
+        self.is_at_bottom = true;
+        self.log_follow = true;
+
+        // Switch to log view
+        self.view_state = ViewState::LogView(container_key);
 
+        RenderAction::Render // Force draw - view changed
+    }
+
+    /// Replaces the log state with a fresh one for `container_key` and starts
+    /// streaming its logs
+    fn open_log_state(&mut self, container_key: ContainerKey) {
         // Create new log state for this container
-        let mut new_log_state = LogState::new(container_key.clone(), container_created_at);
+        let mut new_log_state = LogState::new(container_key.clone());
 
-        // Start streaming logs for this container
-        if let Some(host) = self.connected_hosts.get(&container_key.host_id) {
+        // Start streaming logs for this container. A host that lost its connection
+        // stays in `connected_hosts`, so check its status first.
+        if self.host_status.get(&container_key.host_id) == Some(&HostStatus::Failed) {
+            new_log_state.host_disconnected = true;
+        } else if let Some(host) = self.connected_hosts.get(&container_key.host_id) {
             let host_clone = host.clone();
             let container_id = container_key.container_id.clone();
             let tx_clone = self.event_tx.clone();
@@ -61,16 +87,90 @@ impl AppState {
             new_log_state.stream_handle = Some(handle);
         }
 
-        // Set the log state
+        // Set the log state (dropping the previous one stops its stream)
         self.log_state = Some(new_log_state);
+    }
+
+    /// Toggles the split pane: the container list on top and a live tail of
+    /// the selected container's logs below it
+    pub(super) fn handle_toggle_split_logs(&mut self) -> RenderAction {
+        if self.view_state != ViewState::ContainerList {
+            return RenderAction::None;
+        }
+
+        self.split_logs = !self.split_logs;
+        if !self.split_logs {
+            self.log_state = None;
+        }
+        self.sync_split_logs();
+        RenderAction::Render
+    }
+
+    /// Keeps the split pane on the selected container, restarting the log
+    /// stream when the selection moved to another one. Called after every event,
+    /// since the selection changes with keys, sorting and containers coming and going.
+    pub(super) fn sync_split_logs(&mut self) {
+        if !self.split_logs
+            || !matches!(
+                self.view_state,
+                ViewState::ContainerList | ViewState::SearchMode
+            )
+        {
+            return;
+        }
+
+        let selected = self
+            .table_state
+            .selected()
+            .and_then(|idx| self.sorted_container_keys.get(idx));
+        let Some(selected) = selected else {
+            self.log_state = None;
+            return;
+        };
 
-        // Reset scroll state - start at bottom
+        if self
+            .log_state
+            .as_ref()
+            .is_some_and(|state| &state.container_key == selected)
+        {
+            return;
+        }
+
+        self.open_log_state(selected.clone());
         self.is_at_bottom = true;
+    }
 
-        // Switch to log view
-        self.view_state = ViewState::LogView(container_key.clone());
+    /// Resumes following the open log view's container after it (re)starts.
+    /// The previous follow stream ended when the container stopped (or never
+    /// had anything to follow), so start a new one after the newest line shown.
+    pub(super) fn resume_log_stream(&mut self, key: &ContainerKey) {
+        let Some(state) = &mut self.log_state else {
+            return;
+        };
 
-        RenderAction::Render // Force draw - view changed
+        if &state.container_key != key || state.host_disconnected {
+            return;
+        }
+
+        let Some(host) = self.connected_hosts.get(&key.host_id) else {
+            return;
+        };
+
+        if let Some(handle) = state.stream_handle.take() {
+            handle.abort();
+        }
+
+        let host_clone = host.clone();
+        let container_id = key.container_id.clone();
+        let since = state.newest_timestamp;
+        let tx_clone = self.event_tx.clone();
+
+        let handle = tokio::spawn(async move {
+            use crate::docker::logs::follow_container_logs;
+            follow_container_logs(host_clone, container_id, since, tx_clone).await;
+        });
+
+        state.stream_handle = Some(handle);
     }
 
     pub(super) fn handle_exit_log_view(&mut self) -> RenderAction {
@@ -79,12 +179,12 @@ impl AppState {
             return RenderAction::None;
         }
 
-        // Stop log streaming and cleanup log state
-        if let Some(mut state) = self.log_state.take()
-            && let Some(handle) = state.stream_handle.take()
-        {
-            handle.abort();
-        }
+        // Dropping the log state stops its stream
+        self.log_state = None;
+
+        // A log filter only applies to the logs it was typed for
+        self.log_filter = None;
+        self.log_filter_editing = false;
 
         // Switch back to container list view
         self.view_state = ViewState::ContainerList;
@@ -92,7 +192,134 @@ impl AppState {
         RenderAction::Render // Force draw - view changed
     }
 
+    /// Toggles line numbers in the log view. The setting is kept on `AppState`,
+    /// so it stays in effect when switching to another container's logs.
+    pub(super) fn handle_toggle_log_line_numbers(&mut self) -> RenderAction {
+        if !matches!(self.view_state, ViewState::LogView(_)) {
+            return RenderAction::None;
+        }
+
+        self.show_log_line_numbers = !self.show_log_line_numbers;
+        RenderAction::Render
+    }
+
+    /// Toggles following new lines in the log view. Following again jumps to the
+    /// newest line; pausing keeps the view where it is as lines arrive.
+    pub(super) fn handle_toggle_log_follow(&mut self) -> RenderAction {
+        if !matches!(self.view_state, ViewState::LogView(_)) {
+            return RenderAction::None;
+        }
+
+        self.log_follow = !self.log_follow;
+        if self.log_follow {
+            self.is_at_bottom = true;
+        }
+        RenderAction::Render
+    }
+
+    /// Toggles wrapping of long log lines. Unwrapped lines are cut at the edge
+    /// and ←/→ scroll sideways; like line numbers, the setting carries over.
+    pub(super) fn handle_toggle_log_wrap(&mut self) -> RenderAction {
+        if !matches!(self.view_state, ViewState::LogView(_)) {
+            return RenderAction::None;
+        }
+
+        self.log_wrap = !self.log_wrap;
+        if let Some(state) = &mut self.log_state {
+            state.horizontal_offset = 0;
+        }
+        RenderAction::Render
+    }
+
+    /// Scrolls unwrapped log lines sideways by `columns` (negative is left)
+    pub(super) fn handle_scroll_horizontal(&mut self, columns: isize) -> RenderAction {
+        if self.log_wrap || !matches!(self.view_state, ViewState::LogView(_)) {
+            return RenderAction::None;
+        }
+        let Some(state) = &mut self.log_state else {
+            return RenderAction::None;
+        };
+
+        state.horizontal_offset = state.horizontal_offset.saturating_add_signed(columns);
+        RenderAction::Render
+    }
+
+    /// Toggles the timestamp prefix in the log view. Lines are formatted once as
+    /// they arrive, so the ones already loaded are re-formatted from their entries.
+    pub(super) fn handle_toggle_log_timestamps(&mut self) -> RenderAction {
+        if !matches!(self.view_state, ViewState::LogView(_)) {
+            return RenderAction::None;
+        }
+
+        self.show_log_timestamps = !self.show_log_timestamps;
+        if let Some(state) = &mut self.log_state {
+            state.rebuild_formatted_lines(
+                self.show_log_timestamps,
+                self.timezone,
+                self.log_filter.as_deref(),
+            );
+        }
+        RenderAction::Render
+    }
+
+    /// Handles 'f' in the log view: starts editing the log filter, keeping the
+    /// current query
+    pub(super) fn handle_start_log_filter(&mut self) -> RenderAction {
+        if !matches!(self.view_state, ViewState::LogView(_)) {
+            return RenderAction::None;
+        }
+
+        self.log_filter_input = Input::new(self.log_filter.clone().unwrap_or_default());
+        self.log_filter_editing = true;
+        RenderAction::Render
+    }
+
+    /// Handles keys while the log filter is being typed. The filter applies as
+    /// you type; Enter keeps it and Esc clears it.
+    pub(super) fn handle_log_filter_key(
+        &mut self,
+        key_event: crossterm::event::KeyEvent,
+    ) -> RenderAction {
+        use crossterm::event::KeyCode;
+        use tui_input::backend::crossterm::EventHandler;
+
+        match key_event.code {
+            KeyCode::Enter => {
+                self.log_filter_editing = false;
+                return RenderAction::Render;
+            }
+            KeyCode::Esc => {
+                self.log_filter_editing = false;
+                self.log_filter_input.reset();
+            }
+            _ => {
+                self.log_filter_input
+                    .handle_event(&crossterm::event::Event::Key(key_event));
+            }
+        }
+
+        let query = self.log_filter_input.value();
+        let filter = (!query.is_empty()).then(|| query.to_string());
+        if filter != self.log_filter {
+            self.log_filter = filter;
+            if let Some(state) = &mut self.log_state {
+                state.rebuild_formatted_lines(
+                    self.show_log_timestamps,
+                    self.timezone,
+                    self.log_filter.as_deref(),
+                );
+            }
+            // Line positions changed; show the newest matches
+            self.is_at_bottom = true;
+        }
+        RenderAction::Render
+    }
+
     pub(super) fn handle_scroll_up(&mut self) -> RenderAction {
+        if matches!(self.view_state, ViewState::Inspect(_)) {
+            return self.scroll_inspect(-1);
+        }
+
         // Only handle scroll in log view
         if !matches!(self.view_state, ViewState::LogView(_)) {
             return RenderAction::None;
@@ -121,6 +348,10 @@ impl AppState {
     }
 
     pub(super) fn handle_scroll_down(&mut self) -> RenderAction {
+        if matches!(self.view_state, ViewState::Inspect(_)) {
+            return self.scroll_inspect(1);
+        }
+
         // Only handle scroll in log view
         if !matches!(self.view_state, ViewState::LogView(_)) {
             return RenderAction::None;
@@ -138,6 +369,10 @@ impl AppState {
     }
 
     pub(super) fn handle_scroll_to_top(&mut self) -> RenderAction {
+        if matches!(self.view_state, ViewState::Inspect(_)) {
+            return self.scroll_inspect(isize::MIN);
+        }
+
         // Only handle in log view
         if !matches!(self.view_state, ViewState::LogView(_)) {
             return RenderAction::None;
@@ -158,17 +393,29 @@ impl AppState {
     }
 
     pub(super) fn handle_scroll_to_bottom(&mut self) -> RenderAction {
+        if matches!(self.view_state, ViewState::Inspect(_)) {
+            return self.scroll_inspect(isize::MAX);
+        }
+
         // Only handle in log view
         if !matches!(self.view_state, ViewState::LogView(_)) {
             return RenderAction::None;
         }
 
-        // Set is_at_bottom - the actual offset will be calculated in render
+        // Set is_at_bottom - the actual offset will be calculated in render.
+        // A paused view doesn't pin itself to the bottom, so move the offset there too.
         self.is_at_bottom = true;
+        if let Some(state) = &mut self.log_state {
+            state.scroll_offset = usize::MAX;
+        }
         RenderAction::Render
     }
 
     pub(super) fn handle_scroll_page_up(&mut self) -> RenderAction {
+        if matches!(self.view_state, ViewState::Inspect(_)) {
+            return self.scroll_inspect(-((self.last_viewport_height / 2) as isize));
+        }
+
         // Only handle in log view
         if !matches!(self.view_state, ViewState::LogView(_)) {
             return RenderAction::None;
@@ -194,6 +441,10 @@ impl AppState {
     }
 
     pub(super) fn handle_scroll_page_down(&mut self) -> RenderAction {
+        if matches!(self.view_state, ViewState::Inspect(_)) {
+            return self.scroll_inspect((self.last_viewport_height / 2) as isize);
+        }
+
         // Only handle in log view
         if !matches!(self.view_state, ViewState::LogView(_)) {
             return RenderAction::None;
@@ -228,8 +479,17 @@ impl AppState {
         // Extract timestamp before moving log_entry
         let timestamp = log_entry.timestamp;
 
-        // Format and cache the line before storing the entry
-        state.formatted_lines.push(log_entry.format());
+        // Format and cache the line before storing the entry, unless it is filtered out
+        if self
+            .log_filter
+            .as_deref()
+            .is_none_or(|query| log_entry.matches_filter(query))
+        {
+            state
+                .formatted_lines
+                .push(log_entry.format(self.show_log_timestamps, self.timezone));
+            state.line_entries.push(state.log_entries.len());
+        }
 
         // Store the raw log entry (already owned, no clone needed)
         state.log_entries.push(log_entry);
@@ -237,14 +497,41 @@ impl AppState {
         // Update newest timestamp for progress calculation
         state.newest_timestamp = Some(timestamp);
 
+        // Keep memory bounded on chatty containers. Trimming a tenth below the cap
+        // at once avoids shifting the whole buffer for every new line; the dropped
+        // lines can be fetched again by scrolling to the top.
+        let max_lines = self.log_max_lines.max(1);
+        if state.log_entries.len() > max_lines {
+            let excess = state.log_entries.len() - (max_lines - max_lines / 10);
+            let width = self.last_viewport_width;
+            let dropped_lines = state.line_entries.partition_point(|&i| i < excess);
+            let dropped_rows: usize = state
+                .formatted_lines
+                .drain(..dropped_lines)
+                .map(|line| visual_rows(&line, width))
+                .sum();
+            state.line_entries.drain(..dropped_lines);
+            for i in &mut state.line_entries {
+                *i -= excess;
+            }
+            state.log_entries.drain(..excess);
+
+            // Keep the view on the same lines (offsets are in visual rows)
+            state.scroll_offset = state.scroll_offset.saturating_sub(dropped_rows);
+            state.max_scroll = state.max_scroll.saturating_sub(dropped_rows);
+            state.oldest_timestamp = state.log_entries.first().map(|e| e.timestamp);
+            state.has_more_history = true;
+            state.truncated = true;
+        }
+
         RenderAction::Render
     }
 
     pub(super) fn handle_log_batch_prepend(
         &mut self,
         key: ContainerKey,
-        log_entries: Vec<LogEntry>,
-        has_more_history: bool,
+        mut log_entries: Vec<LogEntry>,
+        mut has_more_history: bool,
     ) -> RenderAction {
         // Only process if viewing this container
         let Some(state) = &mut self.log_state else {
@@ -266,29 +553,68 @@ impl AppState {
             state.total_loaded
         );
 
+        // The initial load shows the newest lines, so any past the cap go from the top
+        let max_lines = self.log_max_lines.max(1);
+        if is_initial_load && log_entries.len() > max_lines {
+            log_entries.drain(..log_entries.len() - max_lines);
+            has_more_history = true;
+            state.truncated = true;
+        }
+
         // Extract timestamps before moving log_entries
         let oldest = log_entries.first().map(|e| e.timestamp);
         let newest = log_entries.last().map(|e| e.timestamp);
         let num_entries = log_entries.len();
 
-        // Format prepended entries and build the new formatted_lines cache
-        let mut new_formatted: Vec<ratatui::text::Line<'static>> =
-            log_entries.iter().map(|e| e.format()).collect();
+        // Format the prepended entries that pass the filter
+        let mut new_formatted: Vec<ratatui::text::Line<'static>> = Vec::new();
+        let mut new_line_entries: Vec<usize> = Vec::new();
+        for (i, entry) in log_entries.iter().enumerate() {
+            if self
+                .log_filter
+                .as_deref()
+                .is_none_or(|query| entry.matches_filter(query))
+            {
+                new_formatted.push(entry.format(self.show_log_timestamps, self.timezone));
+                new_line_entries.push(i);
+            }
+        }
+        let num_lines = new_formatted.len();
 
         // Prepend raw log entries to the beginning
         let mut new_entries = log_entries;
         new_entries.append(&mut state.log_entries);
         state.log_entries = new_entries;
 
-        // Prepend formatted lines
+        // Prepend formatted lines; the existing ones now sit after the new entries
         new_formatted.append(&mut state.formatted_lines);
         state.formatted_lines = new_formatted;
+        new_line_entries.extend(state.line_entries.iter().map(|i| i + num_entries));
+        state.line_entries = new_line_entries;
 
         state.oldest_timestamp = oldest;
         state.has_more_history = has_more_history;
         state.total_loaded += num_entries;
         state.fetching_older = false;
 
+        // Paging back reads the oldest lines, so the newest ones go to stay under the cap
+        if state.log_entries.len() > max_lines {
+            let kept_lines = state.line_entries.partition_point(|&i| i < max_lines);
+            state.line_entries.truncate(kept_lines);
+            let width = self.last_viewport_width;
+            let dropped_rows: usize = state
+                .formatted_lines
+                .drain(kept_lines..)
+                .map(|line| visual_rows(&line, width))
+                .sum();
+            state.log_entries.truncate(max_lines);
+            state.max_scroll = state.max_scroll.saturating_sub(dropped_rows);
+            state.newest_dropped = true;
+        }
+
+        // Only the whole history, with none of its newest lines dropped, is complete
+        state.truncated = state.newest_dropped || (state.truncated && state.has_more_history);
+
         // Update newest timestamp if this is the first batch (initial load)
         if is_initial_load {
             state.newest_timestamp = newest;
@@ -299,18 +625,13 @@ impl AppState {
         // the prepended entries occupy using the cached formatted lines.
         if !is_initial_load {
             let width = self.last_viewport_width;
-            let visual_lines_prepended: usize = state.formatted_lines[..num_entries]
+            let visual_lines_prepended: usize = state.formatted_lines[..num_lines]
                 .iter()
-                .map(|line| {
-                    let w = line.width();
-                    if width == 0 || w <= width {
-                        1
-                    } else {
-                        w.div_ceil(width)
-                    }
-                })
+                .map(|line| visual_rows(line, width))
                 .sum();
             state.scroll_offset += visual_lines_prepended;
+            // Older lines aren't new arrivals, so they don't pull a following view down
+            state.max_scroll += visual_lines_prepended;
         }
 
         RenderAction::Render
@@ -322,6 +643,11 @@ impl AppState {
             return RenderAction::None;
         };
 
+        // Nothing can be fetched once the host is gone
+        if state.host_disconnected {
+            return RenderAction::None;
+        }
+
         // Check if we're already fetching or no more history
         if state.fetching_older {
             tracing::debug!("Already fetching older logs, skipping");
@@ -376,4 +702,32 @@ impl AppState {
 
         RenderAction::None // Don't render yet, wait for LogBatchPrepend
     }
+
+    /// Stops the open log view's stream when its host disconnects, so the view
+    /// says so instead of silently going quiet
+    pub(super) fn handle_log_host_disconnected(&mut self, host_id: &str) {
+        let Some(state) = &mut self.log_state else {
+            return;
+        };
+
+        if state.container_key.host_id != host_id {
+            return;
+        }
+
+        if let Some(handle) = state.stream_handle.take() {
+            handle.abort();
+        }
+        state.fetching_older = false;
+        state.host_disconnected = true;
+    }
+}
+
+/// Rows a log line takes in the log view when wrapped at `width`
+fn visual_rows(line: &ratatui::text::Line, width: usize) -> usize {
+    let w = line.width();
+    if width == 0 || w <= width {
+        1
+    } else {
+        w.div_ceil(width)
+    }
 }
@@ -1,12 +1,14 @@
 use ratatui::widgets::{ListState, TableState};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet, VecDeque};
 use std::time::Instant;
 use tokio::sync::mpsc;
 use tui_input::Input;
 
 use crate::core::types::{
-    AppEvent, Column, ColumnConfig, Container, ContainerKey, HostId, LogState, RenderAction,
-    SortDirection, SortState, ViewState,
+    AppEvent, Column, ColumnConfig, Container, ContainerAction, ContainerKey, CustomAction,
+    CustomActionRun, DiskUsageSummary, DisplayTimezone, HostId, HostStatus, InspectView, LogState,
+    RenderAction, SortDirection, SortState, StateFilter, StatsHistory, TimeFormat, TitleCount,
+    ViewState,
 };
 use crate::docker::connection::DockerHost;
 
@@ -14,6 +16,12 @@ use crate::docker::connection::DockerHost;
 mod actions;
 mod columns;
 mod container_events;
+mod custom_actions;
+mod disk_usage;
+mod errors;
+mod export;
+mod host_picker;
+mod inspect;
 mod integrations;
 mod log_view;
 mod navigation;
@@ -21,6 +29,9 @@ mod preferences;
 mod search;
 pub mod sorting;
 
+/// Columns ←/→ scroll unwrapped log lines by
+const HORIZONTAL_SCROLL_STEP: isize = 8;
+
 /// Application state that manages all runtime data
 pub struct AppState {
     /// All containers indexed by (host_id, container_id)
@@ -48,6 +59,9 @@ pub struct AppState {
     pub last_viewport_width: usize,
     /// Last known number of visible container rows (for container list page up/down)
     pub last_list_viewport_height: usize,
+    /// First visible container row while the mouse wheel has scrolled the list
+    /// independently of the selection (None: the view follows the selection)
+    pub list_scroll: Option<usize>,
     /// Connected Docker hosts for log streaming
     pub connected_hosts: HashMap<String, DockerHost>,
     /// Event sender for spawning log streams
@@ -60,6 +74,10 @@ pub struct AppState {
     pub sort_state: SortState,
     /// Whether to show all containers (including stopped ones)
     pub show_all_containers: bool,
+    /// Only list containers in this state ('p' cycles it)
+    pub state_filter: StateFilter,
+    /// Only list containers on this host (chosen in the host picker, Esc clears it)
+    pub host_filter: Option<HostId>,
     /// Action menu list state for selection tracking
     pub action_menu_state: ListState,
     /// Search input widget
@@ -69,14 +87,134 @@ pub struct AppState {
     pub config_path: Option<std::path::PathBuf>,
     /// Sort selector list state for selection tracking
     pub sort_selector_state: ListState,
+    /// Fuzzy-filter query typed into the host picker
+    pub host_picker_input: Input,
+
+    /// Active log view filter: only lines containing it (ignoring case) are shown
+    pub log_filter: Option<String>,
+
+    /// Input for the log filter, shown while `log_filter_editing`
+    pub log_filter_input: Input,
+
+    /// Whether the log filter is being typed ('f' in the log view)
+    pub log_filter_editing: bool,
+    /// Host picker list state (index into the filtered hosts)
+    pub host_picker_state: ListState,
     /// Connection errors to display (host_id -> (error_message, timestamp))
     pub connection_errors: HashMap<HostId, (String, Instant)>,
+    /// Most recent connection and action errors, oldest first, kept after their
+    /// toasts expire so they can be copied
+    pub recent_errors: VecDeque<String>,
     /// Last time containers were sorted (for throttling)
     pub last_sort_time: Instant,
     /// Notification message to display (message, expiry time)
     pub notification: Option<(String, Instant)>,
+    /// Whether the notification reports a failure (shown in red instead of green)
+    pub notification_is_error: bool,
     /// Whether a reset confirmation is pending
     pub reset_confirm_pending: bool,
+    /// Whether 'q' is waiting for confirmation because actions are still running
+    pub quit_confirm_pending: bool,
+    /// Container actions started but not finished yet (`ActionInProgress` without
+    /// its `ActionSuccess`/`ActionError`)
+    pub actions_in_flight: usize,
+    /// Container waiting for confirmation before a shell is opened (host has `confirm_shell`)
+    pub shell_confirm_pending: Option<ContainerKey>,
+    /// User-defined commands appended to the action menu of running containers
+    /// (config: `custom_actions`)
+    pub custom_actions: Vec<CustomAction>,
+    /// Container and action whose argument is being typed on the bottom row
+    /// (the command for Exec, the new name for Rename)
+    pub action_prompt: Option<(ContainerKey, ContainerAction)>,
+    /// Input for the action prompt, shown while `action_prompt` is set
+    pub action_prompt_input: Input,
+    /// The custom action whose output popup is open
+    pub custom_action_run: Option<CustomActionRun>,
+    /// The inspect view's text and scroll position (while `ViewState::Inspect`)
+    pub inspect_view: Option<InspectView>,
+    /// Disk usage results per host for the disk usage popup (Err holds the fetch error).
+    /// Hosts without an entry are still loading.
+    pub disk_usage: HashMap<HostId, Result<DiskUsageSummary, String>>,
+    /// Last known connection status per host (unlike `connection_errors`, never expires)
+    pub host_status: HashMap<HostId, HostStatus>,
+    /// When each host last delivered stats or a container list, to spot hosts
+    /// that went quiet without disconnecting
+    pub host_last_update: HashMap<HostId, Instant>,
+    /// Seconds without updates after which a host with running containers is
+    /// shown as stale; 0 disables the check (config: `stale_after_secs`)
+    pub stale_after_secs: u64,
+    /// Show stopped containers' CPU, memory and network as of when they stopped
+    /// (config: `show_last_stats`)
+    pub show_last_stats: bool,
+    /// Treat every search query as a regex, not just ones starting with '/'
+    /// (config: `search_regex`)
+    pub search_regex: bool,
+
+    /// Whether to quit once every known host has failed (config: `quit_on_all_disconnected`)
+    pub quit_on_all_disconnected: bool,
+    /// Message to print after the terminal is restored, explaining why dtop quit on its own
+    pub exit_message: Option<String>,
+    /// Whether `exit_message` reports a failure, so dtop exits with a non-zero status
+    pub exit_error: bool,
+    /// Whether the one-line key legend is shown below the container list (config: `show_footer`)
+    pub show_footer: bool,
+    /// Whether the container list shares the screen with the selected container's logs ('v')
+    pub split_logs: bool,
+    /// Maximum width of the Name column; None lets it take all spare width (config: `max_name_width`)
+    pub max_name_width: Option<u16>,
+    /// Whether the log view follows new lines ('F'): each arrival pulls the view to the
+    /// bottom, however far up it was scrolled. While paused it never scrolls by itself,
+    /// even at the bottom; opening a container's logs follows again.
+    pub log_follow: bool,
+
+    /// Most log lines kept for the open log view; older ones are dropped (config: `log_max_lines`)
+    pub log_max_lines: usize,
+    /// Recent CPU/memory samples per container. Only containers still in
+    /// `containers` have an entry; it goes when they do.
+    pub stats_history: HashMap<ContainerKey, StatsHistory>,
+    /// Samples kept per container in `stats_history`, 0 to keep none (config: `stats_history`)
+    pub stats_history_len: usize,
+    /// Containers listed without a creation time that were already inspected for
+    /// one, so each is looked up at most once
+    pub created_lookups: HashSet<ContainerKey>,
+    /// Log view display preferences. They live here rather than on `LogState` so
+    /// they carry over from one container's logs to the next
+    /// (config: `log_timestamps`, `log_wrap`, `log_line_numbers`)
+    pub show_log_timestamps: bool,
+    pub log_wrap: bool,
+    pub show_log_line_numbers: bool,
+    /// Seconds before a connection error toast expires (config: `notification_ttl_secs`)
+    pub notification_ttl_secs: u64,
+    /// Which container count leads the table title (config: `title_count`)
+    pub title_count: TitleCount,
+
+    /// Relative or absolute times in the Created column (config: `time_format`)
+    pub time_format: TimeFormat,
+
+    /// Time zone for log timestamps and absolute Created times (config: `timezone`)
+    pub timezone: DisplayTimezone,
+    /// Whether the title shows CPU and memory totals across all hosts (config: `show_fleet_totals`)
+    pub show_fleet_totals: bool,
+    /// Whether multi-host lists are grouped by host with a separator row above
+    /// each group (config: `host_separators`)
+    pub host_separators: bool,
+    /// Whether a line summing CPU, memory and network of the listed containers
+    /// is shown below the table (config: `show_summary`)
+    pub show_summary: bool,
+    /// Whether destructive actions (Remove) ask for confirmation first
+    /// (config: `confirm_destructive`)
+    pub confirm_destructive: bool,
+    /// Row number being typed in the container list, jumped to on Enter
+    pub pending_row_number: Option<usize>,
+    /// Whether the container list shows a row number gutter (config: `row_numbers`)
+    pub show_row_numbers: bool,
+    /// Lines (or rows) moved per mouse wheel notch (config: `scroll_lines`)
+    pub scroll_lines: usize,
+    /// Screen area of each header cell from the last render, for mouse sorting
+    pub header_columns: Vec<(ratatui::layout::Rect, Column)>,
+    /// Whether the startup connection-progress screen is shown instead of the
+    /// container list (until the first host has listed its containers)
+    pub connecting: bool,
 }
 
 impl AppState {
@@ -95,6 +233,11 @@ impl AppState {
             || std::env::var("SSH_TTY").is_ok()
             || std::env::var("SSH_CONNECTION").is_ok();
 
+        let host_status = connected_hosts
+            .keys()
+            .map(|host_id| (host_id.clone(), HostStatus::Connected))
+            .collect();
+
         Self {
             containers: HashMap::new(),
             sorted_container_keys: Vec::new(),
@@ -107,25 +250,84 @@ impl AppState {
             last_viewport_height: 20, // Default to 20 lines (will be updated on first render)
             last_viewport_width: 80,  // Default width (will be updated on first render)
             last_list_viewport_height: 20, // Default visible rows (updated on first render)
+            list_scroll: None,
             connected_hosts,
             event_tx,
             is_ssh_session,
             show_help: false,
             sort_state: SortState::new_with_direction(sort_field, sort_direction), // Use configured sort field and direction
             show_all_containers: show_all,
+            state_filter: StateFilter::default(),
+            host_filter: None,
             action_menu_state: ListState::default(), // Default to no selection
             search_input: Input::default(),
             column_config,
             column_selector_state: ListState::default(),
             config_path,
             sort_selector_state: ListState::default(),
+            host_picker_input: Input::default(),
+            log_filter: None,
+            log_filter_input: Input::default(),
+            log_filter_editing: false,
+            host_picker_state: ListState::default(),
             connection_errors: HashMap::new(),
+            recent_errors: VecDeque::new(),
             last_sort_time: Instant::now(),
             notification: None,
+            notification_is_error: false,
             reset_confirm_pending: false,
+            quit_confirm_pending: false,
+            actions_in_flight: 0,
+            shell_confirm_pending: None,
+            custom_actions: Vec::new(),
+            action_prompt: None,
+            action_prompt_input: Input::default(),
+            custom_action_run: None,
+            inspect_view: None,
+            disk_usage: HashMap::new(),
+            host_status,
+            show_last_stats: false,
+            search_regex: false,
+            quit_on_all_disconnected: false,
+            exit_message: None,
+            exit_error: false,
+            show_footer: false,
+            split_logs: false,
+            max_name_width: None,
+            log_follow: true,
+            log_max_lines: 10_000,
+            stats_history: HashMap::new(),
+            created_lookups: HashSet::new(),
+            stats_history_len: 60,
+            show_log_timestamps: true,
+            log_wrap: true,
+            show_log_line_numbers: false,
+            notification_ttl_secs: 10,
+            title_count: TitleCount::default(),
+            time_format: TimeFormat::default(),
+            timezone: DisplayTimezone::default(),
+            show_fleet_totals: false,
+            host_separators: false,
+            show_summary: true,
+            confirm_destructive: true,
+            host_last_update: HashMap::new(),
+            stale_after_secs: 30,
+            pending_row_number: None,
+            show_row_numbers: false,
+            scroll_lines: 3,
+            header_columns: Vec::new(),
+            connecting: false,
         }
     }
 
+    /// Shows the connection-progress screen for hosts that are still connecting
+    pub fn start_connecting(&mut self, host_ids: Vec<HostId>) {
+        for host_id in host_ids {
+            self.host_status.insert(host_id, HostStatus::Connecting);
+        }
+        self.connecting = true;
+    }
+
     /// Returns true if containers span more than one Docker host.
     ///
     /// This is used every frame to decide whether to show the "Host" column.
@@ -143,6 +345,46 @@ impl AppState {
         false
     }
 
+    /// Whether a connected host has running containers but hasn't sent stats
+    /// for `stale_after_secs` (or three of its refresh intervals, if longer), so
+    /// its rows show old numbers. Hosts without running containers get no stats
+    /// and are never stale.
+    pub fn is_host_stale(&self, host_id: &str) -> bool {
+        if self.stale_after_secs == 0
+            || self.host_status.get(host_id) != Some(&HostStatus::Connected)
+        {
+            return false;
+        }
+        let Some(last_update) = self.host_last_update.get(host_id) else {
+            return false;
+        };
+        let refresh_interval = self
+            .connected_hosts
+            .get(host_id)
+            .and_then(|host| host.refresh_interval)
+            .unwrap_or_default();
+        let threshold =
+            std::time::Duration::from_secs(self.stale_after_secs).max(refresh_interval * 3);
+
+        last_update.elapsed() > threshold
+            && self.containers.iter().any(|(key, c)| {
+                key.host_id == host_id && c.state == crate::core::types::ContainerState::Running
+            })
+    }
+
+    /// Returns the host's configured `name`, or its ID when it has none. Display
+    /// only; `host_id` stays the key everywhere else. Takes `connected_hosts`
+    /// rather than `&self` so renderers can hold the name while mutating other state.
+    pub fn host_display_name<'a>(
+        connected_hosts: &'a HashMap<HostId, DockerHost>,
+        host_id: &'a str,
+    ) -> &'a str {
+        connected_hosts
+            .get(host_id)
+            .and_then(|host| host.name.as_deref())
+            .unwrap_or(host_id)
+    }
+
     /// Rebuilds the cached list of visible columns in place.
     ///
     /// Reuses the existing `Vec`'s capacity (`clear` + `extend`), so after the
@@ -162,12 +404,14 @@ impl AppState {
     pub fn handle_event(&mut self, event: AppEvent) -> RenderAction {
         // Log stats and log lines at TRACE level since they're very frequent, everything else at DEBUG
         match &event {
-            AppEvent::ContainerStat(_, _) => tracing::trace!("Handling stat update: {:?}", event),
+            AppEvent::ContainerStat(_, _) | AppEvent::ContainerStatBatch(_, _) => {
+                tracing::trace!("Handling stat update: {:?}", event)
+            }
             AppEvent::LogLine(_, _) => tracing::trace!("Handling log line: {:?}", event),
             _ => tracing::debug!("Handling event: {:?}", event),
         }
 
-        match event {
+        let action = match event {
             AppEvent::InitialContainerList(host_id, container_list) => {
                 self.handle_initial_container_list(host_id, container_list)
             }
@@ -177,29 +421,60 @@ impl AppState {
                 self.handle_container_state_changed(key, state)
             }
             AppEvent::ContainerStat(key, stats) => self.handle_container_stat(key, stats),
+            AppEvent::ContainerStatBatch(host_id, stats) => {
+                self.handle_container_stat_batch(host_id, stats)
+            }
             AppEvent::ContainerHealthChanged(key, health) => {
                 self.handle_container_health_changed(key, health)
             }
+            AppEvent::ContainerExitCode(key, code) => self.handle_container_exit_code(key, code),
+            AppEvent::ContainerCreatedAt(key, created) => {
+                self.handle_container_created_at(key, created)
+            }
+            AppEvent::ContainerSizes(host_id, sizes) => self.handle_container_sizes(host_id, sizes),
             AppEvent::Resize => RenderAction::Render,
             AppEvent::Quit => {
                 self.should_quit = true;
                 RenderAction::None
             }
             AppEvent::KeyInput(key_event) => self.handle_key_input(key_event),
+            AppEvent::MouseClick(x, y) => self.handle_mouse_click(x, y),
+            AppEvent::MouseScrollUp => self.handle_mouse_scroll(true),
+            AppEvent::MouseScrollDown => self.handle_mouse_scroll(false),
+            AppEvent::SetSortField(field) => self.handle_set_sort_field(field),
             AppEvent::LogBatchPrepend(key, log_entries, has_more_history) => {
                 self.handle_log_batch_prepend(key, log_entries, has_more_history)
             }
             AppEvent::LogLine(key, log_line) => self.handle_log_line(key, log_line),
+            #[cfg(feature = "control-socket")]
+            AppEvent::RequestAction(key, action) => self.handle_request_action(key, action),
             AppEvent::ActionInProgress(key, action) => self.handle_action_in_progress(key, action),
             AppEvent::ActionSuccess(key, action) => self.handle_action_success(key, action),
             AppEvent::ActionError(key, action, error) => {
                 self.handle_action_error(key, action, error)
             }
+            AppEvent::ContainerRenamed(key, name) => self.handle_container_renamed(key, name),
             AppEvent::ConnectionError(host_id, error) => {
                 self.handle_connection_error(host_id, error)
             }
             AppEvent::HostConnected(docker_host) => self.handle_host_connected(docker_host),
-        }
+            AppEvent::CustomActionFinished(key, label, result) => {
+                self.handle_custom_action_finished(key, label, result)
+            }
+            AppEvent::DiskUsageLoaded(host_id, summary) => {
+                self.handle_disk_usage_loaded(host_id, summary)
+            }
+            AppEvent::DiskUsageError(host_id, error) => {
+                self.handle_disk_usage_error(host_id, error)
+            }
+            AppEvent::InspectResult(key, text) => self.handle_inspect_result(key, text),
+            #[cfg(unix)]
+            AppEvent::DumpState => self.handle_dump_state(),
+        };
+
+        self.sync_split_logs();
+        self.look_up_selected_created();
+        action
     }
 
     /// Dispatches a keyboard input to the appropriate handler based on current view state.
@@ -218,6 +493,27 @@ impl AppState {
             };
         }
 
+        // Handle quit confirmation if pending; it can be asked from any view
+        if self.quit_confirm_pending {
+            self.quit_confirm_pending = false;
+            self.notification = None;
+            return match key.code {
+                KeyCode::Char('y') | KeyCode::Char('Y') => {
+                    self.should_quit = true;
+                    RenderAction::None
+                }
+                _ => RenderAction::Render,
+            };
+        }
+
+        if self.log_filter_editing {
+            return self.handle_log_filter_key(key);
+        }
+
+        if self.action_prompt.is_some() {
+            return self.handle_action_prompt_key(key);
+        }
+
         if self.view_state == ViewState::ColumnSelector {
             return self.handle_column_selector_key(key);
         }
@@ -226,6 +522,22 @@ impl AppState {
             return self.handle_sort_selector_key(key);
         }
 
+        if self.view_state == ViewState::DiskUsage {
+            return self.handle_disk_usage_key(key);
+        }
+
+        if self.view_state == ViewState::CustomActionOutput {
+            return self.handle_custom_action_output_key(key);
+        }
+
+        if self.view_state == ViewState::HostPicker {
+            return self.handle_host_picker_key(key);
+        }
+
+        if matches!(self.view_state, ViewState::ConfirmAction(..)) {
+            return self.handle_confirm_action_key(key);
+        }
+
         // Ctrl modifiers
         if key.modifiers.contains(KeyModifiers::CONTROL) {
             return match key.code {
@@ -256,29 +568,62 @@ impl AppState {
             };
         }
 
-        match key.code {
-            KeyCode::Char('q') => {
-                self.should_quit = true;
-                RenderAction::None
+        // Handle shell confirmation if pending
+        if let Some(container_key) = self.shell_confirm_pending.take() {
+            self.notification = None;
+            return match key.code {
+                KeyCode::Char('y') | KeyCode::Char('Y') => RenderAction::StartShell(container_key),
+                _ => RenderAction::Render,
+            };
+        }
+
+        // A typed row number: more digits extend it, Enter or G jumps there and
+        // anything else drops it (Esc just cancels)
+        if self.pending_row_number.is_some() {
+            match key.code {
+                KeyCode::Char(c) if c.is_ascii_digit() => return self.handle_row_number_digit(c),
+                KeyCode::Enter | KeyCode::Char('G') => return self.handle_jump_to_row(),
+                _ => {
+                    self.pending_row_number = None;
+                    self.notification = None;
+                    if key.code == KeyCode::Esc {
+                        return RenderAction::Render;
+                    }
+                }
             }
+        }
+
+        match key.code {
+            KeyCode::Char('q') => self.handle_quit(),
             KeyCode::Char('/') => self.handle_enter_search_mode(),
+            KeyCode::Char('p') => self.handle_cycle_state_filter(),
             KeyCode::Char('?') => self.handle_toggle_help(),
             KeyCode::Up | KeyCode::Char('k') => match &self.view_state {
                 ViewState::ContainerList => self.handle_select_previous(),
-                ViewState::LogView(_) => self.handle_scroll_up(),
+                ViewState::LogView(_) | ViewState::Inspect(_) => self.handle_scroll_up(),
                 ViewState::ActionMenu(_) => self.handle_select_action_up(),
                 // SearchMode is handled by the early return above; fallback defensively
                 ViewState::SearchMode => self.handle_select_previous(),
-                // ColumnSelector/SortSelector handled by early returns above
-                ViewState::ColumnSelector | ViewState::SortSelector => RenderAction::None,
+                // Popups are handled by early returns above
+                ViewState::ColumnSelector
+                | ViewState::SortSelector
+                | ViewState::DiskUsage
+                | ViewState::CustomActionOutput
+                | ViewState::HostPicker
+                | ViewState::ConfirmAction(..) => RenderAction::None,
             },
             KeyCode::Down | KeyCode::Char('j') => match &self.view_state {
                 ViewState::ContainerList => self.handle_select_next(),
-                ViewState::LogView(_) => self.handle_scroll_down(),
+                ViewState::LogView(_) | ViewState::Inspect(_) => self.handle_scroll_down(),
                 ViewState::ActionMenu(_) => self.handle_select_action_down(),
                 ViewState::SearchMode => self.handle_select_next(),
-                // ColumnSelector/SortSelector handled by early returns above
-                ViewState::ColumnSelector | ViewState::SortSelector => RenderAction::None,
+                // Popups are handled by early returns above
+                ViewState::ColumnSelector
+                | ViewState::SortSelector
+                | ViewState::DiskUsage
+                | ViewState::CustomActionOutput
+                | ViewState::HostPicker
+                | ViewState::ConfirmAction(..) => RenderAction::None,
             },
             KeyCode::PageUp => match &self.view_state {
                 ViewState::ContainerList | ViewState::SearchMode => self.handle_page_up(),
@@ -299,11 +644,46 @@ impl AppState {
             KeyCode::Enter => self.handle_enter_pressed(),
             KeyCode::Esc => self.handle_cancel_action_menu(),
             KeyCode::Char('o') => self.handle_open_dozzle(),
-            KeyCode::Char('s') => self.handle_open_sort_selector(),
+            KeyCode::Char('s') => match &self.view_state {
+                ViewState::LogView(_) => self.handle_save_logs(),
+                _ => self.handle_open_sort_selector(),
+            },
             KeyCode::Char('a') | KeyCode::Char('A') => self.handle_toggle_show_all(),
             KeyCode::Char('c') => self.handle_open_column_selector(),
+            KeyCode::Char('d') => self.handle_open_disk_usage(),
+            KeyCode::Char('H') => self.handle_open_host_picker(),
+            KeyCode::Char('u') => self.handle_refresh_selected_stats(),
+            KeyCode::Char('r') => self.handle_refresh_containers(),
+            KeyCode::Char('f') => match &self.view_state {
+                ViewState::LogView(_) => self.handle_start_log_filter(),
+                _ => self.handle_toggle_footer(),
+            },
+            KeyCode::Char('e') => self.handle_copy_errors(false),
+            KeyCode::Char('E') => self.handle_copy_errors(true),
+            KeyCode::Char('n') => self.handle_toggle_log_line_numbers(),
+            KeyCode::Char('T') => self.handle_toggle_log_timestamps(),
+            KeyCode::Char('F') => self.handle_toggle_log_follow(),
+            KeyCode::Char('w') => self.handle_toggle_log_wrap(),
+            KeyCode::Char('t') => self.handle_sort_by_traffic(),
+            KeyCode::Char('x') => self.handle_export_snapshot(),
+            KeyCode::Char('i') => self.handle_open_inspect(),
+            KeyCode::Char('y') => self.handle_copy_container_id(),
+            KeyCode::Char('v') => self.handle_toggle_split_logs(),
+            KeyCode::Char(c) if c.is_ascii_digit() => self.handle_row_number_digit(c),
+            // Without wrapping, the arrows scroll long log lines sideways ('h' still exits)
+            KeyCode::Right
+                if matches!(self.view_state, ViewState::LogView(_)) && !self.log_wrap =>
+            {
+                self.handle_scroll_horizontal(HORIZONTAL_SCROLL_STEP)
+            }
+            KeyCode::Left if matches!(self.view_state, ViewState::LogView(_)) && !self.log_wrap => {
+                self.handle_scroll_horizontal(-HORIZONTAL_SCROLL_STEP)
+            }
             KeyCode::Right | KeyCode::Char('l') => self.handle_show_log_view(),
-            KeyCode::Left | KeyCode::Char('h') => self.handle_exit_log_view(),
+            KeyCode::Left | KeyCode::Char('h') => match &self.view_state {
+                ViewState::Inspect(_) => self.handle_close_inspect(),
+                _ => self.handle_exit_log_view(),
+            },
             KeyCode::Char('g') => self.handle_scroll_to_top(),
             KeyCode::Char('G') => self.handle_scroll_to_bottom(),
             KeyCode::Char(' ') => self.handle_scroll_page_down(),
@@ -314,17 +694,74 @@ impl AppState {
 
     /// Handles a connection error by storing it with a timestamp
     fn handle_connection_error(&mut self, host_id: HostId, error: String) -> RenderAction {
+        self.host_status.insert(host_id.clone(), HostStatus::Failed);
+        self.record_error(format!("{}: {}", host_id, error));
+        self.handle_log_host_disconnected(&host_id);
+
         // Store the error with current timestamp
         self.connection_errors
             .insert(host_id, (error, Instant::now()));
 
-        // Remove errors older than 10 seconds
+        let all_failed = self
+            .host_status
+            .values()
+            .all(|status| *status == HostStatus::Failed);
+
+        // Nothing will ever replace the connection-progress screen
+        if self.connecting && all_failed {
+            self.should_quit = true;
+            self.exit_error = true;
+            self.exit_message = Some("Failed to connect to any Docker hosts. Please check your configuration and connection settings. Set DEBUG=1 to see detailed logs in debug.log".to_string());
+            return RenderAction::None;
+        }
+
+        // Don't keep showing frozen data once every host is gone
+        if self.quit_on_all_disconnected && all_failed {
+            self.should_quit = true;
+            self.exit_message = Some(
+                "All Docker hosts disconnected (quit_on_all_disconnected is enabled)".to_string(),
+            );
+            return RenderAction::None;
+        }
+
+        // Remove expired errors
+        let ttl = self.notification_ttl_secs;
         self.connection_errors
-            .retain(|_, (_, timestamp)| timestamp.elapsed().as_secs() < 10);
+            .retain(|_, (_, timestamp)| timestamp.elapsed().as_secs() < ttl);
 
         RenderAction::Render // Redraw to show the error
     }
 
+    /// Logs a one-line summary of the current state, for diagnosing stale or frozen data
+    #[cfg(unix)]
+    fn handle_dump_state(&self) -> RenderAction {
+        let running = self
+            .containers
+            .values()
+            .filter(|c| c.state == crate::core::types::ContainerState::Running)
+            .count();
+        let failed_hosts = self
+            .host_status
+            .values()
+            .filter(|status| **status == HostStatus::Failed)
+            .count();
+        let queued_events = self.event_tx.max_capacity() - self.event_tx.capacity();
+
+        tracing::info!(
+            "State dump: hosts={} (failed={}), containers={} (running={}, visible={}), view={:?}, queued_events={}, last_sort={:?} ago",
+            self.connected_hosts.len(),
+            failed_hosts,
+            self.containers.len(),
+            running,
+            self.sorted_container_keys.len(),
+            self.view_state,
+            queued_events,
+            self.last_sort_time.elapsed(),
+        );
+
+        RenderAction::None
+    }
+
     /// Handles a new Docker host connection by adding it to the connected hosts
     fn handle_host_connected(&mut self, docker_host: DockerHost) -> RenderAction {
         use tracing::debug;
@@ -332,10 +769,18 @@ impl AppState {
         let host_id = docker_host.host_id.clone();
         debug!("Adding host to connected_hosts: {}", host_id);
         self.connected_hosts.insert(host_id.clone(), docker_host);
+        self.host_status
+            .insert(host_id.clone(), HostStatus::Connected);
+        self.host_last_update
+            .insert(host_id.clone(), Instant::now());
 
         // Clear any connection error for this host
         self.connection_errors.remove(&host_id);
 
+        if self.connecting {
+            return RenderAction::Render; // Update the connection-progress screen
+        }
+
         RenderAction::None // No need to force redraw, container list will update via normal events
     }
 }
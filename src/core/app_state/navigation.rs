@@ -1,3 +1,5 @@
+use std::time::{Duration, Instant};
+
 use crate::core::app_state::AppState;
 use crate::core::types::{RenderAction, ViewState};
 
@@ -11,6 +13,8 @@ impl AppState {
             return RenderAction::None;
         }
 
+        self.follow_selection();
+
         let container_count = self.sorted_container_keys.len();
         if container_count > 0 {
             let selected = self.table_state.selected().unwrap_or(0);
@@ -30,6 +34,8 @@ impl AppState {
             return RenderAction::None;
         }
 
+        self.follow_selection();
+
         let container_count = self.sorted_container_keys.len();
         if container_count > 0 {
             let selected = self.table_state.selected().unwrap_or(0);
@@ -48,6 +54,8 @@ impl AppState {
             return RenderAction::None;
         }
 
+        self.follow_selection();
+
         let container_count = self.sorted_container_keys.len();
         if container_count > 0 {
             let page = self.last_list_viewport_height.max(1);
@@ -65,6 +73,8 @@ impl AppState {
             return RenderAction::None;
         }
 
+        self.follow_selection();
+
         let container_count = self.sorted_container_keys.len();
         if container_count > 0 {
             let page = self.last_list_viewport_height.max(1);
@@ -83,6 +93,8 @@ impl AppState {
             return RenderAction::None;
         }
 
+        self.follow_selection();
+
         if !self.sorted_container_keys.is_empty() {
             self.table_state.select(Some(0));
         }
@@ -97,6 +109,8 @@ impl AppState {
             return RenderAction::None;
         }
 
+        self.follow_selection();
+
         let container_count = self.sorted_container_keys.len();
         if container_count > 0 {
             self.table_state.select(Some(container_count - 1));
@@ -104,6 +118,51 @@ impl AppState {
         RenderAction::Render // Force draw - selection changed
     }
 
+    /// Scrolls the container list viewport (without moving the selection) or the logs
+    pub(super) fn handle_mouse_scroll(&mut self, up: bool) -> RenderAction {
+        let step = self.scroll_lines.max(1);
+
+        match self.view_state {
+            ViewState::ContainerList | ViewState::SearchMode => {
+                let max_offset = self
+                    .sorted_container_keys
+                    .len()
+                    .saturating_sub(self.last_list_viewport_height);
+                let offset = self.list_scroll.unwrap_or(self.table_state.offset());
+                let offset = if up {
+                    offset.saturating_sub(step)
+                } else {
+                    (offset + step).min(max_offset)
+                };
+                self.list_scroll = Some(offset);
+                RenderAction::Render
+            }
+            ViewState::LogView(_) | ViewState::Inspect(_) => {
+                let mut action = RenderAction::None;
+                for _ in 0..step {
+                    let step = if up {
+                        self.handle_scroll_up()
+                    } else {
+                        self.handle_scroll_down()
+                    };
+                    if step == RenderAction::Render {
+                        action = RenderAction::Render;
+                    }
+                }
+                action
+            }
+            _ => RenderAction::None,
+        }
+    }
+
+    /// Hands the viewport back to the selection after wheel scrolling, starting
+    /// from where the wheel left it so the list scrolls on smoothly from there
+    fn follow_selection(&mut self) {
+        if let Some(offset) = self.list_scroll.take() {
+            *self.table_state.offset_mut() = offset;
+        }
+    }
+
     /// Note: In search mode, '?' is routed to handle_search_key_event by handle_key_input,
     /// so this method is only called outside of search mode.
     pub(super) fn handle_toggle_help(&mut self) -> RenderAction {
@@ -111,16 +170,68 @@ impl AppState {
         RenderAction::Render // Force redraw to show/hide popup
     }
 
-    /// Clamps the current table selection to be within the valid range of sorted container keys.
-    /// Call this after filtering or removing containers to ensure the selection remains valid.
+    /// Handles a digit typed in the container list, extending the row number to
+    /// jump to. The number is shown as a prompt until Enter (or G) jumps to it.
+    pub(super) fn handle_row_number_digit(&mut self, digit: char) -> RenderAction {
+        if self.view_state != ViewState::ContainerList {
+            return RenderAction::None;
+        }
+        let Some(digit) = digit.to_digit(10) else {
+            return RenderAction::None;
+        };
+
+        // Rows are numbered from 1. Ignore digits that would put the number a
+        // whole order of magnitude past the last row (jumps past it clamp anyway).
+        let number = self.pending_row_number.unwrap_or(0) * 10 + digit as usize;
+        if number == 0 || number >= self.sorted_container_keys.len().max(1) * 10 {
+            return RenderAction::None;
+        }
+        self.pending_row_number = Some(number);
+        self.notification = Some((
+            format!("Go to row {} (Enter)", number),
+            Instant::now() + Duration::from_secs(5),
+        ));
+        RenderAction::Render
+    }
+
+    /// Selects the row typed with [`Self::handle_row_number_digit`], clamped to
+    /// the last row
+    pub(super) fn handle_jump_to_row(&mut self) -> RenderAction {
+        let Some(number) = self.pending_row_number.take() else {
+            return RenderAction::None;
+        };
+        self.notification = None;
+
+        self.follow_selection();
+
+        let container_count = self.sorted_container_keys.len();
+        if container_count > 0 {
+            self.table_state
+                .select(Some(number.min(container_count) - 1));
+        }
+        RenderAction::Render // Force draw - selection changed
+    }
+
+    pub(super) fn handle_toggle_footer(&mut self) -> RenderAction {
+        if self.view_state != ViewState::ContainerList {
+            return RenderAction::None;
+        }
+        self.show_footer = !self.show_footer;
+        RenderAction::Render // Force redraw - list height changed
+    }
+
+    /// Clamps the current table selection to be within the valid range of sorted container keys,
+    /// selecting the first row if there are rows but nothing is selected.
+    /// Called after every sort, so filtering, toggling and container events all end up here.
     pub fn clamp_selection(&mut self) {
         let container_count = self.sorted_container_keys.len();
-        if container_count == 0 {
-            self.table_state.select(None);
-        } else if let Some(selected) = self.table_state.selected()
-            && selected >= container_count
-        {
-            self.table_state.select(Some(container_count - 1));
+        match self.table_state.selected() {
+            _ if container_count == 0 => self.table_state.select(None),
+            None => self.table_state.select(Some(0)),
+            Some(selected) if selected >= container_count => {
+                self.table_state.select(Some(container_count - 1));
+            }
+            Some(_) => {}
         }
     }
 }
@@ -4,22 +4,33 @@ use std::path::PathBuf;
 use std::time::{Duration, Instant};
 
 use crate::core::app_state::AppState;
-use crate::core::types::{ColumnConfig, RenderAction, SortDirection, SortState, ViewState};
+use crate::core::types::{ColumnConfig, RenderAction, SortState, ViewState};
 
 impl AppState {
     /// Shows a notification message that auto-dismisses after 2 seconds
     pub fn show_notification(&mut self, message: &str) {
         self.notification = Some((message.to_string(), Instant::now() + Duration::from_secs(2)));
+        self.notification_is_error = false;
     }
 
-    /// Clears the notification if it has expired. Also cancels a pending reset
-    /// confirmation so a stray later `y` cannot trigger a reset with no prompt shown.
+    /// Shows a failure in red, for 5 seconds so it can be read
+    pub fn show_error_notification(&mut self, message: &str) {
+        self.notification = Some((message.to_string(), Instant::now() + Duration::from_secs(5)));
+        self.notification_is_error = true;
+    }
+
+    /// Clears the notification if it has expired. Also cancels a pending reset,
+    /// quit or shell confirmation or row number so a stray later key cannot act
+    /// with no prompt shown.
     pub fn clear_expired_notification(&mut self) {
         if let Some((_, expiry)) = &self.notification
             && Instant::now() > *expiry
         {
             self.notification = None;
             self.reset_confirm_pending = false;
+            self.quit_confirm_pending = false;
+            self.shell_confirm_pending = None;
+            self.pending_row_number = None;
         }
     }
 
@@ -37,13 +48,15 @@ impl AppState {
         let config_path = self.config_path.clone().unwrap_or_else(default_config_path);
 
         // Collect current preferences
-        let columns = self.column_config.to_config_strings();
-        let sort = self.sort_state.field.id().to_string();
-        let sort_direction = match self.sort_state.direction {
-            SortDirection::Ascending => "asc".to_string(),
-            SortDirection::Descending => "desc".to_string(),
+        let preferences = Preferences {
+            columns: self.column_config.to_config_strings(),
+            sort: self.sort_state.field.id().to_string(),
+            sort_direction: self.sort_state.direction.config_str().to_string(),
+            all: self.show_all_containers,
+            log_timestamps: self.show_log_timestamps,
+            log_wrap: self.log_wrap,
+            log_line_numbers: self.show_log_line_numbers,
         };
-        let all = self.show_all_containers;
 
         // Build display path for notification (shorten home dir to ~)
         let display_path = config_path
@@ -61,13 +74,13 @@ impl AppState {
 
         // Perform synchronous write - preferences save is user-initiated and should
         // complete before showing result. The write is fast (<1ms typically).
-        match write_preferences(&config_path, columns, sort, sort_direction, all) {
+        match write_preferences(&config_path, preferences) {
             Ok(()) => {
                 self.show_notification(&format!("Saved to {}", display_path));
             }
             Err(e) => {
                 tracing::error!("Failed to save preferences: {}", e);
-                self.show_notification(&format!("Save failed: {}", e));
+                self.show_error_notification(&format!("Save failed: {}", e));
             }
         }
 
@@ -100,6 +113,9 @@ impl AppState {
         self.column_config = ColumnConfig::default();
         self.sort_state = SortState::default();
         self.show_all_containers = false;
+        self.show_log_timestamps = true;
+        self.log_wrap = true;
+        self.show_log_line_numbers = false;
 
         // Force re-sort with new settings
         self.force_sort_containers();
@@ -122,6 +138,17 @@ fn default_config_path() -> PathBuf {
         })
 }
 
+/// Preferences saved by Ctrl-S, written under the matching config keys
+struct Preferences {
+    columns: Vec<String>,
+    sort: String,
+    sort_direction: String,
+    all: bool,
+    log_timestamps: bool,
+    log_wrap: bool,
+    log_line_numbers: bool,
+}
+
 /// Writes preferences to the config file, preserving other keys (hosts, icons, etc.)
 ///
 /// Uses atomic write (write to temp file, then rename) to prevent corruption on crash.
@@ -129,10 +156,7 @@ fn default_config_path() -> PathBuf {
 /// silently replacing it.
 fn write_preferences(
     path: &PathBuf,
-    columns: Vec<String>,
-    sort: String,
-    sort_direction: String,
-    all: bool,
+    preferences: Preferences,
 ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
     use serde_yaml::Value;
     use std::fs;
@@ -159,14 +183,32 @@ fn write_preferences(
     // Update preference keys
     mapping.insert(
         Value::String("columns".to_string()),
-        Value::Sequence(columns.into_iter().map(Value::String).collect()),
+        Value::Sequence(preferences.columns.into_iter().map(Value::String).collect()),
+    );
+    mapping.insert(
+        Value::String("sort".to_string()),
+        Value::String(preferences.sort),
     );
-    mapping.insert(Value::String("sort".to_string()), Value::String(sort));
     mapping.insert(
         Value::String("sort_direction".to_string()),
-        Value::String(sort_direction),
+        Value::String(preferences.sort_direction),
+    );
+    mapping.insert(
+        Value::String("all".to_string()),
+        Value::Bool(preferences.all),
+    );
+    mapping.insert(
+        Value::String("log_timestamps".to_string()),
+        Value::Bool(preferences.log_timestamps),
+    );
+    mapping.insert(
+        Value::String("log_wrap".to_string()),
+        Value::Bool(preferences.log_wrap),
+    );
+    mapping.insert(
+        Value::String("log_line_numbers".to_string()),
+        Value::Bool(preferences.log_line_numbers),
     );
-    mapping.insert(Value::String("all".to_string()), Value::Bool(all));
 
     // Create parent directories if needed
     if let Some(parent) = path.parent() {
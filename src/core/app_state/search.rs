@@ -1,7 +1,68 @@
+use regex::{Regex, RegexBuilder};
+
 use crate::core::app_state::AppState;
-use crate::core::types::{RenderAction, ViewState};
+use crate::core::types::{Container, RenderAction, ViewState};
+
+/// How the search query is matched against container names, IDs and hosts
+pub(super) enum SearchPattern {
+    /// Case-insensitive substring (the query is lowercased)
+    Substring(String),
+    /// Case-insensitive regex
+    Regex(Regex),
+}
+
+impl SearchPattern {
+    /// Whether the container's name, ID or host matches
+    pub(super) fn matches(&self, container: &Container) -> bool {
+        let fields = [&container.name, &container.id, &container.host_id];
+        match self {
+            SearchPattern::Substring(query) => fields
+                .iter()
+                .any(|field| field.to_lowercase().contains(query.as_str())),
+            SearchPattern::Regex(regex) => fields.iter().any(|field| regex.is_match(field)),
+        }
+    }
+}
 
 impl AppState {
+    /// The regex source when the query asks for regex matching: a leading '/'
+    /// (stripped), or any query with `search_regex` set
+    fn search_regex_source(&self) -> Option<&str> {
+        let query = self.search_input.value();
+        query
+            .strip_prefix('/')
+            .or_else(|| self.search_regex.then_some(query))
+    }
+
+    /// Compiles the current query, or None when it isn't a regex query
+    fn compile_search_regex(&self) -> Option<Result<Regex, regex::Error>> {
+        self.search_regex_source()
+            .map(|source| RegexBuilder::new(source).case_insensitive(true).build())
+    }
+
+    /// Builds the matcher for the current query, or None if there is no query.
+    /// A regex query that doesn't compile falls back to a substring match.
+    pub(super) fn search_pattern(&self) -> Option<SearchPattern> {
+        if self.search_input.value().is_empty() {
+            return None;
+        }
+        match self.compile_search_regex() {
+            Some(Ok(regex)) => Some(SearchPattern::Regex(regex)),
+            Some(Err(_)) | None => {
+                let query = self
+                    .search_regex_source()
+                    .unwrap_or(self.search_input.value());
+                Some(SearchPattern::Substring(query.to_lowercase()))
+            }
+        }
+    }
+
+    /// Whether the query is meant as a regex but doesn't compile, so the search
+    /// bar can show it's matching as plain text
+    pub fn search_regex_invalid(&self) -> bool {
+        matches!(self.compile_search_regex(), Some(Err(_)))
+    }
+
     pub(super) fn handle_enter_search_mode(&mut self) -> RenderAction {
         // Only allow entering search mode from ContainerList view
         if self.view_state != ViewState::ContainerList {
@@ -30,14 +91,9 @@ impl AppState {
         self.search_input.reset();
 
         // Force immediate re-sort/filter when exiting search mode
+        // (this also adjusts the selection)
         self.force_sort_containers();
 
-        // Adjust selection after clearing filter
-        self.clamp_selection();
-        if self.table_state.selected().is_none() && !self.sorted_container_keys.is_empty() {
-            self.table_state.select(Some(0));
-        }
-
         RenderAction::Render // Force redraw to hide search bar
     }
 
@@ -56,14 +112,9 @@ impl AppState {
             .handle_event(&crossterm::event::Event::Key(key_event));
 
         // Force immediate re-filter and sort as user types
+        // (this also adjusts the selection)
         self.force_sort_containers();
 
-        // Adjust selection after filtering
-        self.clamp_selection();
-        if self.table_state.selected().is_none() && !self.sorted_container_keys.is_empty() {
-            self.table_state.select(Some(0));
-        }
-
         RenderAction::Render // Force redraw to show updated search text and filtered results
     }
 }
@@ -1,6 +1,7 @@
 use crate::core::app_state::AppState;
 use crate::core::types::{
-    Column, ContainerState, RenderAction, SortDirection, SortState, ViewState,
+    AppEvent, Column, ContainerState, RenderAction, SortDirection, SortState, StateFilter,
+    ViewState,
 };
 use std::time::Duration;
 
@@ -56,14 +57,7 @@ impl AppState {
                 if let Some(idx) = self.sort_selector_state.selected()
                     && let Some(&field) = visible.get(idx)
                 {
-                    if self.sort_state.field == field {
-                        // Same field: toggle direction
-                        self.sort_state.direction = self.sort_state.direction.toggle();
-                    } else {
-                        // Different field: set with default direction
-                        self.sort_state = SortState::new(field);
-                    }
-                    self.force_sort_containers();
+                    self.handle_set_sort_field(field);
                 }
                 RenderAction::Render
             }
@@ -72,6 +66,46 @@ impl AppState {
         }
     }
 
+    /// Handles 't': sorts by total network traffic (TX + RX), toggling the direction
+    /// when already sorted by it
+    pub(super) fn handle_sort_by_traffic(&mut self) -> RenderAction {
+        if self.view_state != ViewState::ContainerList {
+            return RenderAction::None;
+        }
+        self.handle_set_sort_field(Column::NetIo)
+    }
+
+    /// Sorts by `field`, toggling the direction if it is already the sort field
+    pub(super) fn handle_set_sort_field(&mut self, field: Column) -> RenderAction {
+        if self.sort_state.field == field {
+            // Same field: toggle direction
+            self.sort_state.direction = self.sort_state.direction.toggle();
+        } else {
+            // Different field: set with default direction
+            self.sort_state = SortState::new(field);
+        }
+        self.force_sort_containers();
+        RenderAction::Render
+    }
+
+    /// Sorts by the clicked column when the click lands on the table header
+    pub(super) fn handle_mouse_click(&mut self, x: u16, y: u16) -> RenderAction {
+        if self.view_state != ViewState::ContainerList {
+            return RenderAction::None;
+        }
+
+        let position = ratatui::layout::Position::new(x, y);
+        let Some(&(_, field)) = self
+            .header_columns
+            .iter()
+            .find(|(area, _)| area.contains(position))
+        else {
+            return RenderAction::None;
+        };
+
+        self.handle_event(AppEvent::SetSortField(field))
+    }
+
     fn close_sort_selector(&mut self) -> RenderAction {
         self.view_state = ViewState::ContainerList;
         self.sort_selector_state.select(None);
@@ -88,14 +122,23 @@ impl AppState {
         self.show_all_containers = !self.show_all_containers;
 
         // Force immediate re-sort/filter when user toggles visibility
+        // (this also adjusts the selection)
         self.force_sort_containers();
 
-        // Adjust selection if needed after filtering
-        self.clamp_selection();
-
         RenderAction::Render // Force redraw - visibility changed
     }
 
+    /// Handles 'p': cycles the state filter (all → running → exited → unhealthy)
+    pub(super) fn handle_cycle_state_filter(&mut self) -> RenderAction {
+        if self.view_state != ViewState::ContainerList {
+            return RenderAction::None;
+        }
+
+        self.state_filter = self.state_filter.next();
+        self.force_sort_containers();
+        RenderAction::Render
+    }
+
     /// Sorts the container keys based on the current sort field and direction
     /// If force is false, will only sort if enough time has passed since last sort
     pub fn sort_containers(&mut self) {
@@ -116,36 +159,59 @@ impl AppState {
 
         // Update last sort time
         self.last_sort_time = std::time::Instant::now();
-        // Get the search filter (case-insensitive)
-        let search_filter = self.search_input.value().to_lowercase();
-        let has_search_filter = !search_filter.is_empty();
+
+        // The highlight follows the selected container to its new row
+        let selected_key = self
+            .table_state
+            .selected()
+            .and_then(|idx| self.sorted_container_keys.get(idx))
+            .cloned();
+
+        // Get the search filter (case-insensitive substring or regex)
+        let search_pattern = self.search_pattern();
 
         // Collect (key, container) pairs to avoid repeated HashMap lookups during sort
         let mut key_container_pairs: Vec<_> = self
             .containers
             .iter()
             .filter(|(_, container)| {
-                // First filter by running state
-                if !self.show_all_containers && container.state != ContainerState::Running {
+                // First filter by running state. Asking for exited containers
+                // shows them even while stopped ones are hidden.
+                if !self.show_all_containers
+                    && self.state_filter != StateFilter::Exited
+                    && container.state != ContainerState::Running
+                {
                     return false;
                 }
 
-                // Then filter by search term if present
-                if has_search_filter {
-                    let name_matches = container.name.to_lowercase().contains(&search_filter);
-                    let id_matches = container.id.to_lowercase().contains(&search_filter);
-                    let host_matches = container.host_id.to_lowercase().contains(&search_filter);
-                    name_matches || id_matches || host_matches
-                } else {
-                    true
+                if !self.state_filter.matches(container) {
+                    return false;
                 }
+
+                if self
+                    .host_filter
+                    .as_ref()
+                    .is_some_and(|host_id| *host_id != container.host_id)
+                {
+                    return false;
+                }
+
+                // Then filter by search term if present
+                search_pattern
+                    .as_ref()
+                    .is_none_or(|pattern| pattern.matches(container))
             })
             .collect();
 
         let direction = self.sort_state.direction;
         let sort_field = self.sort_state.field;
+        // Host separators need each host's containers next to each other
+        let group_by_host = self.host_separators && self.has_multiple_hosts();
 
         key_container_pairs.sort_by(|(_, a), (_, b)| {
+            if group_by_host && a.host_id != b.host_id {
+                return a.host_id.cmp(&b.host_id);
+            }
             let ord = match sort_field {
                 // `Option`'s ordering already places `None` before `Some`, which
                 // matches the previous hand-written match exactly.
@@ -154,6 +220,7 @@ impl AppState {
                 Column::Id => a.id.cmp(&b.id),
                 Column::Host => a.host_id.cmp(&b.host_id),
                 Column::Compose => a.compose_project.cmp(&b.compose_project),
+                Column::Image => a.image.cmp(&b.image),
                 // `total_cmp` gives a deterministic total order over floats
                 // (including NaN) without needing to unwrap `partial_cmp`.
                 Column::Cpu => a.stats.cpu.total_cmp(&b.stats.cpu),
@@ -166,6 +233,10 @@ impl AppState {
                     .stats
                     .network_rx_bytes_per_sec
                     .total_cmp(&b.stats.network_rx_bytes_per_sec),
+                Column::NetIo => a
+                    .stats
+                    .network_bytes_per_sec()
+                    .total_cmp(&b.stats.network_bytes_per_sec()),
                 Column::DiskRead => a
                     .stats
                     .disk_read_bytes_per_sec
@@ -174,20 +245,28 @@ impl AppState {
                     .stats
                     .disk_write_bytes_per_sec
                     .total_cmp(&b.stats.disk_write_bytes_per_sec),
+                Column::DiskIo => a
+                    .stats
+                    .disk_io_bytes_per_sec()
+                    .total_cmp(&b.stats.disk_io_bytes_per_sec()),
                 Column::Status => {
                     let a_state = format!("{:?}", a.state);
                     let b_state = format!("{:?}", b.state);
                     a_state.cmp(&b_state)
                 }
                 Column::Restarts => a.restart_count.cmp(&b.restart_count),
+                Column::Size => a.size.map(|s| s.rw).cmp(&b.size.map(|s| s.rw)),
+                Column::Ports => a.ports.cmp(&b.ports),
             };
             let ord = if direction == SortDirection::Descending {
                 ord.reverse()
             } else {
                 ord
             };
-            // Use host_id as tiebreaker for stable ordering
+            // Use host_id and then the ID as tiebreakers, so equal values (e.g.
+            // containers without a creation time) keep a fixed order
             ord.then_with(|| a.host_id.cmp(&b.host_id))
+                .then_with(|| a.id.cmp(&b.id))
         });
 
         // Extract sorted keys
@@ -195,5 +274,15 @@ impl AppState {
             .into_iter()
             .map(|(key, _)| key.clone())
             .collect();
+
+        // Stay on the same container if it is still listed; otherwise keep the
+        // selection on one of the visible rows
+        if let Some(idx) =
+            selected_key.and_then(|key| self.sorted_container_keys.iter().position(|k| *k == key))
+        {
+            self.table_state.select(Some(idx));
+        } else {
+            self.clamp_selection();
+        }
     }
 }
@@ -1,5 +1,6 @@
 use chrono::{DateTime, Utc};
 use ratatui::text::Line;
+use std::collections::VecDeque;
 use std::str::FromStr;
 use tokio::sync::mpsc;
 
@@ -21,6 +22,22 @@ pub enum ContainerState {
     Unknown,
 }
 
+impl ContainerState {
+    /// Lowercase name as Docker reports it, e.g. "running"
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ContainerState::Running => "running",
+            ContainerState::Paused => "paused",
+            ContainerState::Restarting => "restarting",
+            ContainerState::Removing => "removing",
+            ContainerState::Exited => "exited",
+            ContainerState::Dead => "dead",
+            ContainerState::Created => "created",
+            ContainerState::Unknown => "unknown",
+        }
+    }
+}
+
 /// Container health status from Docker health checks
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum HealthStatus {
@@ -67,8 +84,13 @@ impl FromStr for HealthStatus {
 /// Container metadata (static information)
 #[derive(Clone, Debug)]
 pub struct Container {
+    /// Short (12-character) ID, as shown in the list and used in keys
     pub id: String,
+    /// Full 64-character ID, for copying ('y')
+    pub full_id: String,
     pub name: String,
+    /// Image reference the container was created from, e.g. `nginx:1.27`
+    pub image: String,
     pub state: ContainerState,
     pub health: Option<HealthStatus>, // None if container has no health check configured
     pub created: Option<DateTime<Utc>>, // When the container was created
@@ -77,6 +99,29 @@ pub struct Container {
     pub dozzle_url: Option<String>,
     pub restart_count: Option<i64>,
     pub compose_project: Option<String>, // Docker Compose project name from labels
+    pub size: Option<ContainerSize>,     // Only collected when `container_size` is enabled
+    pub health_check: Option<String>,    // Health-check command from `Config.Healthcheck.Test`
+    pub last_stats: Option<ContainerStats>, // Stats when it stopped, kept with `show_last_stats`
+    pub exit_code: Option<i64>,          // Exit code of the last run; None while running
+    pub ports: Vec<PublishedPort>,       // Ports published on the host, sorted
+}
+
+/// A container port published on the host, shown as "8080→80"
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct PublishedPort {
+    pub host_port: u16,
+    pub container_port: u16,
+    /// "tcp", "udp" or "sctp"
+    pub protocol: String,
+}
+
+/// Disk space used by a container, as reported by `docker ps --size`
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ContainerSize {
+    /// Size of the container's writable layer in bytes
+    pub rw: u64,
+    /// Total size of the container's filesystem (image layers + writable layer) in bytes
+    pub root_fs: u64,
 }
 
 /// Container runtime statistics (updated frequently)
@@ -96,6 +141,313 @@ pub struct ContainerStats {
     pub disk_read_bytes_per_sec: f64,
     /// Disk write rate in bytes per second
     pub disk_write_bytes_per_sec: f64,
+    /// The daemon reports no disk counters (e.g. null `blkio_stats` on older
+    /// daemons), so the disk rates are unknown rather than zero
+    pub disk_io_unavailable: bool,
+}
+
+impl ContainerStats {
+    /// Combined network transmit and receive rate in bytes per second
+    pub fn network_bytes_per_sec(&self) -> f64 {
+        self.network_tx_bytes_per_sec + self.network_rx_bytes_per_sec
+    }
+
+    /// Combined disk read and write rate in bytes per second
+    pub fn disk_io_bytes_per_sec(&self) -> f64 {
+        self.disk_read_bytes_per_sec + self.disk_write_bytes_per_sec
+    }
+}
+
+/// Recent CPU and memory samples of a container, oldest first, for the
+/// sparklines in the inspect view
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct StatsHistory {
+    pub cpu: VecDeque<f64>,
+    pub memory: VecDeque<f64>,
+}
+
+impl StatsHistory {
+    /// Appends a sample, dropping the oldest ones beyond `capacity`
+    pub fn push(&mut self, stats: &ContainerStats, capacity: usize) {
+        for (samples, value) in [(&mut self.cpu, stats.cpu), (&mut self.memory, stats.memory)] {
+            samples.push_back(value);
+            while samples.len() > capacity {
+                samples.pop_front();
+            }
+        }
+    }
+}
+
+/// Disk usage totals for one category of Docker objects (images, volumes, ...)
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct DiskUsageCategory {
+    /// Total number of objects in this category
+    pub total_count: u64,
+    /// Number of objects in use by at least one container
+    pub active_count: u64,
+    /// Disk space used in bytes
+    pub total_size: u64,
+    /// Disk space that can be reclaimed by pruning, in bytes
+    pub reclaimable: u64,
+}
+
+/// `docker system df`-style summary for a single host
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct DiskUsageSummary {
+    pub images: DiskUsageCategory,
+    pub containers: DiskUsageCategory,
+    pub volumes: DiskUsageCategory,
+    pub build_cache: DiskUsageCategory,
+}
+
+/// A user-defined command offered in the action menu of running containers
+/// (config: `custom_actions`)
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct CustomAction {
+    /// Menu entry text
+    pub label: String,
+    /// Command run with `sh -c` inside the container; `{id}` and `{name}` are
+    /// replaced with the container's ID and name
+    pub command: String,
+}
+
+impl CustomAction {
+    /// Returns the command with the container's placeholders filled in
+    pub fn command_for(&self, container_id: &str, container_name: &str) -> String {
+        self.command
+            .replace("{id}", container_id)
+            .replace("{name}", container_name)
+    }
+}
+
+/// Usage percentages above which CPU or memory is shown as medium (`warn`) and
+/// high (`critical`) (config: `cpu_thresholds`, `memory_thresholds`)
+#[derive(Clone, Copy, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Thresholds {
+    pub warn: f64,
+    pub critical: f64,
+}
+
+impl Default for Thresholds {
+    fn default() -> Self {
+        Self {
+            warn: 50.0,
+            critical: 80.0,
+        }
+    }
+}
+
+impl Thresholds {
+    /// Checks that `0 <= warn < critical`; `name` is the config key for the error
+    pub fn validate(&self, name: &str) -> Result<(), String> {
+        if self.warn >= 0.0 && self.warn < self.critical {
+            Ok(())
+        } else {
+            Err(format!(
+                "Invalid {}: warn ({}) must be at least 0 and below critical ({})",
+                name, self.warn, self.critical
+            ))
+        }
+    }
+}
+
+/// Output of a custom action shown in the output popup
+#[derive(Clone, Debug, PartialEq)]
+pub struct CustomActionRun {
+    pub container_key: ContainerKey,
+    pub label: String,
+    /// None while the command is still running; Err if it could not be run
+    pub output: Option<Result<String, String>>,
+    /// Lines scrolled up from the end of the output (0 keeps the tail in view)
+    pub scroll_back: usize,
+}
+
+/// Contents and scroll position of the inspect view
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct InspectView {
+    /// None while the inspect request is in flight
+    pub text: Option<String>,
+    /// First visible line; clamped to the text when rendering
+    pub scroll_offset: usize,
+}
+
+/// How container memory usage is calculated from Docker's memory stats
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum MemoryMode {
+    /// Raw usage minus inactive page cache, matching `docker stats` (default)
+    #[default]
+    Docker,
+    /// Resident (anonymous) memory only: `anon` on cgroups v2, `rss` on cgroups v1.
+    /// Excludes all page cache, so file-heavy workloads don't look memory-bound.
+    Rss,
+}
+
+impl FromStr for MemoryMode {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "docker" => Ok(MemoryMode::Docker),
+            "rss" => Ok(MemoryMode::Rss),
+            _ => Err(()),
+        }
+    }
+}
+
+/// How container stats are collected from the Docker API
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum StatsMode {
+    /// One long-lived stats stream per container (default, smoothest updates)
+    #[default]
+    Stream,
+    /// A single stats request per container every refresh interval, so no
+    /// connection stays open (cheaper on hosts with hundreds of containers)
+    Poll,
+}
+
+impl FromStr for StatsMode {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "stream" => Ok(StatsMode::Stream),
+            "poll" => Ok(StatsMode::Poll),
+            _ => Err(()),
+        }
+    }
+}
+
+/// Which container count leads the table title ("12 running / 15 total")
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum TitleCount {
+    /// Running containers first (default)
+    #[default]
+    Running,
+    /// All known containers first
+    Total,
+}
+
+impl FromStr for TitleCount {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "running" => Ok(TitleCount::Running),
+            "total" => Ok(TitleCount::Total),
+            _ => Err(()),
+        }
+    }
+}
+
+/// Container list filter by state, cycled with 'p'
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum StateFilter {
+    /// No state filter (default)
+    #[default]
+    All,
+    Running,
+    /// Exited containers; shown even while stopped containers are hidden
+    Exited,
+    /// Containers whose health check fails
+    Unhealthy,
+}
+
+impl StateFilter {
+    /// The next filter in the 'p' cycle
+    pub fn next(self) -> Self {
+        match self {
+            StateFilter::All => StateFilter::Running,
+            StateFilter::Running => StateFilter::Exited,
+            StateFilter::Exited => StateFilter::Unhealthy,
+            StateFilter::Unhealthy => StateFilter::All,
+        }
+    }
+
+    /// Label shown in the table title while the filter is active
+    pub fn label(self) -> &'static str {
+        match self {
+            StateFilter::All => "all",
+            StateFilter::Running => "running",
+            StateFilter::Exited => "exited",
+            StateFilter::Unhealthy => "unhealthy",
+        }
+    }
+
+    /// Whether `container` passes this filter
+    pub fn matches(self, container: &Container) -> bool {
+        match self {
+            StateFilter::All => true,
+            StateFilter::Running => container.state == ContainerState::Running,
+            StateFilter::Exited => container.state == ContainerState::Exited,
+            StateFilter::Unhealthy => container.health == Some(HealthStatus::Unhealthy),
+        }
+    }
+}
+
+/// How the Created column shows when a container started (config: `time_format`)
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum TimeFormat {
+    /// "2 hours ago" (default)
+    #[default]
+    Relative,
+    /// "Oct 16 14:03", in the configured [`DisplayTimezone`]
+    Absolute,
+}
+
+impl FromStr for TimeFormat {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "relative" => Ok(TimeFormat::Relative),
+            "absolute" => Ok(TimeFormat::Absolute),
+            _ => Err(()),
+        }
+    }
+}
+
+/// Time zone for absolute times: log timestamps and the Created column
+/// with `time_format: absolute` (config: `timezone`)
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum DisplayTimezone {
+    /// The system's local time zone (default)
+    #[default]
+    Local,
+    Utc,
+}
+
+impl DisplayTimezone {
+    /// Formats `time` in this time zone with a chrono format string
+    pub fn format(self, time: &DateTime<Utc>, fmt: &str) -> String {
+        match self {
+            DisplayTimezone::Local => time.with_timezone(&chrono::Local).format(fmt).to_string(),
+            DisplayTimezone::Utc => time.format(fmt).to_string(),
+        }
+    }
+}
+
+impl FromStr for DisplayTimezone {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "local" => Ok(DisplayTimezone::Local),
+            "utc" => Ok(DisplayTimezone::Utc),
+            _ => Err(()),
+        }
+    }
+}
+
+/// Connection status of a Docker host as last reported to the UI
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum HostStatus {
+    /// Connection attempt in progress (startup only)
+    Connecting,
+    /// Host is connected and its container manager is running
+    Connected,
+    /// Connecting failed or the connection was lost
+    Failed,
 }
 
 /// Unique key for identifying containers across multiple hosts
@@ -126,29 +478,62 @@ pub enum AppEvent {
     ContainerStateChanged(ContainerKey, ContainerState),
     /// Stats update for an existing container on a specific host
     ContainerStat(ContainerKey, ContainerStats),
+    /// Stats updates for several containers on one host (container id -> stats),
+    /// coalesced by the host's stats tasks to cut channel traffic
+    ContainerStatBatch(HostId, Vec<(String, ContainerStats)>),
     /// Health status changed for a container
     ContainerHealthChanged(ContainerKey, HealthStatus),
+    /// A container died with this exit code (sent just before its state change)
+    ContainerExitCode(ContainerKey, i64),
+    /// Creation time looked up for a container that was listed without one
+    ContainerCreatedAt(ContainerKey, DateTime<Utc>),
+    /// Periodic container size refresh for a host (container id -> size)
+    ContainerSizes(HostId, Vec<(String, ContainerSize)>),
     /// User requested to quit
     Quit,
     /// Terminal was resized
     Resize,
     /// A keyboard input event - dispatched by AppState based on view state
     KeyInput(crossterm::event::KeyEvent),
+    /// Left mouse button pressed at (column, row) of the terminal
+    MouseClick(u16, u16),
+    /// Mouse wheel scrolled up
+    MouseScrollUp,
+    /// Mouse wheel scrolled down
+    MouseScrollDown,
+    /// Sort by this column; toggles the direction if it is already the sort field
+    SetSortField(Column),
     /// Batch of historical logs to prepend (initial load AND pagination)
     /// bool indicates if there are more historical logs available before this batch
     LogBatchPrepend(ContainerKey, Vec<LogEntry>, bool),
     /// New log line received from streaming logs
     LogLine(ContainerKey, LogEntry),
+    /// Run an action on a container without going through the action menu
+    #[cfg(feature = "control-socket")]
+    RequestAction(ContainerKey, ContainerAction),
     /// Action is in progress
     ActionInProgress(ContainerKey, ContainerAction),
     /// Action completed successfully
     ActionSuccess(ContainerKey, ContainerAction),
     /// Action failed with error
     ActionError(ContainerKey, ContainerAction, String),
+    /// Container was renamed from the action menu (new name)
+    ContainerRenamed(ContainerKey, String),
     /// Connection to a Docker host failed
     ConnectionError(HostId, String),
     /// A new Docker host has successfully connected
     HostConnected(crate::docker::connection::DockerHost),
+    /// A custom action finished: (container, label, output or error)
+    CustomActionFinished(ContainerKey, String, Result<String, String>),
+    /// Disk usage summary fetched for a host
+    DiskUsageLoaded(HostId, DiskUsageSummary),
+    /// Fetching the disk usage summary for a host failed
+    DiskUsageError(HostId, String),
+    /// Formatted inspect summary of a container (or the error fetching it)
+    InspectResult(ContainerKey, String),
+    /// Log a summary of the app state for debugging (sent on SIGUSR1)
+    #[cfg(unix)]
+    DumpState,
 }
 
 pub type EventSender = mpsc::Sender<AppEvent>;
@@ -162,6 +547,10 @@ pub enum RenderAction {
     Render,
     /// Start a shell session for a container
     StartShell(ContainerKey),
+    /// Open a container's logs in the external pager
+    OpenPager(ContainerKey),
+    /// Copy text to the system clipboard (via the terminal, so it also works over SSH)
+    CopyToClipboard(String),
 }
 
 /// Current view state of the application
@@ -179,6 +568,16 @@ pub enum ViewState {
     ColumnSelector,
     /// Sort selector popup
     SortSelector,
+    /// Disk usage summary popup (images, containers, volumes, build cache per host)
+    DiskUsage,
+    /// Output of a custom action run from the action menu
+    CustomActionOutput,
+    /// Host picker popup with a fuzzy-filter query
+    HostPicker,
+    /// Inspect summary (image, command, ports, env, mounts, labels) of a container
+    Inspect(ContainerKey),
+    /// Asks before running a destructive action on a container (y runs it, n/Esc cancels)
+    ConfirmAction(ContainerKey, ContainerAction),
 }
 
 /// Available actions for containers
@@ -189,9 +588,25 @@ pub enum ContainerAction {
     Restart,
     Remove,
     Shell,
+    /// Run one command non-interactively and show its output
+    Exec,
+    Rename,
+    Pager,
 }
 
 impl ContainerAction {
+    /// All actions, in the order they are listed in the help popup
+    pub const ALL: [ContainerAction; 8] = [
+        ContainerAction::Start,
+        ContainerAction::Stop,
+        ContainerAction::Restart,
+        ContainerAction::Remove,
+        ContainerAction::Shell,
+        ContainerAction::Exec,
+        ContainerAction::Rename,
+        ContainerAction::Pager,
+    ];
+
     /// Returns the display name for this action
     pub fn display_name(self) -> &'static str {
         match self {
@@ -200,22 +615,43 @@ impl ContainerAction {
             ContainerAction::Restart => "Restart",
             ContainerAction::Remove => "Remove",
             ContainerAction::Shell => "Shell",
+            ContainerAction::Exec => "Exec command",
+            ContainerAction::Rename => "Rename",
+            ContainerAction::Pager => "Logs in pager",
         }
     }
 
+    /// Whether the action can't be undone, so it is confirmed first (config:
+    /// `confirm_destructive`). Only Remove: Stop and Restart can be reversed by
+    /// starting the container again.
+    pub fn is_destructive(self) -> bool {
+        self == ContainerAction::Remove
+    }
+
     /// Returns all available actions for a given container state
     pub fn available_for_state(state: &ContainerState) -> Vec<ContainerAction> {
         match state {
             ContainerState::Running => vec![
                 ContainerAction::Shell,
+                ContainerAction::Exec,
                 ContainerAction::Stop,
                 ContainerAction::Restart,
+                ContainerAction::Rename,
                 ContainerAction::Remove,
+                ContainerAction::Pager,
+            ],
+            ContainerState::Paused => vec![
+                ContainerAction::Stop,
+                ContainerAction::Rename,
+                ContainerAction::Remove,
+                ContainerAction::Pager,
+            ],
+            ContainerState::Exited | ContainerState::Created | ContainerState::Dead => vec![
+                ContainerAction::Start,
+                ContainerAction::Rename,
+                ContainerAction::Remove,
+                ContainerAction::Pager,
             ],
-            ContainerState::Paused => vec![ContainerAction::Stop, ContainerAction::Remove],
-            ContainerState::Exited | ContainerState::Created | ContainerState::Dead => {
-                vec![ContainerAction::Start, ContainerAction::Remove]
-            }
             ContainerState::Restarting | ContainerState::Removing => vec![],
             ContainerState::Unknown => vec![],
         }
@@ -245,6 +681,23 @@ impl SortDirection {
             SortDirection::Descending => "▼",
         }
     }
+
+    /// Parses a config `sort_direction` value ("asc"/"desc", case-insensitive)
+    pub fn from_config_str(s: &str) -> Option<Self> {
+        match s.to_lowercase().as_str() {
+            "asc" | "ascending" => Some(SortDirection::Ascending),
+            "desc" | "descending" => Some(SortDirection::Descending),
+            _ => None,
+        }
+    }
+
+    /// Returns the value saved as config `sort_direction`
+    pub fn config_str(self) -> &'static str {
+        match self {
+            SortDirection::Ascending => "asc",
+            SortDirection::Descending => "desc",
+        }
+    }
 }
 
 /// Combined sort state (field + direction)
@@ -288,12 +741,19 @@ pub struct LogState {
     /// Raw log entries with timestamps (used for progress calculation)
     pub log_entries: Vec<crate::docker::logs::LogEntry>,
 
-    /// Pre-formatted lines for rendering (cached to avoid reformatting every frame)
+    /// Pre-formatted lines for rendering (cached to avoid reformatting every frame).
+    /// Only entries matching the log filter get a line.
     pub formatted_lines: Vec<Line<'static>>,
 
+    /// Index into `log_entries` of each formatted line
+    pub line_entries: Vec<usize>,
+
     /// Current scroll offset in visual lines (not entry count)
     pub scroll_offset: usize,
 
+    /// Columns scrolled to the right while wrapping is off; clamped when rendering
+    pub horizontal_offset: usize,
+
     /// Handle to the log streaming task (for cancellation)
     pub stream_handle: Option<tokio::task::JoinHandle<()>>,
 
@@ -309,28 +769,44 @@ pub struct LogState {
     /// Total number of logs loaded so far
     pub total_loaded: usize,
 
-    /// Timestamp when the container was created (for progress bar calculation)
-    pub container_created_at: Option<DateTime<Utc>>,
-
     /// Track if we're currently fetching older logs (prevent duplicate requests)
     pub fetching_older: bool,
+
+    /// Max scroll offset as of the last render (detects newly arrived lines)
+    pub max_scroll: usize,
+
+    /// The container's host has disconnected, so no more logs can arrive
+    pub host_disconnected: bool,
+
+    /// Lines were dropped to stay under `log_max_lines`: oldest ones that haven't
+    /// been fetched again by scrolling to the top, or newest ones while paging back
+    pub truncated: bool,
+
+    /// Newest lines were dropped to stay under `log_max_lines` while paging back.
+    /// They can't be fetched again, so the buffer stays truncated.
+    pub newest_dropped: bool,
 }
 
 impl LogState {
     /// Create a new LogState for a container
-    pub fn new(container_key: ContainerKey, container_created_at: Option<DateTime<Utc>>) -> Self {
+    pub fn new(container_key: ContainerKey) -> Self {
         Self {
             container_key,
             log_entries: Vec::new(),
             formatted_lines: Vec::new(),
+            line_entries: Vec::new(),
             scroll_offset: 0,
             stream_handle: None,
             oldest_timestamp: None,
             newest_timestamp: None,
             has_more_history: false,
             total_loaded: 0,
-            container_created_at,
             fetching_older: false,
+            max_scroll: 0,
+            horizontal_offset: 0,
+            host_disconnected: false,
+            truncated: false,
+            newest_dropped: false,
         }
     }
 
@@ -338,45 +814,37 @@ impl LogState {
     /// Used in tests and when bulk-replacing entries.
     #[cfg_attr(not(test), allow(dead_code))]
     pub fn set_entries(&mut self, entries: Vec<crate::docker::logs::LogEntry>) {
-        self.formatted_lines = entries.iter().map(|e| e.format()).collect();
         self.log_entries = entries;
+        self.rebuild_formatted_lines(true, DisplayTimezone::Local, None);
     }
 
-    /// Calculate what percentage of log history the current visible page represents.
-    /// Takes the entry index of the topmost visible log entry.
-    /// 0% = viewing logs from container creation time (top), 100% = viewing current/newest logs (bottom)
-    /// Returns None if we can't calculate (missing timestamps)
-    pub fn calculate_progress(&self, visible_entry_index: usize) -> Option<f64> {
-        let container_created = self.container_created_at?;
-        let newest_loaded = self.newest_timestamp?;
-
-        // Get the timestamp of the currently visible log entry
-        let visible_timestamp = if visible_entry_index < self.log_entries.len() {
-            self.log_entries[visible_entry_index].timestamp
-        } else if !self.log_entries.is_empty() {
-            // If index is out of range, use the last entry
-            self.log_entries.last()?.timestamp
-        } else {
-            return None;
-        };
-
-        // Calculate time range from container creation to newest log
-        let total_duration = (newest_loaded - container_created).num_seconds() as f64;
-
-        // Avoid division by zero
-        if total_duration <= 0.0 {
-            return Some(100.0);
+    /// Re-formats every retained entry that matches `filter`, e.g. after a log
+    /// display setting or the filter changed
+    pub fn rebuild_formatted_lines(
+        &mut self,
+        show_timestamp: bool,
+        timezone: DisplayTimezone,
+        filter: Option<&str>,
+    ) {
+        self.formatted_lines.clear();
+        self.line_entries.clear();
+        for (i, entry) in self.log_entries.iter().enumerate() {
+            if filter.is_none_or(|query| entry.matches_filter(query)) {
+                self.formatted_lines
+                    .push(entry.format(show_timestamp, timezone));
+                self.line_entries.push(i);
+            }
         }
+    }
+}
 
-        // Calculate how far the visible timestamp is from container creation
-        let visible_offset = (visible_timestamp - container_created).num_seconds() as f64;
-
-        // Percentage: how far through the log history we are
-        // 0% = at container creation (visible_timestamp = container_created)
-        // 100% = at newest logs (visible_timestamp = newest_loaded)
-        let percentage = (visible_offset / total_duration) * 100.0;
-
-        Some(percentage.clamp(0.0, 100.0))
+impl Drop for LogState {
+    /// Stops the log stream with its state, so replacing or clearing the log
+    /// view can never leave a stream (and its Docker connection) running
+    fn drop(&mut self) {
+        if let Some(handle) = self.stream_handle.take() {
+            handle.abort();
+        }
     }
 }
 
@@ -388,14 +856,19 @@ pub enum Column {
     Id,
     Host,
     Compose,
+    Image,
     Cpu,
     Memory,
     NetTx,
     NetRx,
+    NetIo,
     DiskRead,
     DiskWrite,
+    DiskIo,
     Uptime,
     Restarts,
+    Size,
+    Ports,
 }
 
 impl Column {
@@ -406,14 +879,19 @@ impl Column {
             Column::Id => "ID",
             Column::Host => "Host",
             Column::Compose => "Compose",
+            Column::Image => "Image",
             Column::Cpu => "CPU %",
             Column::Memory => "Memory %",
             Column::NetTx => "Net TX",
             Column::NetRx => "Net RX",
+            Column::NetIo => "Net I/O",
             Column::DiskRead => "Disk R",
             Column::DiskWrite => "Disk W",
+            Column::DiskIo => "Disk I/O",
             Column::Uptime => "Uptime",
             Column::Restarts => "Restarts",
+            Column::Size => "Size",
+            Column::Ports => "Ports",
         }
     }
 
@@ -424,14 +902,19 @@ impl Column {
             Column::Id => "id",
             Column::Host => "host",
             Column::Compose => "compose",
+            Column::Image => "image",
             Column::Cpu => "cpu",
             Column::Memory => "memory",
             Column::NetTx => "net_tx",
             Column::NetRx => "net_rx",
+            Column::NetIo => "net_io",
             Column::DiskRead => "disk_read",
             Column::DiskWrite => "disk_write",
+            Column::DiskIo => "disk_io",
             Column::Uptime => "uptime",
             Column::Restarts => "restarts",
+            Column::Size => "size",
+            Column::Ports => "ports",
         }
     }
 
@@ -442,14 +925,19 @@ impl Column {
             "id" => Some(Column::Id),
             "host" => Some(Column::Host),
             "compose" => Some(Column::Compose),
+            "image" => Some(Column::Image),
             "cpu" => Some(Column::Cpu),
             "memory" => Some(Column::Memory),
             "net_tx" => Some(Column::NetTx),
             "net_rx" => Some(Column::NetRx),
+            "net_io" => Some(Column::NetIo),
             "disk_read" => Some(Column::DiskRead),
             "disk_write" => Some(Column::DiskWrite),
+            "disk_io" => Some(Column::DiskIo),
             "uptime" => Some(Column::Uptime),
             "restarts" => Some(Column::Restarts),
+            "size" => Some(Column::Size),
+            "ports" => Some(Column::Ports),
             _ => None,
         }
     }
@@ -461,14 +949,19 @@ impl Column {
             Column::Name,
             Column::Host,
             Column::Compose,
+            Column::Image,
             Column::Cpu,
             Column::Memory,
             Column::NetTx,
             Column::NetRx,
+            Column::NetIo,
             Column::DiskRead,
             Column::DiskWrite,
+            Column::DiskIo,
             Column::Uptime,
             Column::Restarts,
+            Column::Size,
+            Column::Ports,
         ]
     }
 
@@ -476,24 +969,49 @@ impl Column {
     pub fn default_visible(self) -> bool {
         !matches!(
             self,
-            Column::Restarts | Column::Compose | Column::DiskRead | Column::DiskWrite
+            Column::Restarts
+                | Column::Compose
+                | Column::Image
+                | Column::NetIo
+                | Column::DiskRead
+                | Column::DiskWrite
+                | Column::DiskIo
+                | Column::Size
+                | Column::Ports
         )
     }
 
+    /// Columns whose values a combined column adds up. The header shows the sort
+    /// arrow on these while sorting by the combined column with it hidden.
+    pub fn components(self) -> &'static [Column] {
+        match self {
+            Column::NetIo => &[Column::NetTx, Column::NetRx],
+            Column::DiskIo => &[Column::DiskRead, Column::DiskWrite],
+            _ => &[],
+        }
+    }
+
     /// Returns the default sort direction when sorting by this column
     pub fn default_sort_direction(self) -> SortDirection {
         match self {
-            Column::Name | Column::Id | Column::Host | Column::Compose | Column::Status => {
-                SortDirection::Ascending
-            }
+            Column::Name
+            | Column::Id
+            | Column::Host
+            | Column::Compose
+            | Column::Image
+            | Column::Status
+            | Column::Ports => SortDirection::Ascending,
             Column::Uptime
             | Column::Cpu
             | Column::Memory
             | Column::NetTx
             | Column::NetRx
+            | Column::NetIo
             | Column::DiskRead
             | Column::DiskWrite
-            | Column::Restarts => SortDirection::Descending,
+            | Column::DiskIo
+            | Column::Restarts
+            | Column::Size => SortDirection::Descending,
         }
     }
 
@@ -516,14 +1034,19 @@ impl Column {
             Column::Id => "ID",
             Column::Host => "Host",
             Column::Compose => "Compose",
+            Column::Image => "Image",
             Column::Cpu => "CPU",
             Column::Memory => "Memory",
             Column::NetTx => "Net TX",
             Column::NetRx => "Net RX",
+            Column::NetIo => "Net I/O (total)",
             Column::DiskRead => "Disk Read",
             Column::DiskWrite => "Disk Write",
+            Column::DiskIo => "Disk I/O (total)",
             Column::Uptime => "Uptime",
             Column::Restarts => "Restarts",
+            Column::Size => "Size",
+            Column::Ports => "Ports",
         }
     }
 }
@@ -606,6 +1129,25 @@ impl ColumnConfig {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_stats_mode_from_str() {
+        assert_eq!("stream".parse(), Ok(StatsMode::Stream));
+        assert_eq!("POLL".parse(), Ok(StatsMode::Poll));
+        assert_eq!("sometimes".parse::<StatsMode>(), Err(()));
+    }
+
+    #[test]
+    fn test_custom_action_command_placeholders() {
+        let action = CustomAction {
+            label: "Logs".to_string(),
+            command: "echo {name} {id} {name}".to_string(),
+        };
+        assert_eq!(
+            action.command_for("abc123def456", "nginx"),
+            "echo nginx abc123def456 nginx"
+        );
+    }
+
     #[test]
     fn test_column_default_sort_direction() {
         assert_eq!(
@@ -654,6 +1196,24 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_shell_action_only_for_running_containers() {
+        assert!(
+            ContainerAction::available_for_state(&ContainerState::Running)
+                .contains(&ContainerAction::Shell)
+        );
+        for state in [
+            ContainerState::Paused,
+            ContainerState::Exited,
+            ContainerState::Created,
+            ContainerState::Dead,
+        ] {
+            assert!(
+                !ContainerAction::available_for_state(&state).contains(&ContainerAction::Shell)
+            );
+        }
+    }
+
     #[test]
     fn test_sort_state_new() {
         let state = SortState::new(Column::Name);
@@ -665,6 +1225,48 @@ mod tests {
         assert_eq!(state.direction, SortDirection::Descending);
     }
 
+    #[test]
+    fn test_dropping_log_state_stops_stream() {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap();
+        runtime.block_on(async {
+            let handle = tokio::spawn(std::future::pending::<()>());
+            let abort_handle = handle.abort_handle();
+
+            let key = ContainerKey::new("local".to_string(), "abc123".to_string());
+            let mut state = LogState::new(key);
+            state.stream_handle = Some(handle);
+            drop(state);
+
+            tokio::task::yield_now().await;
+            assert!(abort_handle.is_finished());
+        });
+    }
+
+    #[test]
+    fn test_sort_config_round_trip() {
+        for direction in [SortDirection::Ascending, SortDirection::Descending] {
+            assert_eq!(
+                SortDirection::from_config_str(direction.config_str()),
+                Some(direction)
+            );
+        }
+        assert_eq!(
+            SortDirection::from_config_str("DESC"),
+            Some(SortDirection::Descending)
+        );
+        assert_eq!(SortDirection::from_config_str("up"), None);
+
+        // Saved sort fields are column IDs and must parse back
+        for column in Column::all_default() {
+            assert_eq!(Column::from_sort_str(column.id()), Some(column));
+        }
+        assert_eq!(Column::from_sort_str("CPU"), Some(Column::Cpu));
+        assert_eq!(Column::from_sort_str("bogus"), None);
+    }
+
     #[test]
     fn test_column_label() {
         assert_eq!(Column::Status.label(), "Status Icon");
@@ -675,8 +1277,10 @@ mod tests {
         assert_eq!(Column::Memory.label(), "Memory %");
         assert_eq!(Column::NetTx.label(), "Net TX");
         assert_eq!(Column::NetRx.label(), "Net RX");
+        assert_eq!(Column::NetIo.label(), "Net I/O");
         assert_eq!(Column::DiskRead.label(), "Disk R");
         assert_eq!(Column::DiskWrite.label(), "Disk W");
+        assert_eq!(Column::DiskIo.label(), "Disk I/O");
         assert_eq!(Column::Uptime.label(), "Uptime");
         assert_eq!(Column::Restarts.label(), "Restarts");
     }
@@ -684,8 +1288,8 @@ mod tests {
     #[test]
     fn test_column_config_default_all_visible() {
         let config = ColumnConfig::default();
-        assert_eq!(config.columns.len(), 13);
-        // All columns except Restarts, Compose, DiskRead, DiskWrite should be visible by default
+        assert_eq!(config.columns.len(), 18);
+        // All columns except Restarts, Compose, Image, NetIo, DiskRead, DiskWrite, DiskIo, Size, Ports should be visible by default
         for (col, visible) in &config.columns {
             assert_eq!(*visible, col.default_visible());
         }
@@ -702,7 +1306,7 @@ mod tests {
         config.columns[id_idx] = (Column::Id, false);
         let visible = config.visible_columns();
         assert!(!visible.contains(&Column::Id));
-        // Default has 9 visible (Restarts, Compose, DiskRead, DiskWrite hidden), minus Id = 8
+        // Default has 9 visible (Restarts, Compose, Image, NetIo, DiskRead, DiskWrite, DiskIo, Size, Ports hidden), minus Id = 8
         assert_eq!(visible.len(), 8);
     }
 
@@ -787,7 +1391,7 @@ mod tests {
         let config = ColumnConfig::from_config_strings(&strings);
         let visible = config.visible_columns();
         assert_eq!(visible, vec![Column::Status, Column::Name, Column::Cpu]);
-        assert_eq!(config.columns.len(), 13);
+        assert_eq!(config.columns.len(), 18);
     }
 
     #[test]
@@ -814,10 +1418,15 @@ mod tests {
         assert_eq!(Column::Memory.id(), "memory");
         assert_eq!(Column::NetTx.id(), "net_tx");
         assert_eq!(Column::NetRx.id(), "net_rx");
+        assert_eq!(Column::NetIo.id(), "net_io");
         assert_eq!(Column::DiskRead.id(), "disk_read");
         assert_eq!(Column::DiskWrite.id(), "disk_write");
+        assert_eq!(Column::DiskIo.id(), "disk_io");
         assert_eq!(Column::Uptime.id(), "uptime");
         assert_eq!(Column::Restarts.id(), "restarts");
+        assert_eq!(Column::Size.id(), "size");
+        assert_eq!(Column::Ports.id(), "ports");
+        assert_eq!(Column::Image.id(), "image");
     }
 
     #[test]
@@ -830,10 +1439,38 @@ mod tests {
         assert_eq!(Column::from_id("memory"), Some(Column::Memory));
         assert_eq!(Column::from_id("net_tx"), Some(Column::NetTx));
         assert_eq!(Column::from_id("net_rx"), Some(Column::NetRx));
+        assert_eq!(Column::from_id("net_io"), Some(Column::NetIo));
         assert_eq!(Column::from_id("disk_read"), Some(Column::DiskRead));
         assert_eq!(Column::from_id("disk_write"), Some(Column::DiskWrite));
+        assert_eq!(Column::from_id("disk_io"), Some(Column::DiskIo));
         assert_eq!(Column::from_id("uptime"), Some(Column::Uptime));
         assert_eq!(Column::from_id("restarts"), Some(Column::Restarts));
+        assert_eq!(Column::from_id("size"), Some(Column::Size));
+        assert_eq!(Column::from_id("ports"), Some(Column::Ports));
+        assert_eq!(Column::from_id("image"), Some(Column::Image));
         assert_eq!(Column::from_id("invalid"), None);
     }
+
+    #[test]
+    fn test_time_display_config() {
+        assert_eq!("Absolute".parse(), Ok(TimeFormat::Absolute));
+        assert_eq!("relative".parse(), Ok(TimeFormat::Relative));
+        assert_eq!("UTC".parse(), Ok(DisplayTimezone::Utc));
+        assert_eq!("local".parse(), Ok(DisplayTimezone::Local));
+        assert!("gmt".parse::<DisplayTimezone>().is_err());
+
+        let time = DateTime::parse_from_rfc3339("2025-10-29T23:15:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        assert_eq!(
+            DisplayTimezone::Utc.format(&time, "%b %d %H:%M"),
+            "Oct 29 23:15"
+        );
+        assert_eq!(
+            DisplayTimezone::Local.format(&time, "%b %d %H:%M"),
+            time.with_timezone(&chrono::Local)
+                .format("%b %d %H:%M")
+                .to_string()
+        );
+    }
 }
@@ -8,25 +8,75 @@ pub async fn execute_container_action(
     action: ContainerAction,
     tx: EventSender,
 ) {
-    // Send in-progress event
-    let _ = tx
-        .send(AppEvent::ActionInProgress(container_key.clone(), action))
-        .await;
+    let container_id = &container_key.container_id;
+    let in_progress = || tx.send(AppEvent::ActionInProgress(container_key.clone(), action));
 
-    // Execute the action using DockerHost methods
+    // Execute the action using DockerHost methods, marking it in progress first
     let result = match action {
-        ContainerAction::Start => host.start_container(&container_key.container_id).await,
-        ContainerAction::Stop => host.stop_container(&container_key.container_id).await,
-        ContainerAction::Restart => host.restart_container(&container_key.container_id).await,
-        ContainerAction::Remove => host.remove_container(&container_key.container_id).await,
-        ContainerAction::Shell => {
-            // Shell is handled separately in main.rs via StartShell event
-            // This path should never be reached
+        ContainerAction::Start => {
+            let _ = in_progress().await;
+            host.start_container(container_id).await
+        }
+        ContainerAction::Stop => {
+            let _ = in_progress().await;
+            host.stop_container(container_id).await
+        }
+        ContainerAction::Restart => {
+            let _ = in_progress().await;
+            host.restart_container(container_id).await
+        }
+        ContainerAction::Remove => {
+            let _ = in_progress().await;
+            host.remove_container(container_id).await
+        }
+        ContainerAction::Shell | ContainerAction::Exec | ContainerAction::Pager => {
+            // Shell and pager are handled separately in main.rs via the
+            // StartShell/OpenPager render actions, and exec prompts for its
+            // command first. This path should never be reached, and nothing
+            // is marked in progress for it
+            return;
+        }
+        ContainerAction::Rename => {
+            // Needs the new name; see `execute_rename_container`
             return;
         }
     };
 
-    // Send result event
+    send_action_result(&tx, container_key, action, result).await;
+}
+
+/// Renames a container asynchronously. On success the new name is sent ahead
+/// of `ActionSuccess` so the list shows it without waiting for a refresh.
+pub async fn execute_rename_container(
+    host: DockerHost,
+    container_key: ContainerKey,
+    name: String,
+    tx: EventSender,
+) {
+    let action = ContainerAction::Rename;
+    let _ = tx
+        .send(AppEvent::ActionInProgress(container_key.clone(), action))
+        .await;
+
+    let result = host
+        .rename_container(&container_key.container_id, &name)
+        .await;
+    if result.is_ok() {
+        let _ = tx
+            .send(AppEvent::ContainerRenamed(container_key.clone(), name))
+            .await;
+    }
+
+    send_action_result(&tx, container_key, action, result).await;
+}
+
+/// Sends the result event of a finished action
+async fn send_action_result(
+    tx: &EventSender,
+    container_key: ContainerKey,
+    action: ContainerAction,
+    result: Result<(), String>,
+) {
     match result {
         Ok(_) => {
             let _ = tx
@@ -40,3 +90,32 @@ pub async fn execute_container_action(
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    #[tokio::test]
+    async fn test_actions_run_elsewhere_are_not_marked_in_progress() {
+        let docker = bollard::Docker::connect_with_http(
+            "tcp://localhost:2375",
+            4,
+            bollard::API_DEFAULT_VERSION,
+        )
+        .unwrap();
+        let host = DockerHost::new("local".to_string(), docker, None, HashMap::new());
+        let key = ContainerKey::new("local".to_string(), "abc123".to_string());
+        let (tx, mut rx) = tokio::sync::mpsc::channel(8);
+
+        for action in [
+            ContainerAction::Shell,
+            ContainerAction::Exec,
+            ContainerAction::Pager,
+            ContainerAction::Rename,
+        ] {
+            execute_container_action(host.clone(), key.clone(), action, tx.clone()).await;
+        }
+        assert!(rx.try_recv().is_err());
+    }
+}
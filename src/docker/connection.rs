@@ -1,32 +1,91 @@
+use bollard::models::ContainerSummaryStateEnum;
 use bollard::query_parameters::{EventsOptions, InspectContainerOptions, ListContainersOptions};
 use bollard::{API_DEFAULT_VERSION, Docker};
 use chrono::{DateTime, Utc};
 use futures_util::stream::StreamExt;
 use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
 use std::time::Duration;
+use tokio::sync::{Notify, Semaphore};
 
 use crate::core::types::{
-    AppEvent, Container, ContainerKey, ContainerState, ContainerStats, EventSender, HostId,
+    AppEvent, Container, ContainerKey, ContainerSize, ContainerState, ContainerStats,
+    DiskUsageCategory, DiskUsageSummary, EventSender, HostId, MemoryMode, PublishedPort, StatsMode,
+};
+use crate::docker::stats::{
+    StatsSender, poll_host_stats, spawn_stats_batcher, stream_container_stats,
 };
-use crate::docker::stats::stream_container_stats;
 
 /// Docker container IDs are 64-char hex strings; like the Docker CLI we track
 /// and display only the first 12 characters.
 const SHORT_ID_LEN: usize = 12;
 
+/// Above this many running containers, SSH hosts without an explicit `stats_mode`
+/// poll stats instead of streaming them. Every stream holds its own connection,
+/// and over SSH each connection is a separate `ssh` session.
+const SSH_MAX_STATS_STREAMS: usize = 32;
+
+/// Stats polls allowed in flight per host (see [`DockerHost::stats_poll_limit`])
+const MAX_CONCURRENT_STATS_POLLS: usize = 16;
+
+/// Output kept from a custom action command; anything beyond is dropped
+const MAX_COMMAND_OUTPUT: usize = 64 * 1024;
+
+/// How often container sizes are refreshed when `container_size` is enabled
+const CONTAINER_SIZE_INTERVAL: Duration = Duration::from_secs(60);
+
 /// Returns the first [`SHORT_ID_LEN`] characters of a container ID, or the whole
 /// ID if it is shorter.
 fn short_id(id: &str) -> &str {
     id.get(..SHORT_ID_LEN).unwrap_or(id)
 }
 
+/// Returns whether `id` looks like a Docker container ID (non-empty hex).
+/// Some event sources (e.g. buildkit) emit container events without a usable
+/// ID, which would otherwise end up as phantom rows keyed by "".
+fn is_valid_container_id(id: &str) -> bool {
+    !id.is_empty() && id.chars().all(|c| c.is_ascii_hexdigit())
+}
+
 /// Represents a Docker host connection with its identifier
 #[derive(Clone, Debug)]
 pub struct DockerHost {
     pub host_id: HostId,
+    /// Name shown for this host instead of `host_id` (config: `name`)
+    pub name: Option<String>,
     pub docker: Docker,
     pub dozzle_url: Option<String>,
     pub filters: HashMap<String, Vec<String>>,
+    /// How memory usage is calculated for this host's containers
+    pub memory_mode: MemoryMode,
+    /// Minimum interval between stats updates for this host (None = every sample)
+    pub refresh_interval: Option<Duration>,
+    /// Whether stats are streamed or polled for this host's containers.
+    /// None until configured or resolved by [`DockerHost::resolve_stats_mode`].
+    pub stats_mode: Option<StatsMode>,
+    /// Whether this host is reached over SSH (every Docker connection is an `ssh` session)
+    pub is_ssh: bool,
+    /// Bounds how many stats polls run at once against this host (`stats_mode: poll`),
+    /// so a large host doesn't see a burst of hundreds of requests every interval
+    pub stats_poll_limit: Arc<Semaphore>,
+    /// Whether to periodically collect container sizes (`docker ps --size`)
+    pub container_size: bool,
+    /// Whether logs are requested with the logging driver's attributes (config: `log_details`)
+    pub log_details: bool,
+    /// Whether opening a shell on this host asks for confirmation first
+    pub confirm_shell: bool,
+    /// Shell to run for Shell sessions instead of bash, falling back to sh (config: `shell`)
+    pub shell: Option<String>,
+    /// Docker API version agreed with the daemon on connect, e.g. "1.41".
+    /// None until negotiated (requests then use bollard's default version).
+    pub api_version: Option<String>,
+    /// Wakes this host's container manager to list its containers again ('r').
+    /// Shared by every clone, so the UI's copy can reach the manager.
+    pub refresh: Arc<Notify>,
+    /// Where this host's stats streams send samples to be batched, set by the
+    /// container manager. Without it each sample is sent as its own event.
+    pub stats_tx: Option<StatsSender>,
 }
 
 impl DockerHost {
@@ -38,15 +97,48 @@ impl DockerHost {
     ) -> Self {
         Self {
             host_id,
+            name: None,
             docker,
             dozzle_url,
             filters,
+            memory_mode: MemoryMode::default(),
+            refresh_interval: None,
+            stats_mode: None,
+            is_ssh: false,
+            stats_poll_limit: Arc::new(Semaphore::new(MAX_CONCURRENT_STATS_POLLS)),
+            container_size: false,
+            log_details: false,
+            confirm_shell: false,
+            shell: None,
+            api_version: None,
+            refresh: Arc::new(Notify::new()),
+            stats_tx: None,
         }
     }
 
+    /// Picks the stats mode when none was configured: streaming, unless this is
+    /// an SSH host with more running containers than [`SSH_MAX_STATS_STREAMS`]
+    fn resolve_stats_mode(&mut self, running_containers: usize) {
+        if self.stats_mode.is_some() {
+            return;
+        }
+
+        let mode = if self.is_ssh && running_containers > SSH_MAX_STATS_STREAMS {
+            tracing::warn!(
+                "{} has {} running containers; polling stats to avoid one SSH session per container (set stats_mode to override)",
+                self.host_id,
+                running_containers
+            );
+            StatsMode::Poll
+        } else {
+            StatsMode::Stream
+        };
+        self.stats_mode = Some(mode);
+    }
+
     /// Fetches the initial list of containers and starts monitoring them
     async fn fetch_initial_containers(
-        &self,
+        &mut self,
         tx: &EventSender,
         active_containers: &mut HashMap<String, tokio::task::JoinHandle<()>>,
     ) {
@@ -63,12 +155,18 @@ impl DockerHost {
         let list_options = Some(list_options);
 
         if let Ok(container_list) = self.docker.list_containers(list_options).await {
+            let running = container_list
+                .iter()
+                .filter(|c| c.state == Some(ContainerSummaryStateEnum::RUNNING))
+                .count();
+            self.resolve_stats_mode(running);
+
             let mut initial_containers = Vec::new();
 
             for container in container_list {
                 let full_id = container.id.clone().unwrap_or_default();
-                if full_id.is_empty() {
-                    tracing::warn!("Skipping container with empty ID");
+                if !is_valid_container_id(&full_id) {
+                    tracing::warn!("Skipping container with invalid ID {:?}", full_id);
                     continue;
                 }
                 let truncated_id = short_id(&full_id).to_string();
@@ -97,13 +195,23 @@ impl DockerHost {
                 // Check if container is running before moving state
                 let is_running = state == ContainerState::Running;
 
-                // Fetch restart count via inspect (not available in list API)
-                let restart_count = self
+                // Fetch restart count and health-check command via inspect
+                // (not available in list API)
+                let inspect = self
                     .docker
                     .inspect_container(&full_id, None::<InspectContainerOptions>)
                     .await
-                    .ok()
-                    .and_then(|inspect| inspect.restart_count);
+                    .ok();
+                let restart_count = inspect.as_ref().and_then(|i| i.restart_count);
+                let health_check = inspect.as_ref().and_then(health_check_command);
+                let ports = inspect.as_ref().map(published_ports).unwrap_or_default();
+                let exit_code = inspect
+                    .as_ref()
+                    .filter(|_| !is_running)
+                    .and_then(|i| i.state.as_ref())
+                    .and_then(|s| s.exit_code);
+
+                let image = container.image.clone().unwrap_or_default();
 
                 let compose_project = container
                     .labels
@@ -112,7 +220,9 @@ impl DockerHost {
 
                 let container_info = Container {
                     id: truncated_id.clone(),
+                    full_id: full_id.clone(),
                     name: name.clone(),
+                    image,
                     state,
                     health,
                     created,
@@ -121,6 +231,11 @@ impl DockerHost {
                     dozzle_url: self.dozzle_url.clone(),
                     restart_count,
                     compose_project,
+                    size: None,
+                    health_check,
+                    last_stats: None,
+                    exit_code,
+                    ports,
                 };
 
                 initial_containers.push(container_info);
@@ -131,24 +246,77 @@ impl DockerHost {
                 }
             }
 
-            // Send all initial containers in one event
-            if !initial_containers.is_empty() {
-                let _ = tx
-                    .send(AppEvent::InitialContainerList(
-                        self.host_id.clone(),
-                        initial_containers,
-                    ))
-                    .await;
-            }
+            // Send all initial containers in one event. Sent even when empty, so the
+            // UI knows this host has finished loading.
+            let _ = tx
+                .send(AppEvent::InitialContainerList(
+                    self.host_id.clone(),
+                    initial_containers,
+                ))
+                .await;
         }
     }
 
-    /// Monitors Docker events for container start/stop/die events
+    /// Lists the short IDs of this host's running containers, honoring its filters
+    pub(crate) async fn running_container_ids(&self) -> Result<Vec<String>, String> {
+        // Without `all`, Docker lists running (and paused) containers only
+        let list_options = ListContainersOptions {
+            filters: (!self.filters.is_empty()).then(|| self.filters.clone()),
+            ..Default::default()
+        };
+
+        let containers = self
+            .docker
+            .list_containers(Some(list_options))
+            .await
+            .map_err(|e| format!("Failed to list containers: {}", e))?;
+
+        Ok(containers
+            .into_iter()
+            .filter(|c| c.state == Some(ContainerSummaryStateEnum::RUNNING))
+            .filter_map(|c| c.id)
+            .filter(|id| is_valid_container_id(id))
+            .map(|id| short_id(&id).to_string())
+            .collect())
+    }
+
+    /// Fetches writable-layer and total sizes for all containers on this host.
+    /// Docker computes these by walking each container's filesystem, so this is
+    /// much slower than a plain listing and should be called sparingly.
+    async fn fetch_container_sizes(&self) -> Result<Vec<(String, ContainerSize)>, String> {
+        let list_options = ListContainersOptions {
+            all: true,
+            size: true,
+            filters: (!self.filters.is_empty()).then(|| self.filters.clone()),
+            ..Default::default()
+        };
+
+        let containers = self
+            .docker
+            .list_containers(Some(list_options))
+            .await
+            .map_err(|e| e.to_string())?;
+
+        Ok(containers
+            .into_iter()
+            .filter_map(|container| {
+                let id = container.id?;
+                let size = ContainerSize {
+                    rw: container.size_rw.unwrap_or(0).max(0) as u64,
+                    root_fs: container.size_root_fs.unwrap_or(0).max(0) as u64,
+                };
+                Some((short_id(&id).to_string(), size))
+            })
+            .collect())
+    }
+
+    /// Monitors Docker events for container start/stop/die events. Returns true
+    /// when a refresh was requested, false once the event stream ends.
     async fn monitor_docker_events(
         &self,
         tx: &EventSender,
         active_containers: &mut HashMap<String, tokio::task::JoinHandle<()>>,
-    ) {
+    ) -> bool {
         // Start with base filters (type and event are always needed)
         let mut filters = HashMap::new();
         filters.insert("type".to_string(), vec!["container".to_string()]);
@@ -159,6 +327,9 @@ impl DockerHost {
                 "die".to_string(),
                 "stop".to_string(),
                 "destroy".to_string(),
+                "pause".to_string(),
+                "unpause".to_string(),
+                "restart".to_string(),
                 "health_status".to_string(),
             ],
         );
@@ -197,15 +368,28 @@ impl DockerHost {
 
         let mut events_stream = self.docker.events(Some(events_options));
 
-        while let Some(event_result) = events_stream.next().await {
+        loop {
+            let event_result = tokio::select! {
+                _ = self.refresh.notified() => return true,
+                event_result = events_stream.next() => match event_result {
+                    Some(event_result) => event_result,
+                    None => return false,
+                },
+            };
             match event_result {
                 Ok(event) => {
                     if let Some(actor) = event.actor {
                         let container_id = actor.id.clone().unwrap_or_default();
-                        if container_id.is_empty() {
+                        let action = event.action.unwrap_or_default();
+                        if !is_valid_container_id(&container_id) {
+                            tracing::debug!(
+                                "Ignoring '{}' event with invalid container ID {:?} (host: {})",
+                                action,
+                                container_id,
+                                self.host_id
+                            );
                             continue;
                         }
-                        let action = event.action.unwrap_or_default();
 
                         match action.as_str() {
                             "start" => {
@@ -220,6 +404,15 @@ impl DockerHost {
                                 self.handle_container_destroy(&container_id, tx, active_containers)
                                     .await;
                             }
+                            "pause" | "unpause" | "restart" => {
+                                self.handle_container_lifecycle(
+                                    &container_id,
+                                    &action,
+                                    tx,
+                                    active_containers,
+                                )
+                                .await;
+                            }
                             a if a.starts_with("health_status") => {
                                 self.handle_health_status_change(&container_id, a, &actor, tx)
                                     .await;
@@ -236,13 +429,19 @@ impl DockerHost {
         }
     }
 
-    /// Starts monitoring a container by spawning a stats stream task
+    /// Starts monitoring a container by spawning a stats stream task. Polled hosts
+    /// have a single [`poll_host_stats`] task instead, which finds new containers
+    /// by itself.
     fn start_container_monitoring(
         &self,
         truncated_id: &str,
         tx: &EventSender,
         active_containers: &mut HashMap<String, tokio::task::JoinHandle<()>>,
     ) {
+        if self.stats_mode == Some(StatsMode::Poll) {
+            return;
+        }
+
         let tx_clone = tx.clone();
         let host_clone = self.clone();
         let truncated_id_clone = truncated_id.to_string();
@@ -251,7 +450,44 @@ impl DockerHost {
             stream_container_stats(host_clone, truncated_id_clone, tx_clone).await;
         });
 
-        active_containers.insert(truncated_id.to_string(), handle);
+        // Replacing an existing stream (e.g. on unpause) must not leak the old task
+        if let Some(old) = active_containers.insert(truncated_id.to_string(), handle) {
+            old.abort();
+        }
+    }
+
+    /// Handles pause/unpause/restart events, which change a container's state
+    /// without creating or removing it
+    async fn handle_container_lifecycle(
+        &self,
+        container_id: &str,
+        action: &str,
+        tx: &EventSender,
+        active_containers: &mut HashMap<String, tokio::task::JoinHandle<()>>,
+    ) {
+        let Some(state) = state_for_lifecycle_action(action) else {
+            return;
+        };
+        let truncated_id = short_id(container_id).to_string();
+
+        // Send the new state first so stats from a resumed stream never land on a
+        // container the UI still considers paused
+        let key = ContainerKey::new(self.host_id.clone(), truncated_id.clone());
+        let _ = tx.send(AppEvent::ContainerStateChanged(key, state)).await;
+
+        match action {
+            // A paused container's stats stream stalls, so stop it instead of leaving
+            // the last sample frozen on screen. The entry stays in `active_containers`
+            // so a later die/stop/destroy is handled like for any monitored container.
+            "pause" => {
+                if let Some(handle) = active_containers.get(&truncated_id) {
+                    handle.abort();
+                }
+            }
+            // Resume stats; this also covers containers that were paused at startup
+            "unpause" => self.start_container_monitoring(&truncated_id, tx, active_containers),
+            _ => {}
+        }
     }
 
     /// Handles a container start event
@@ -289,14 +525,17 @@ impl DockerHost {
                 .and_then(|h| h.status.as_ref())
                 .and_then(|status| format!("{:?}", status).parse().ok());
 
-            // Parse created timestamp from RFC3339 string
-            let created = inspect.created.as_ref().and_then(|created_str| {
-                DateTime::parse_from_rfc3339(created_str)
-                    .ok()
-                    .map(|dt| dt.with_timezone(&Utc))
-            });
+            let created = created_time(&inspect);
 
             let restart_count = inspect.restart_count;
+            let health_check = health_check_command(&inspect);
+            let ports = published_ports(&inspect);
+
+            let image = inspect
+                .config
+                .as_ref()
+                .and_then(|config| config.image.clone())
+                .unwrap_or_default();
 
             let compose_project = inspect
                 .config
@@ -308,7 +547,12 @@ impl DockerHost {
                 // New container or restarted container — create/update and start monitoring
                 let container = Container {
                     id: truncated_id.clone(),
+                    full_id: inspect
+                        .id
+                        .clone()
+                        .unwrap_or_else(|| container_id.to_string()),
                     name: name.clone(),
+                    image,
                     state,
                     health,
                     created,
@@ -317,6 +561,11 @@ impl DockerHost {
                     dozzle_url: self.dozzle_url.clone(),
                     restart_count,
                     compose_project,
+                    size: None,
+                    health_check,
+                    last_stats: None,
+                    exit_code: None,
+                    ports,
                 };
 
                 let _ = tx.send(AppEvent::ContainerCreated(container)).await;
@@ -325,7 +574,15 @@ impl DockerHost {
             } else {
                 // Container already monitored (e.g., "start" event without preceding "die")
                 // — just update the state to Running
-                let _ = tx.send(AppEvent::ContainerStateChanged(key, state)).await;
+                let _ = tx
+                    .send(AppEvent::ContainerStateChanged(key.clone(), state))
+                    .await;
+
+                // A restarted container's health check starts over; refresh it so the
+                // row reads "starting" until the service is actually ready again
+                if let Some(health) = health {
+                    let _ = tx.send(AppEvent::ContainerHealthChanged(key, health)).await;
+                }
             }
         }
     }
@@ -343,11 +600,32 @@ impl DockerHost {
         if let Some(handle) = active_containers.remove(&truncated_id) {
             handle.abort();
 
+            // A container with a restart policy reports "restarting" right after
+            // it dies; show that instead of Exited so the transition is visible
+            let inspect_state = self
+                .docker
+                .inspect_container(container_id, None::<InspectContainerOptions>)
+                .await
+                .ok()
+                .and_then(|inspect| inspect.state);
+            let restarting = inspect_state
+                .as_ref()
+                .and_then(|s| s.restarting)
+                .unwrap_or(false);
+            let state = if restarting {
+                ContainerState::Restarting
+            } else {
+                ContainerState::Exited
+            };
+
             // Send state change event instead of destroying the container
             let key = ContainerKey::new(self.host_id.clone(), truncated_id);
-            let _ = tx
-                .send(AppEvent::ContainerStateChanged(key, ContainerState::Exited))
-                .await;
+            if let Some(code) = inspect_state.and_then(|s| s.exit_code) {
+                let _ = tx
+                    .send(AppEvent::ContainerExitCode(key.clone(), code))
+                    .await;
+            }
+            let _ = tx.send(AppEvent::ContainerStateChanged(key, state)).await;
         }
     }
 
@@ -464,6 +742,20 @@ impl DockerHost {
             .map_err(|e| format!("Failed to restart container: {}", e))
     }
 
+    /// Renames a container
+    pub async fn rename_container(&self, container_id: &str, name: &str) -> Result<(), String> {
+        use bollard::query_parameters::RenameContainerOptions;
+
+        let options = RenameContainerOptions {
+            name: name.to_string(),
+        };
+
+        self.docker
+            .rename_container(container_id, options)
+            .await
+            .map_err(|e| format!("Failed to rename container: {}", e))
+    }
+
     /// Removes a container (with force option if needed)
     pub async fn remove_container(&self, container_id: &str) -> Result<(), String> {
         use bollard::query_parameters::RemoveContainerOptions;
@@ -480,33 +772,414 @@ impl DockerHost {
             .map_err(|e| format!("Failed to remove container: {}", e))
     }
 
+    /// Fetches a `docker system df`-style disk usage summary for this host
+    pub async fn fetch_disk_usage(&self) -> Result<DiskUsageSummary, String> {
+        use bollard::query_parameters::DataUsageOptions;
+
+        let usage = self
+            .docker
+            .df(None::<DataUsageOptions>)
+            .await
+            .map_err(|e| format!("Failed to fetch disk usage: {}", e))?;
+
+        let images = usage.image_usage.map(|u| {
+            disk_usage_category(u.total_count, u.active_count, u.total_size, u.reclaimable)
+        });
+        let containers = usage.container_usage.map(|u| {
+            disk_usage_category(u.total_count, u.active_count, u.total_size, u.reclaimable)
+        });
+        let volumes = usage.volume_usage.map(|u| {
+            disk_usage_category(u.total_count, u.active_count, u.total_size, u.reclaimable)
+        });
+        let build_cache = usage.build_cache_usage.map(|u| {
+            disk_usage_category(u.total_count, u.active_count, u.total_size, u.reclaimable)
+        });
+
+        Ok(DiskUsageSummary {
+            images: images.unwrap_or_default(),
+            containers: containers.unwrap_or_default(),
+            volumes: volumes.unwrap_or_default(),
+            build_cache: build_cache.unwrap_or_default(),
+        })
+    }
+
+    /// Runs a command non-interactively inside a container (`sh -c <command>`)
+    /// and returns its combined stdout/stderr, truncated to
+    /// [`MAX_COMMAND_OUTPUT`] bytes. A non-zero exit code is appended to the output.
+    pub async fn run_command(&self, container_id: &str, command: &str) -> Result<String, String> {
+        use bollard::exec::{CreateExecOptions, StartExecResults};
+
+        let exec_config = CreateExecOptions {
+            cmd: Some(vec!["sh", "-c", command]),
+            attach_stdout: Some(true),
+            attach_stderr: Some(true),
+            ..Default::default()
+        };
+
+        let exec = self
+            .docker
+            .create_exec(container_id, exec_config)
+            .await
+            .map_err(|e| format!("Failed to create exec: {}", e))?;
+
+        let mut output = match self.docker.start_exec(&exec.id, None).await {
+            Ok(StartExecResults::Attached { output, .. }) => output,
+            Ok(StartExecResults::Detached) => {
+                return Err("Exec started in detached mode unexpectedly".to_string());
+            }
+            Err(e) => return Err(format!("Failed to start exec: {}", e)),
+        };
+
+        let mut bytes = Vec::new();
+        while let Some(chunk) = output.next().await {
+            let chunk = chunk.map_err(|e| format!("Failed to read command output: {}", e))?;
+            if bytes.len() < MAX_COMMAND_OUTPUT {
+                bytes.extend_from_slice(&chunk.into_bytes());
+            }
+        }
+        bytes.truncate(MAX_COMMAND_OUTPUT);
+        let mut text = String::from_utf8_lossy(&bytes).into_owned();
+
+        let exit_code = self
+            .docker
+            .inspect_exec(&exec.id)
+            .await
+            .ok()
+            .and_then(|inspect| inspect.exit_code);
+        if let Some(code) = exit_code.filter(|code| *code != 0) {
+            if !text.is_empty() && !text.ends_with('\n') {
+                text.push('\n');
+            }
+            text.push_str(&format!("[exit code {}]", code));
+        }
+
+        Ok(text)
+    }
+
+    /// Inspects a container and formats the parts worth reading at a glance
+    /// (image, command, ports, env, mounts, labels) for the inspect view
+    pub async fn inspect_summary(&self, container_id: &str) -> Result<String, String> {
+        self.docker
+            .inspect_container(container_id, None::<InspectContainerOptions>)
+            .await
+            .map(|inspect| format_inspect_summary(&inspect))
+            .map_err(|e| format!("Failed to inspect container: {}", e))
+    }
+
+    /// Creation time of a container, from inspect; None if it can't be inspected
+    /// or Docker has none for it
+    pub async fn created_time(&self, container_id: &str) -> Option<DateTime<Utc>> {
+        self.docker
+            .inspect_container(container_id, None::<InspectContainerOptions>)
+            .await
+            .ok()
+            .as_ref()
+            .and_then(created_time)
+    }
+
     /// Runs an interactive shell session inside a container
     /// This function takes over the terminal completely until the shell exits
     pub async fn run_shell_session(
         &self,
         container_id: &str,
+        transcript_dir: Option<&std::path::Path>,
+    ) -> Result<Option<std::path::PathBuf>, Box<dyn std::error::Error + Send + Sync>> {
+        crate::docker::shell::run_shell_session(self, container_id, transcript_dir).await
+    }
+
+    /// Pipes a container's logs into `$PAGER` (default `less`)
+    /// This function takes over the terminal completely until the pager exits
+    pub async fn run_pager_session(
+        &self,
+        container_id: &str,
     ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
-        crate::docker::shell::run_shell_session(self, container_id).await
+        crate::docker::pager::run_pager_session(self, container_id).await
+    }
+}
+
+/// Maps a Docker lifecycle event action that doesn't create or remove a
+/// container to the state the container is in after it.
+fn state_for_lifecycle_action(action: &str) -> Option<ContainerState> {
+    match action {
+        "pause" => Some(ContainerState::Paused),
+        // "restart" fires once the container is running again
+        "unpause" | "restart" => Some(ContainerState::Running),
+        _ => None,
+    }
+}
+
+/// Extracts the health-check command from an inspect response.
+fn health_check_command(inspect: &bollard::models::ContainerInspectResponse) -> Option<String> {
+    let test = inspect
+        .config
+        .as_ref()?
+        .healthcheck
+        .as_ref()?
+        .test
+        .as_ref()?;
+    format_health_check(test)
+}
+
+/// Parses an inspect response's RFC3339 creation timestamp
+fn created_time(inspect: &bollard::models::ContainerInspectResponse) -> Option<DateTime<Utc>> {
+    inspect.created.as_ref().and_then(|created_str| {
+        DateTime::parse_from_rfc3339(created_str)
+            .ok()
+            .map(|dt| dt.with_timezone(&Utc))
+    })
+}
+
+/// Ports a running container publishes on the host, sorted by host port. Docker
+/// reports a binding per address family, so the IPv4 and IPv6 copies are merged.
+fn published_ports(inspect: &bollard::models::ContainerInspectResponse) -> Vec<PublishedPort> {
+    let Some(port_map) = inspect
+        .network_settings
+        .as_ref()
+        .and_then(|n| n.ports.as_ref())
+    else {
+        return Vec::new();
+    };
+
+    let mut ports: Vec<PublishedPort> = port_map
+        .iter()
+        .filter_map(|(port, bindings)| {
+            let (container_port, protocol) = port.split_once('/').unwrap_or((port, "tcp"));
+            let container_port = container_port.parse().ok()?;
+            Some(bindings.iter().flatten().filter_map(move |binding| {
+                Some(PublishedPort {
+                    host_port: binding.host_port.as_deref()?.parse().ok()?,
+                    container_port,
+                    protocol: protocol.to_string(),
+                })
+            }))
+        })
+        .flatten()
+        .collect();
+    ports.sort();
+    ports.dedup();
+    ports
+}
+
+/// Formats a `Healthcheck.Test` array the way it was written in the Dockerfile.
+/// Returns `None` for `["NONE"]` (health check disabled) or an empty test.
+fn format_health_check(test: &[String]) -> Option<String> {
+    let (kind, args) = test.split_first()?;
+    let command = match kind.as_str() {
+        "CMD-SHELL" | "CMD" => args.join(" "),
+        _ => return None,
+    };
+    (!command.is_empty()).then_some(command)
+}
+
+/// Formats an inspect response as a plain-text summary: image and command,
+/// then one section each for ports, environment, mounts and labels (sorted,
+/// so the output is stable). Empty sections are left out.
+fn format_inspect_summary(inspect: &bollard::models::ContainerInspectResponse) -> String {
+    let config = inspect.config.as_ref();
+    let mut out = String::new();
+
+    let image = config.and_then(|c| c.image.as_deref()).unwrap_or("-");
+    out.push_str(&format!("Image:    {}\n", image));
+    let command: Vec<&str> = config
+        .into_iter()
+        .flat_map(|c| c.entrypoint.iter().chain(c.cmd.iter()).flatten())
+        .map(String::as_str)
+        .collect();
+    out.push_str(&format!("Command:  {}\n", command.join(" ")));
+
+    let mut ports: Vec<String> = inspect
+        .network_settings
+        .as_ref()
+        .and_then(|n| n.ports.as_ref())
+        .into_iter()
+        .flatten()
+        .map(|(port, bindings)| {
+            let published: Vec<String> = bindings
+                .iter()
+                .flatten()
+                .map(|b| {
+                    format!(
+                        "{}:{}",
+                        b.host_ip.as_deref().unwrap_or(""),
+                        b.host_port.as_deref().unwrap_or("")
+                    )
+                })
+                .collect();
+            if published.is_empty() {
+                port.clone()
+            } else {
+                format!("{} -> {}", port, published.join(", "))
+            }
+        })
+        .collect();
+    ports.sort();
+
+    let env: Vec<String> = config.and_then(|c| c.env.clone()).unwrap_or_default();
+
+    let mounts: Vec<String> = inspect
+        .mounts
+        .iter()
+        .flatten()
+        .map(|m| {
+            format!(
+                "{} -> {} ({})",
+                m.source.as_deref().or(m.name.as_deref()).unwrap_or("-"),
+                m.destination.as_deref().unwrap_or("-"),
+                if m.rw.unwrap_or(true) { "rw" } else { "ro" }
+            )
+        })
+        .collect();
+
+    let mut labels: Vec<String> = config
+        .and_then(|c| c.labels.as_ref())
+        .into_iter()
+        .flatten()
+        .map(|(k, v)| format!("{}={}", k, v))
+        .collect();
+    labels.sort();
+
+    for (title, items) in [
+        ("Ports", ports),
+        ("Environment", env),
+        ("Mounts", mounts),
+        ("Labels", labels),
+    ] {
+        if items.is_empty() {
+            continue;
+        }
+        out.push_str(&format!("\n{}\n", title));
+        for item in items {
+            out.push_str(&format!("  {}\n", item));
+        }
+    }
+
+    out
+}
+
+/// Builds a [`DiskUsageCategory`] from the optional counters returned by the
+/// `/system/df` endpoint. Missing or negative values are treated as zero.
+fn disk_usage_category(
+    total_count: Option<i64>,
+    active_count: Option<i64>,
+    total_size: Option<i64>,
+    reclaimable: Option<i64>,
+) -> DiskUsageCategory {
+    let to_u64 = |v: Option<i64>| v.unwrap_or(0).max(0) as u64;
+    DiskUsageCategory {
+        total_count: to_u64(total_count),
+        active_count: to_u64(active_count),
+        total_size: to_u64(total_size),
+        reclaimable: to_u64(reclaimable),
     }
 }
 
 /// Manages container monitoring for a specific Docker host: fetches initial containers and listens for Docker events
-pub async fn container_manager(host: DockerHost, tx: EventSender) {
+pub async fn container_manager(mut host: DockerHost, tx: EventSender) {
     let mut active_containers: HashMap<String, tokio::task::JoinHandle<()>> = HashMap::new();
 
+    // Stats streams send their samples through one batcher per host
+    let (stats_tx, batch_task) = spawn_stats_batcher(host.host_id.clone(), tx.clone());
+    host.stats_tx = Some(stats_tx);
+
     // Fetch and start monitoring initial containers
     host.fetch_initial_containers(&tx, &mut active_containers)
         .await;
 
-    // Subscribe to Docker events and handle container lifecycle
-    host.monitor_docker_events(&tx, &mut active_containers)
+    // Polled hosts sample all their running containers from one task
+    let poll_task = (host.stats_mode == Some(StatsMode::Poll)).then(|| {
+        let host = host.clone();
+        let tx = tx.clone();
+        tokio::spawn(async move { poll_host_stats(host, tx).await })
+    });
+
+    // Container sizes are expensive to compute, so they are only collected when
+    // enabled and on a much slower cadence than stats
+    let size_task = host.container_size.then(|| {
+        let host = host.clone();
+        let tx = tx.clone();
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(CONTAINER_SIZE_INTERVAL);
+            loop {
+                interval.tick().await;
+                match host.fetch_container_sizes().await {
+                    Ok(sizes) => {
+                        let _ = tx
+                            .send(AppEvent::ContainerSizes(host.host_id.clone(), sizes))
+                            .await;
+                    }
+                    Err(e) => tracing::warn!(
+                        "Failed to fetch container sizes for {}: {}",
+                        host.host_id,
+                        e
+                    ),
+                }
+            }
+        })
+    });
+
+    // Subscribe to Docker events and handle container lifecycle. A refresh ('r')
+    // lists the containers again from scratch, in case an event was missed.
+    while host
+        .monitor_docker_events(&tx, &mut active_containers)
+        .await
+    {
+        tracing::debug!("Refreshing containers for host: {}", host.host_id);
+        for (_, handle) in active_containers.drain() {
+            handle.abort();
+        }
+        host.fetch_initial_containers(&tx, &mut active_containers)
+            .await;
+    }
+
+    // The event stream only ends when the connection to the daemon is lost
+    tracing::warn!("Docker event stream ended for host: {}", host.host_id);
+    for (_, handle) in active_containers.drain() {
+        handle.abort();
+    }
+    for handle in [Some(batch_task), poll_task, size_task]
+        .into_iter()
+        .flatten()
+    {
+        handle.abort();
+    }
+    let _ = tx
+        .send(AppEvent::ConnectionError(
+            host.host_id.clone(),
+            "Lost connection to Docker daemon".to_string(),
+        ))
         .await;
 }
 
+/// Certificate files for a `tls://` host, from its `cert_path`, `key_path` and
+/// `ca_path` config. Unset files are looked up in `DOCKER_CERT_PATH` (or
+/// `~/.docker`) under the Docker CLI's names.
+#[derive(Debug, Clone, Default)]
+pub struct TlsFiles {
+    pub cert: Option<PathBuf>,
+    pub key: Option<PathBuf>,
+    pub ca: Option<PathBuf>,
+}
+
+impl TlsFiles {
+    /// Returns the (key, cert, ca) paths, falling back to `key.pem`, `cert.pem`
+    /// and `ca.pem` in `cert_dir`
+    fn resolve(&self, cert_dir: &Path) -> (PathBuf, PathBuf, PathBuf) {
+        let file = |path: &Option<PathBuf>, name: &str| {
+            path.clone().unwrap_or_else(|| cert_dir.join(name))
+        };
+        (
+            file(&self.key, "key.pem"),
+            file(&self.cert, "cert.pem"),
+            file(&self.ca, "ca.pem"),
+        )
+    }
+}
+
 /// Connects to Docker based on the host string
 ///
 /// # Arguments
 /// * `host` - Host specification string (e.g., "local", "unix:///path/to/docker.sock", "ssh://user@host", "tcp://host:port", "tls://host:port")
+/// * `tls` - Certificate files for `tls://` hosts (ignored for other hosts)
 ///
 /// The `"local"` host follows the Docker CLI's endpoint resolution: it honors the
 /// `DOCKER_HOST` and `DOCKER_CONTEXT` environment variables and the active context
@@ -518,12 +1191,12 @@ pub async fn container_manager(host: DockerHost, tx: EventSender) {
 ///
 /// # Examples
 /// ```ignore
-/// let docker = connect_docker("local")?;
-/// let docker = connect_docker("ssh://user@host")?;
-/// let docker = connect_docker("tcp://host:2375")?;
-/// let docker = connect_docker("tls://host:2376")?;
+/// let docker = connect_docker("local", &TlsFiles::default())?;
+/// let docker = connect_docker("ssh://user@host", &TlsFiles::default())?;
+/// let docker = connect_docker("tcp://host:2375", &TlsFiles::default())?;
+/// let docker = connect_docker("tls://host:2376", &TlsFiles::default())?;
 /// ```
-pub fn connect_docker(host: &str) -> Result<Docker, Box<dyn std::error::Error>> {
+pub fn connect_docker(host: &str, tls: &TlsFiles) -> Result<Docker, Box<dyn std::error::Error>> {
     use tracing::{debug, error};
 
     if host == "local" {
@@ -535,7 +1208,7 @@ pub fn connect_docker(host: &str) -> Result<Docker, Box<dyn std::error::Error>>
             && endpoint != "local"
         {
             debug!("Resolved local Docker endpoint to: {}", endpoint);
-            return connect_docker(&endpoint);
+            return connect_docker(&endpoint, tls);
         }
 
         debug!("Connecting to local Docker daemon using default socket");
@@ -572,15 +1245,11 @@ pub fn connect_docker(host: &str) -> Result<Docker, Box<dyn std::error::Error>>
             e.into()
         })
     } else if host.starts_with("tls://") {
-        // Connect via TLS using environment variables for certificates
-        // Expects DOCKER_CERT_PATH to be set with key.pem, cert.pem, and ca.pem files
-        let cert_path = std::env::var("DOCKER_CERT_PATH")
+        // Connect via TLS with the host's certificate files. Any not configured
+        // come from DOCKER_CERT_PATH (key.pem, cert.pem and ca.pem)
+        let cert_dir = std::env::var("DOCKER_CERT_PATH")
             .unwrap_or_else(|_| format!("{}/.docker", std::env::var("HOME").unwrap_or_default()));
-
-        let cert_dir = std::path::Path::new(&cert_path);
-        let key_path = cert_dir.join("key.pem");
-        let cert_path = cert_dir.join("cert.pem");
-        let ca_path = cert_dir.join("ca.pem");
+        let (key_path, cert_path, ca_path) = tls.resolve(Path::new(&cert_dir));
 
         // Convert tls:// to tcp:// for Bollard
         let tcp_host = host.replace("tls://", "tcp://");
@@ -608,3 +1277,198 @@ pub fn connect_docker(host: &str) -> Result<Docker, Box<dyn std::error::Error>>
         .into())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_published_ports_merges_address_families() {
+        use bollard::models::{NetworkSettings, PortBinding};
+
+        let binding = |host_ip: &str, host_port: &str| PortBinding {
+            host_ip: Some(host_ip.to_string()),
+            host_port: Some(host_port.to_string()),
+        };
+        let inspect = bollard::models::ContainerInspectResponse {
+            network_settings: Some(NetworkSettings {
+                ports: Some(HashMap::from([
+                    (
+                        "80/tcp".to_string(),
+                        Some(vec![binding("0.0.0.0", "8080"), binding("::", "8080")]),
+                    ),
+                    ("53/udp".to_string(), Some(vec![binding("0.0.0.0", "5353")])),
+                    ("443/tcp".to_string(), None),
+                ])),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        let port = |host_port, container_port, protocol: &str| PublishedPort {
+            host_port,
+            container_port,
+            protocol: protocol.to_string(),
+        };
+        assert_eq!(
+            published_ports(&inspect),
+            vec![port(5353, 53, "udp"), port(8080, 80, "tcp")]
+        );
+    }
+
+    #[test]
+    fn test_format_inspect_summary() {
+        use bollard::models::{ContainerConfig, MountPoint, NetworkSettings, PortBinding};
+
+        let inspect = bollard::models::ContainerInspectResponse {
+            config: Some(ContainerConfig {
+                image: Some("nginx:1.27".to_string()),
+                entrypoint: Some(vec!["/docker-entrypoint.sh".to_string()]),
+                cmd: Some(vec!["nginx".to_string(), "-g".to_string()]),
+                env: Some(vec!["PATH=/usr/bin".to_string()]),
+                labels: Some(HashMap::from([
+                    ("b".to_string(), "2".to_string()),
+                    ("a".to_string(), "1".to_string()),
+                ])),
+                ..Default::default()
+            }),
+            network_settings: Some(NetworkSettings {
+                ports: Some(HashMap::from([
+                    (
+                        "80/tcp".to_string(),
+                        Some(vec![PortBinding {
+                            host_ip: Some("0.0.0.0".to_string()),
+                            host_port: Some("8080".to_string()),
+                        }]),
+                    ),
+                    ("443/tcp".to_string(), None),
+                ])),
+                ..Default::default()
+            }),
+            mounts: Some(vec![MountPoint {
+                source: Some("/srv/html".to_string()),
+                destination: Some("/usr/share/nginx/html".to_string()),
+                rw: Some(false),
+                ..Default::default()
+            }]),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            format_inspect_summary(&inspect),
+            "Image:    nginx:1.27\n\
+             Command:  /docker-entrypoint.sh nginx -g\n\
+             \n\
+             Ports\n  443/tcp\n  80/tcp -> 0.0.0.0:8080\n\
+             \n\
+             Environment\n  PATH=/usr/bin\n\
+             \n\
+             Mounts\n  /srv/html -> /usr/share/nginx/html (ro)\n\
+             \n\
+             Labels\n  a=1\n  b=2\n"
+        );
+
+        // Sections without entries are left out
+        let empty = format_inspect_summary(&Default::default());
+        assert_eq!(empty, "Image:    -\nCommand:  \n");
+    }
+
+    #[test]
+    fn test_format_health_check() {
+        let test = |parts: &[&str]| {
+            format_health_check(&parts.iter().map(|s| s.to_string()).collect::<Vec<_>>())
+        };
+        assert_eq!(
+            test(&["CMD-SHELL", "curl -f http://localhost/ || exit 1"]),
+            Some("curl -f http://localhost/ || exit 1".to_string())
+        );
+        assert_eq!(
+            test(&["CMD", "pg_isready", "-U", "postgres"]),
+            Some("pg_isready -U postgres".to_string())
+        );
+        assert_eq!(test(&["NONE"]), None);
+        assert_eq!(test(&["CMD"]), None);
+        assert_eq!(test(&[]), None);
+    }
+
+    #[test]
+    fn test_resolve_stats_mode() {
+        let docker =
+            Docker::connect_with_http("tcp://localhost:2375", 4, API_DEFAULT_VERSION).unwrap();
+        let mut host = DockerHost::new("user@server".to_string(), docker, None, HashMap::new());
+
+        let mut local = host.clone();
+        local.resolve_stats_mode(500);
+        assert_eq!(local.stats_mode, Some(StatsMode::Stream));
+
+        host.is_ssh = true;
+        let mut small = host.clone();
+        small.resolve_stats_mode(SSH_MAX_STATS_STREAMS);
+        assert_eq!(small.stats_mode, Some(StatsMode::Stream));
+
+        let mut large = host.clone();
+        large.resolve_stats_mode(SSH_MAX_STATS_STREAMS + 1);
+        assert_eq!(large.stats_mode, Some(StatsMode::Poll));
+
+        // An explicit setting always wins
+        host.stats_mode = Some(StatsMode::Stream);
+        host.resolve_stats_mode(500);
+        assert_eq!(host.stats_mode, Some(StatsMode::Stream));
+    }
+
+    #[test]
+    fn test_tls_files_fall_back_to_cert_dir() {
+        let dir = Path::new("/certs");
+        assert_eq!(
+            TlsFiles::default().resolve(dir),
+            (
+                dir.join("key.pem"),
+                dir.join("cert.pem"),
+                dir.join("ca.pem")
+            )
+        );
+
+        // Configured files win, one at a time
+        let tls = TlsFiles {
+            cert: Some(PathBuf::from("/prod/client.crt")),
+            ca: Some(PathBuf::from("/prod/ca.crt")),
+            ..Default::default()
+        };
+        assert_eq!(
+            tls.resolve(dir),
+            (
+                dir.join("key.pem"),
+                PathBuf::from("/prod/client.crt"),
+                PathBuf::from("/prod/ca.crt")
+            )
+        );
+    }
+
+    #[test]
+    fn test_is_valid_container_id() {
+        assert!(is_valid_container_id("abc123def456"));
+        assert!(is_valid_container_id(
+            "0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef"
+        ));
+        assert!(!is_valid_container_id(""));
+        assert!(!is_valid_container_id("not-a-container"));
+    }
+
+    #[test]
+    fn test_state_for_lifecycle_action() {
+        assert_eq!(
+            state_for_lifecycle_action("pause"),
+            Some(ContainerState::Paused)
+        );
+        assert_eq!(
+            state_for_lifecycle_action("unpause"),
+            Some(ContainerState::Running)
+        );
+        assert_eq!(
+            state_for_lifecycle_action("restart"),
+            Some(ContainerState::Running)
+        );
+        assert_eq!(state_for_lifecycle_action("start"), None);
+        assert_eq!(state_for_lifecycle_action("exec_start: sh"), None);
+    }
+}
@@ -1,10 +1,11 @@
 use ansi_to_tui::IntoText;
+use bollard::Docker;
 use bollard::query_parameters::LogsOptions;
 use chrono::{DateTime, Utc};
 use futures_util::stream::StreamExt;
 use ratatui::text::{Line, Text};
 
-use crate::core::types::{AppEvent, ContainerKey, EventSender};
+use crate::core::types::{AppEvent, ContainerKey, DisplayTimezone, EventSender};
 use crate::docker::connection::DockerHost;
 use crate::docker::json_formatter;
 
@@ -14,22 +15,44 @@ pub struct LogEntry {
     pub timestamp: DateTime<Utc>,
     /// Parsed ANSI text ready for rendering
     pub text: Text<'static>,
+    /// Extra attributes attached by the logging driver (only for hosts with
+    /// `log_details` on)
+    pub details: Option<String>,
 }
 
 impl LogEntry {
-    /// Format this log entry into a styled Line with timestamp and ANSI-parsed content.
-    /// The result is suitable for rendering in a ratatui Paragraph.
-    pub fn format(&self) -> Line<'static> {
-        use chrono::Local;
+    /// Whether the line's text contains `query`, ignoring case
+    pub fn matches_filter(&self, query: &str) -> bool {
+        let query = query.to_lowercase();
+        self.text
+            .lines
+            .iter()
+            .any(|line| line.to_string().to_lowercase().contains(&query))
+    }
+
+    /// Format this log entry into a styled Line with (optionally) the timestamp, in
+    /// `timezone`, and ANSI-parsed content. The result is suitable for rendering in a
+    /// ratatui Paragraph.
+    pub fn format(&self, show_timestamp: bool, timezone: DisplayTimezone) -> Line<'static> {
         use ratatui::style::{Color, Modifier, Style};
         use ratatui::text::Span;
 
         const TIMESTAMP_STYLE: Style = Style::new().fg(Color::Yellow).add_modifier(Modifier::BOLD);
 
-        let local_timestamp = self.timestamp.with_timezone(&Local);
-        let timestamp_str = local_timestamp.format("%Y-%m-%d %H:%M:%S").to_string();
+        const DETAILS_STYLE: Style = Style::new().fg(Color::DarkGray);
 
-        let mut line_spans = vec![Span::styled(timestamp_str, TIMESTAMP_STYLE), Span::raw(" ")];
+        let mut line_spans = Vec::new();
+
+        if show_timestamp {
+            let timestamp_str = timezone.format(&self.timestamp, "%Y-%m-%d %H:%M:%S");
+            line_spans.push(Span::styled(timestamp_str, TIMESTAMP_STYLE));
+            line_spans.push(Span::raw(" "));
+        }
+
+        if let Some(details) = &self.details {
+            line_spans.push(Span::styled(details.clone(), DETAILS_STYLE));
+            line_spans.push(Span::raw(" "));
+        }
 
         if let Some(text_line) = self.text.lines.first() {
             line_spans.extend(text_line.spans.iter().cloned());
@@ -38,18 +61,35 @@ impl LogEntry {
         Line::from(line_spans)
     }
 
-    /// Parse a Docker log line with RFC3339 timestamp
+    /// Parse a Docker log line with RFC3339 timestamp, requested without details
     /// Format: "2025-10-28T12:34:56.789Z message content"
+    #[cfg(test)]
     pub fn parse(log_line: &str) -> Option<Self> {
+        Self::parse_line(log_line, false)
+    }
+
+    /// Parse a Docker log line, optionally requested with `details: true`
+    /// Format: "2025-10-28T12:34:56.789Z key=value,key2=value2 message content"
+    /// (the attribute list is empty for lines without attributes)
+    pub fn parse_line(log_line: &str, with_details: bool) -> Option<Self> {
         // Find the first space which separates timestamp from message
         let space_idx = log_line.find(' ')?;
-        let (timestamp_str, message) = log_line.split_at(space_idx);
+        let (timestamp_str, mut message) = log_line.split_at(space_idx);
 
         // Parse the timestamp (Docker uses RFC3339 format)
         let timestamp = DateTime::parse_from_rfc3339(timestamp_str)
             .ok()?
             .with_timezone(&Utc);
 
+        let details = if with_details {
+            let rest = &message[1..];
+            let (attrs, line) = rest.split_at(rest.find(' ').unwrap_or(rest.len()));
+            message = line;
+            format_log_details(attrs)
+        } else {
+            None
+        };
+
         // Try to detect and format JSON
         let text = if let Ok(json_value) = serde_json::from_str::<serde_json::Value>(message.trim())
         {
@@ -63,10 +103,52 @@ impl LogEntry {
                 .unwrap_or_else(|_| Text::from(message.to_string()))
         };
 
-        Some(LogEntry { timestamp, text })
+        Some(LogEntry {
+            timestamp,
+            text,
+            details,
+        })
     }
 }
 
+/// Formats Docker's log attribute list ("key=value,key2=value2", each part
+/// query-escaped) for display, or None if the line has no attributes
+fn format_log_details(attrs: &str) -> Option<String> {
+    if attrs.is_empty() {
+        return None;
+    }
+
+    let pairs: Vec<String> = attrs
+        .split(',')
+        .flat_map(|pair| url::form_urlencoded::parse(pair.as_bytes()))
+        .map(|(key, value)| format!("{}={}", key, value))
+        .collect();
+
+    (!pairs.is_empty()).then(|| pairs.join(" "))
+}
+
+/// Returns the client to request `host`'s logs with. With `log_details` on, it adds
+/// Docker's `details=1` query parameter, which bollard's `LogsOptions` has no field for.
+fn logs_client(host: &DockerHost) -> Docker {
+    if !host.log_details {
+        return host.docker.clone();
+    }
+    host.docker.clone().with_request_modifier(|mut request| {
+        if request.uri().path().ends_with("/logs")
+            && let Ok(uri) = with_details_query(&request.uri().to_string()).parse()
+        {
+            *request.uri_mut() = uri;
+        }
+        request
+    })
+}
+
+/// Appends `details=1` to a request URI's query
+fn with_details_query(uri: &str) -> String {
+    let separator = if uri.contains('?') { '&' } else { '?' };
+    format!("{}{}details=1", uri, separator)
+}
+
 /// Fetches older logs for pagination using density-based adaptive algorithm
 ///
 /// This function calculates log density from the existing batch (time span between
@@ -152,7 +234,7 @@ pub async fn fetch_older_logs(
             ..Default::default()
         });
 
-        let mut log_stream = host.docker.logs(&container_id, options);
+        let mut log_stream = logs_client(&host).logs(&container_id, options);
         let mut batch_logs = Vec::new();
 
         // Collect logs within the time window
@@ -160,7 +242,7 @@ pub async fn fetch_older_logs(
             match log_result {
                 Ok(log_output) => {
                     let log_line = log_output.to_string().replace('\r', "");
-                    if let Some(log_entry) = LogEntry::parse(&log_line) {
+                    if let Some(log_entry) = LogEntry::parse_line(&log_line, host.log_details) {
                         batch_logs.push(log_entry);
                     }
                 }
@@ -220,7 +302,7 @@ pub async fn stream_container_logs(host: DockerHost, container_id: String, tx: E
         ..Default::default()
     });
 
-    let mut historical_stream = host.docker.logs(&container_id, historical_options);
+    let mut historical_stream = logs_client(&host).logs(&container_id, historical_options);
     let mut historical_logs = Vec::new();
     let mut last_timestamp: Option<DateTime<Utc>> = None;
 
@@ -229,7 +311,7 @@ pub async fn stream_container_logs(host: DockerHost, container_id: String, tx: E
         match log_result {
             Ok(log_output) => {
                 let log_line = log_output.to_string().replace('\r', "");
-                if let Some(log_entry) = LogEntry::parse(&log_line) {
+                if let Some(log_entry) = LogEntry::parse_line(&log_line, host.log_details) {
                     last_timestamp = Some(log_entry.timestamp);
                     historical_logs.push(log_entry);
                 }
@@ -257,27 +339,49 @@ pub async fn stream_container_logs(host: DockerHost, container_id: String, tx: E
     }
 
     // Phase 2: Start streaming new logs from after the last timestamp
+    follow_container_logs(host, container_id, last_timestamp, tx).await;
+}
+
+/// Follows a container's logs in real-time, starting after `since` (or from the
+/// beginning if None). The stream ends when the container stops; the log view
+/// calls this again when the container starts, to pick up where it left off.
+pub async fn follow_container_logs(
+    host: DockerHost,
+    container_id: String,
+    since: Option<DateTime<Utc>>,
+    tx: EventSender,
+) {
+    let key = ContainerKey::new(host.host_id.clone(), container_id.clone());
+
     let streaming_options = Some(LogsOptions {
         follow: true, // Stream logs in real-time
         stdout: true, // Include stdout
         stderr: true, // Include stderr
         timestamps: true,
         // NOTE: Bollard's LogsOptions uses i32 for timestamps (Y2038 limitation)
-        since: last_timestamp.map(|ts| ts.timestamp() as i32).unwrap_or(0), // Start after last historical log
+        since: since.map(|ts| ts.timestamp() as i32).unwrap_or(0),
         ..Default::default()
     });
 
-    let mut log_stream = host.docker.logs(&container_id, streaming_options);
+    let mut log_stream = logs_client(&host).logs(&container_id, streaming_options);
 
     while let Some(log_result) = log_stream.next().await {
         match log_result {
             Ok(log_output) => {
                 let log_line = log_output.to_string().replace('\r', "");
-                if let Some(log_entry) = LogEntry::parse(&log_line)
-                    && tx
-                        .send(AppEvent::LogLine(key.clone(), log_entry))
-                        .await
-                        .is_err()
+                let Some(log_entry) = LogEntry::parse_line(&log_line, host.log_details) else {
+                    continue;
+                };
+
+                // `since` only has second precision, so skip lines we already have
+                if since.is_some_and(|since| log_entry.timestamp <= since) {
+                    continue;
+                }
+
+                if tx
+                    .send(AppEvent::LogLine(key.clone(), log_entry))
+                    .await
+                    .is_err()
                 {
                     break; // Channel closed, stop streaming
                 }
@@ -308,6 +412,43 @@ mod tests {
         assert!(!entry.text.lines.is_empty());
     }
 
+    #[test]
+    fn test_parse_log_entry_with_details() {
+        let log_line = "2025-10-28T12:34:56.789Z env=prod,service=web+api Hello world";
+        let entry = LogEntry::parse_line(log_line, true).expect("Should parse line with details");
+
+        assert_eq!(entry.details.as_deref(), Some("env=prod service=web api"));
+        assert_eq!(entry.text.lines[0].spans[0].content, "Hello world");
+    }
+
+    #[test]
+    fn test_parse_log_entry_with_empty_details() {
+        // Lines without attributes still get the (empty) attribute field
+        let log_line = "2025-10-28T12:34:56.789Z  Hello world";
+        let entry = LogEntry::parse_line(log_line, true).expect("Should parse line with details");
+
+        assert_eq!(entry.details, None);
+        assert_eq!(entry.text.lines[0].spans[0].content, "Hello world");
+    }
+
+    #[test]
+    fn test_log_details_request_and_output() {
+        assert_eq!(
+            with_details_query("http://localhost/v1.47/containers/abc/logs?follow=true"),
+            "http://localhost/v1.47/containers/abc/logs?follow=true&details=1"
+        );
+        assert_eq!(
+            with_details_query("http://localhost/containers/abc/logs"),
+            "http://localhost/containers/abc/logs?details=1"
+        );
+
+        let log_line = "2025-10-28T12:34:56.789Z env=prod,service=web GET /health 200";
+        let entry = LogEntry::parse_line(log_line, true).expect("Should parse line with details");
+        let line = entry.format(false, DisplayTimezone::Utc).to_string();
+        assert!(line.contains("env=prod service=web"), "{}", line);
+        assert!(line.ends_with("GET /health 200"), "{}", line);
+    }
+
     #[test]
     fn test_parse_log_entry_invalid_timestamp() {
         let log_line = "invalid-timestamp Message";
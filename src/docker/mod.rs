@@ -3,5 +3,6 @@ pub mod connection;
 pub mod context;
 pub mod json_formatter;
 pub mod logs;
+pub mod pager;
 pub mod shell;
 pub mod stats;
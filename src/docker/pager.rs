@@ -0,0 +1,127 @@
+use bollard::query_parameters::LogsOptions;
+use crossterm::{
+    cursor, execute,
+    terminal::{self, Clear, ClearType, EnterAlternateScreen, LeaveAlternateScreen},
+};
+use futures_util::StreamExt;
+use std::io;
+use std::process::Stdio;
+use tokio::io::AsyncWriteExt as _;
+
+use crate::docker::connection::DockerHost;
+
+/// Pager used when `$PAGER` is unset or empty
+const DEFAULT_PAGER: &str = "less";
+
+/// Returns the pager command line: `$PAGER`, or `less` if it isn't set
+fn pager_command() -> String {
+    std::env::var("PAGER")
+        .ok()
+        .filter(|pager| !pager.trim().is_empty())
+        .unwrap_or_else(|| DEFAULT_PAGER.to_string())
+}
+
+/// Pipes a container's full log (`docker logs -f`) into the user's pager.
+/// Like a shell session, this takes over the terminal until the pager exits.
+pub async fn run_pager_session(
+    host: &DockerHost,
+    container_id: &str,
+) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    tracing::debug!("Opening logs of {} in pager", container_id);
+
+    // Leave alternate screen so the pager gets a normal terminal
+    execute!(io::stdout(), LeaveAlternateScreen, cursor::Show)?;
+    terminal::disable_raw_mode()?;
+
+    let result = pipe_logs_to_pager(host, container_id, &pager_command()).await;
+
+    // Restore terminal state, however the pager ended
+    execute!(
+        io::stdout(),
+        EnterAlternateScreen,
+        Clear(ClearType::All),
+        cursor::Hide
+    )?;
+    terminal::enable_raw_mode()?;
+
+    result
+}
+
+/// Runs the pager and feeds it the log stream until the pager exits
+async fn pipe_logs_to_pager(
+    host: &DockerHost,
+    container_id: &str,
+    pager: &str,
+) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    // Run through the shell so `$PAGER` may carry arguments (e.g. "less -R")
+    let mut command = tokio::process::Command::new("sh");
+    command.arg("-c").arg(pager).stdin(Stdio::piped());
+    #[cfg(unix)]
+    run_in_foreground_group(&mut command);
+    let mut child = command
+        .spawn()
+        .map_err(|e| format!("Failed to start pager '{}': {}", pager, e))?;
+    let mut stdin = child.stdin.take().ok_or("Pager has no stdin")?;
+
+    let docker = host.docker.clone();
+    let container_id = container_id.to_string();
+    let writer = tokio::spawn(async move {
+        let options = LogsOptions {
+            follow: true,
+            stdout: true,
+            stderr: true,
+            tail: "all".to_string(),
+            ..Default::default()
+        };
+        let mut stream = docker.logs(&container_id, Some(options));
+        while let Some(Ok(output)) = stream.next().await {
+            // A write error means the pager has quit
+            if stdin.write_all(&output.into_bytes()).await.is_err() {
+                break;
+            }
+        }
+    });
+
+    let status = child.wait().await;
+    writer.abort();
+    #[cfg(unix)]
+    reclaim_terminal();
+    status?;
+
+    Ok(())
+}
+
+/// Starts the pager in its own process group and hands that group the terminal.
+/// Outside raw mode Ctrl+C raises SIGINT for the terminal's foreground group, so
+/// it reaches the pager (less stops following) without terminating dtop.
+#[cfg(unix)]
+fn run_in_foreground_group(command: &mut tokio::process::Command) {
+    command.process_group(0);
+    // SAFETY: the closure runs between fork and exec and only makes
+    // async-signal-safe calls
+    unsafe {
+        command.pre_exec(|| {
+            // A background group may only take the terminal while ignoring SIGTTOU
+            libc::signal(libc::SIGTTOU, libc::SIG_IGN);
+            libc::tcsetpgrp(libc::STDOUT_FILENO, libc::getpid());
+            libc::signal(libc::SIGTTOU, libc::SIG_DFL);
+            Ok(())
+        });
+    }
+}
+
+/// Makes dtop's process group the terminal's foreground group again once the pager exits.
+/// SIGTTOU is blocked for this thread only, leaving the process's signal dispositions alone.
+#[cfg(unix)]
+fn reclaim_terminal() {
+    // SAFETY: the signal sets are initialized by sigemptyset/pthread_sigmask before use
+    unsafe {
+        let mut ttou: libc::sigset_t = std::mem::zeroed();
+        let mut previous: libc::sigset_t = std::mem::zeroed();
+        libc::sigemptyset(&mut ttou);
+        libc::sigaddset(&mut ttou, libc::SIGTTOU);
+        libc::pthread_sigmask(libc::SIG_BLOCK, &ttou, &mut previous);
+        libc::tcsetpgrp(libc::STDOUT_FILENO, libc::getpgrp());
+        libc::pthread_sigmask(libc::SIG_SETMASK, &previous, std::ptr::null_mut());
+    }
+}
@@ -7,17 +7,21 @@ use crossterm::{
 };
 use futures_util::StreamExt;
 use std::io;
+use std::path::{Path, PathBuf};
 use tokio::io::AsyncWriteExt as _;
 use tokio::sync::mpsc;
 
 use crate::docker::connection::DockerHost;
 
 /// Runs an interactive shell session inside a container
-/// This function takes over the terminal completely until the shell exits
+/// This function takes over the terminal completely until the shell exits.
+/// With a `transcript_dir`, everything the shell prints is also saved to a file
+/// there; returns that file's path.
 pub async fn run_shell_session(
     host: &DockerHost,
     container_id: &str,
-) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    transcript_dir: Option<&Path>,
+) -> Result<Option<PathBuf>, Box<dyn std::error::Error + Send + Sync>> {
     use tracing::debug;
 
     debug!("Starting shell session for container: {}", container_id);
@@ -33,16 +37,44 @@ pub async fn run_shell_session(
     println!("Press Ctrl+D to exit");
     println!();
 
+    let result = attach_shell(host, container_id, transcript_dir).await;
+
+    // Restore terminal state, however the session ended
+    terminal::disable_raw_mode()?;
+    execute!(
+        io::stdout(),
+        EnterAlternateScreen,
+        Clear(ClearType::All),
+        cursor::Hide
+    )?;
+    terminal::enable_raw_mode()?;
+
+    result
+}
+
+/// Creates the exec instance and relays the terminal to it until the shell exits
+async fn attach_shell(
+    host: &DockerHost,
+    container_id: &str,
+    transcript_dir: Option<&Path>,
+) -> Result<Option<PathBuf>, Box<dyn std::error::Error + Send + Sync>> {
+    use tracing::debug;
+
     // Get terminal size
     let (cols, rows) = terminal::size()?;
 
-    // Create exec instance with /bin/sh (most containers have this)
-    let exec_config = CreateExecOptions {
-        cmd: Some(vec![
+    // Run the host's configured shell, or bash when the image has it and
+    // /bin/sh otherwise (most containers have this)
+    let cmd = match host.shell.as_deref() {
+        Some(shell) => vec![shell],
+        None => vec![
             "sh",
             "-c",
             "command -v bash >/dev/null 2>&1 && exec bash || exec sh",
-        ]),
+        ],
+    };
+    let exec_config = CreateExecOptions {
+        cmd: Some(cmd),
         attach_stdin: Some(true),
         attach_stdout: Some(true),
         attach_stderr: Some(true),
@@ -76,6 +108,25 @@ pub async fn run_shell_session(
 
     debug!("Exec started, handling attached session");
 
+    // Open the transcript file, if requested. A failure here shouldn't cost the shell.
+    let mut transcript = None;
+    if let Some(dir) = transcript_dir {
+        let path = dir.join(transcript_file_name(
+            &host.host_id,
+            container_id,
+            chrono::Local::now(),
+        ));
+        let file = match tokio::fs::create_dir_all(dir).await {
+            Ok(()) => tokio::fs::File::create(&path).await,
+            Err(e) => Err(e),
+        };
+        match file {
+            Ok(file) => transcript = Some((path, file)),
+            Err(e) => tracing::warn!("Failed to create shell transcript {:?}: {}", path, e),
+        }
+    }
+    let transcript_path = transcript.as_ref().map(|(path, _)| path.clone());
+
     // Resize the TTY to match terminal size (after exec starts)
     let resize_options = ResizeExecOptions {
         height: rows,
@@ -83,6 +134,9 @@ pub async fn run_shell_session(
     };
     let _ = host.docker.resize_exec(&exec_id, resize_options).await;
 
+    // Whether the user typed anything; a shell that exits before that never started
+    let mut sent_input = false;
+
     // Handle the attached session
     match exec_result {
         StartExecResults::Attached {
@@ -122,6 +176,7 @@ pub async fn run_shell_session(
 
             // Spawn async task to read from container and write to stdout
             let (shutdown_tx, mut shutdown_rx) = mpsc::channel::<()>(1);
+            let mut transcript_file = transcript.map(|(_, file)| file);
             let output_handle = tokio::spawn(async move {
                 let mut stdout = tokio::io::stdout();
                 loop {
@@ -138,6 +193,9 @@ pub async fn run_shell_session(
                                     if stdout.flush().await.is_err() {
                                         break;
                                     }
+                                    if let Some(file) = &mut transcript_file {
+                                        let _ = file.write_all(&bytes).await;
+                                    }
                                 }
                                 Some(Err(_)) | None => break,
                             }
@@ -171,6 +229,7 @@ pub async fn run_shell_session(
                                 if input.write_all(&bytes).await.is_err() {
                                     break;
                                 }
+                                sent_input = true;
                                 if input.flush().await.is_err() {
                                     break;
                                 }
@@ -202,17 +261,59 @@ pub async fn run_shell_session(
         }
     }
 
-    // Restore terminal state
-    terminal::disable_raw_mode()?;
-    execute!(
-        io::stdout(),
-        EnterAlternateScreen,
-        Clear(ClearType::All),
-        cursor::Hide
-    )?;
-    terminal::enable_raw_mode()?;
+    // Images without a shell (distroless, scratch) fail the exec right away. Only
+    // trust the exit code if the user never typed: a real shell exits with the
+    // status of the last command, which may well have been "not found".
+    let exit_code = host
+        .docker
+        .inspect_exec(&exec_id)
+        .await
+        .ok()
+        .and_then(|inspect| inspect.exit_code);
+    if !sent_input && is_missing_shell(exit_code) {
+        return Err(match &host.shell {
+            Some(shell) => format!("Shell '{}' not found in this container", shell).into(),
+            None => NO_SHELL_MESSAGE.into(),
+        });
+    }
+
+    Ok(transcript_path)
+}
+
+/// Error message for containers whose image has no shell
+const NO_SHELL_MESSAGE: &str = "No shell available in this container";
 
-    Ok(())
+/// Whether an exec's exit code means `sh` could not be run at all:
+/// 126 (not executable) or 127 (not found), as set by the shell and the OCI runtime
+fn is_missing_shell(exit_code: Option<i64>) -> bool {
+    matches!(exit_code, Some(126 | 127))
+}
+
+/// Builds a transcript file name that is unique per session and safe on any
+/// filesystem, e.g. `dtop-shell-user_server1-abc123def456-20251029-101530.log`
+fn transcript_file_name(
+    host_id: &str,
+    container_id: &str,
+    started: chrono::DateTime<chrono::Local>,
+) -> String {
+    let sanitize = |s: &str| -> String {
+        s.chars()
+            .map(|c| {
+                if c.is_ascii_alphanumeric() || c == '-' || c == '.' {
+                    c
+                } else {
+                    '_'
+                }
+            })
+            .collect()
+    };
+
+    format!(
+        "dtop-shell-{}-{}-{}.log",
+        sanitize(host_id),
+        sanitize(container_id),
+        started.format("%Y%m%d-%H%M%S")
+    )
 }
 
 /// Input events from the blocking crossterm thread
@@ -249,3 +350,30 @@ fn key_to_bytes(key_event: KeyEvent) -> Option<Vec<u8>> {
         _ => return None,
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_is_missing_shell() {
+        assert!(is_missing_shell(Some(127)));
+        assert!(is_missing_shell(Some(126)));
+        assert!(!is_missing_shell(Some(0)));
+        // Exit codes of the user's last command (e.g. after Ctrl+C) are not errors
+        assert!(!is_missing_shell(Some(130)));
+        assert!(!is_missing_shell(None));
+    }
+
+    #[test]
+    fn test_transcript_file_name_is_sanitized() {
+        let started = chrono::Local
+            .with_ymd_and_hms(2025, 10, 29, 10, 15, 30)
+            .unwrap();
+        assert_eq!(
+            transcript_file_name("user@server1:2222", "abc123def456", started),
+            "dtop-shell-user_server1_2222-abc123def456-20251029-101530.log"
+        );
+    }
+}
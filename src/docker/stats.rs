@@ -1,15 +1,203 @@
 use bollard::models::ContainerStatsResponse;
 use bollard::query_parameters::StatsOptions;
+use futures_util::future::join_all;
 use futures_util::stream::StreamExt;
-use std::time::Instant;
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+use tokio::sync::mpsc;
+use tokio::time::MissedTickBehavior;
 
-use crate::core::types::{AppEvent, ContainerKey, ContainerStats, EventSender};
+use crate::core::types::{AppEvent, ContainerKey, ContainerStats, EventSender, HostId, MemoryMode};
 use crate::docker::connection::DockerHost;
 
-/// Streams stats for a single container and sends updates via the event channel
+/// How often stats are polled in `stats_mode: poll` when the host has no `refresh_interval_ms`
+const DEFAULT_POLL_INTERVAL: Duration = Duration::from_secs(2);
+
+/// How long [`batch_host_stats`] collects samples before sending them as one event
+const STATS_BATCH_WINDOW: Duration = Duration::from_millis(250);
+
+/// Samples waiting to be batched, so a slow UI can't hold up the stats streams
+const STATS_BATCH_CAPACITY: usize = 256;
+
+/// Sends one container's stats sample (short ID, stats) to its host's batcher
+pub type StatsSender = mpsc::Sender<(String, ContainerStats)>;
+
+/// Starts a [`batch_host_stats`] task for a host and returns the sender its stats
+/// streams use, along with the task's handle
+pub fn spawn_stats_batcher(
+    host_id: HostId,
+    tx: EventSender,
+) -> (StatsSender, tokio::task::JoinHandle<()>) {
+    let (stats_tx, stats_rx) = mpsc::channel(STATS_BATCH_CAPACITY);
+    let handle = tokio::spawn(batch_host_stats(host_id, stats_rx, tx));
+    (stats_tx, handle)
+}
+
+/// Coalesces a host's stats samples into `ContainerStatBatch` events
 ///
-/// Uses exponential decay smoothing to reduce noise in stats:
-/// smoothed = alpha * new_value + (1 - alpha) * previous_smoothed
+/// Streams send a sample per container about every second; rather than one event
+/// each, samples arriving within [`STATS_BATCH_WINDOW`] of the first go out
+/// together. Ends when every sender is dropped or the event channel closes.
+async fn batch_host_stats(
+    host_id: HostId,
+    mut stats_rx: mpsc::Receiver<(String, ContainerStats)>,
+    tx: EventSender,
+) {
+    while let Some(first) = stats_rx.recv().await {
+        let mut batch = vec![first];
+        let window = tokio::time::sleep(STATS_BATCH_WINDOW);
+        tokio::pin!(window);
+        loop {
+            tokio::select! {
+                _ = &mut window => break,
+                sample = stats_rx.recv() => match sample {
+                    Some(sample) => batch.push(sample),
+                    None => break,
+                },
+            }
+        }
+
+        if tx
+            .send(AppEvent::ContainerStatBatch(host_id.clone(), batch))
+            .await
+            .is_err()
+        {
+            break;
+        }
+    }
+}
+
+/// Smoothing and rate state for one container, carried from sample to sample
+#[derive(Default)]
+struct StatsTracker {
+    smoothed_cpu: Option<f64>,
+    smoothed_memory: Option<f64>,
+    smoothed_net_tx: Option<f64>,
+    smoothed_net_rx: Option<f64>,
+    smoothed_disk_read: Option<f64>,
+    smoothed_disk_write: Option<f64>,
+
+    // Previous network stats for rate calculation
+    prev_net_tx: Option<u64>,
+    prev_net_rx: Option<u64>,
+    prev_timestamp: Option<Instant>,
+
+    // Previous disk I/O stats for rate calculation
+    prev_disk_read: Option<u64>,
+    prev_disk_write: Option<u64>,
+
+    cached_cgroup_path: Option<std::path::PathBuf>,
+}
+
+impl StatsTracker {
+    /// Turns a raw sample into smoothed stats, with network and disk rates
+    /// computed against the previous sample
+    ///
+    /// Uses exponential decay smoothing to reduce noise in stats:
+    /// smoothed = alpha * new_value + (1 - alpha) * previous_smoothed
+    async fn update(
+        &mut self,
+        stats: &ContainerStatsResponse,
+        memory_mode: MemoryMode,
+        truncated_id: &str,
+        is_local_host: bool,
+    ) -> ContainerStats {
+        // Smoothing factor: higher alpha = more responsive, lower alpha = smoother
+        // 0.3 provides good balance between responsiveness and smoothness
+        const ALPHA: f64 = 0.3;
+
+        let sample = compute_stats(stats, memory_mode);
+        let (net_tx_rate, net_rx_rate) = calculate_network_rates(
+            stats,
+            self.prev_net_tx,
+            self.prev_net_rx,
+            self.prev_timestamp,
+        );
+
+        // Extract cumulative disk bytes exactly once per tick. The Docker API
+        // path is cheap; only the cgroups v2 filesystem fallback (moby#35352)
+        // touches the disk, and that runs off the async reactor via spawn_blocking.
+        let (disk_read_bytes, disk_write_bytes) = {
+            let (read, write) = extract_disk_bytes(stats);
+            if read.is_some() || write.is_some() {
+                (read, write)
+            } else if is_local_host {
+                let id = truncated_id.to_string();
+                let path = self.cached_cgroup_path.take();
+                match tokio::task::spawn_blocking(move || {
+                    let mut path = path;
+                    let res = extract_cgroup_v2_disk_bytes(&id, &mut path);
+                    (res, path)
+                })
+                .await
+                {
+                    Ok((res, path)) => {
+                        self.cached_cgroup_path = path;
+                        res
+                    }
+                    Err(_) => (None, None),
+                }
+            } else {
+                (None, None)
+            }
+        };
+
+        let (disk_read_rate, disk_write_rate) = calculate_disk_rates(
+            disk_read_bytes,
+            disk_write_bytes,
+            self.prev_disk_read,
+            self.prev_disk_write,
+            self.prev_timestamp,
+        );
+
+        // Update previous network values for next iteration
+        let (tx_bytes, rx_bytes) = extract_network_bytes(stats);
+        self.prev_net_tx = tx_bytes;
+        self.prev_net_rx = rx_bytes;
+
+        // Update previous disk I/O values for next iteration
+        self.prev_disk_read = disk_read_bytes;
+        self.prev_disk_write = disk_write_bytes;
+
+        self.prev_timestamp = Some(Instant::now());
+
+        // Apply exponential moving average (first value passes through unsmoothed)
+        let cpu = ema(self.smoothed_cpu, sample.cpu, ALPHA);
+        let memory = ema(self.smoothed_memory, sample.memory, ALPHA);
+        let network_tx_bytes_per_sec = ema(self.smoothed_net_tx, net_tx_rate, ALPHA);
+        let network_rx_bytes_per_sec = ema(self.smoothed_net_rx, net_rx_rate, ALPHA);
+        let disk_read_bytes_per_sec = ema(self.smoothed_disk_read, disk_read_rate, ALPHA);
+        let disk_write_bytes_per_sec = ema(self.smoothed_disk_write, disk_write_rate, ALPHA);
+
+        // Update smoothed values for next iteration
+        self.smoothed_cpu = Some(cpu);
+        self.smoothed_memory = Some(memory);
+        self.smoothed_net_tx = Some(network_tx_bytes_per_sec);
+        self.smoothed_net_rx = Some(network_rx_bytes_per_sec);
+        self.smoothed_disk_read = Some(disk_read_bytes_per_sec);
+        self.smoothed_disk_write = Some(disk_write_bytes_per_sec);
+
+        ContainerStats {
+            cpu,
+            memory,
+            memory_used_bytes: sample.memory_used_bytes,
+            memory_limit_bytes: sample.memory_limit_bytes,
+            network_tx_bytes_per_sec,
+            network_rx_bytes_per_sec,
+            disk_read_bytes_per_sec,
+            disk_write_bytes_per_sec,
+            disk_io_unavailable: disk_read_bytes.is_none() && disk_write_bytes.is_none(),
+        }
+    }
+}
+
+/// Whether the local cgroups v2 filesystem can stand in for missing disk stats
+fn is_local_host(host: &DockerHost) -> bool {
+    host.host_id == "local" || host.host_id.starts_with("unix://")
+}
+
+/// Streams stats for a single container and sends updates via the event channel
+/// (`stats_mode: stream`; polled hosts use [`poll_host_stats`] instead)
 ///
 /// # Arguments
 /// * `host` - Docker host instance with identifier
@@ -20,120 +208,37 @@ pub async fn stream_container_stats(host: DockerHost, truncated_id: String, tx:
         stream: true,
         one_shot: false,
     };
-
     let mut stats_stream = host.docker.stats(&truncated_id, Some(stats_options));
 
-    // Smoothing factor: higher alpha = more responsive, lower alpha = smoother
-    // 0.3 provides good balance between responsiveness and smoothness
-    const ALPHA: f64 = 0.3;
-
-    let mut smoothed_cpu: Option<f64> = None;
-    let mut smoothed_memory: Option<f64> = None;
-    let mut smoothed_net_tx: Option<f64> = None;
-    let mut smoothed_net_rx: Option<f64> = None;
-    let mut smoothed_disk_read: Option<f64> = None;
-    let mut smoothed_disk_write: Option<f64> = None;
-
-    // Track previous network stats for rate calculation
-    let mut prev_net_tx: Option<u64> = None;
-    let mut prev_net_rx: Option<u64> = None;
-    let mut prev_timestamp: Option<Instant> = None;
-
-    // Track previous disk I/O stats for rate calculation
-    let mut prev_disk_read: Option<u64> = None;
-    let mut prev_disk_write: Option<u64> = None;
-
+    let mut tracker = StatsTracker::default();
     // Check if host is local before permitting local cgroups v2 filesystem fallbacks
-    let is_local_host = host.host_id == "local" || host.host_id.starts_with("unix://");
-    let mut cached_cgroup_path: Option<std::path::PathBuf> = None;
+    let is_local_host = is_local_host(&host);
 
     while let Some(result) = stats_stream.next().await {
         match result {
             Ok(stats) => {
-                let cpu_percent = calculate_cpu_percentage(&stats);
-                let memory_percent = calculate_memory_percentage(&stats);
-                let (net_tx_rate, net_rx_rate) =
-                    calculate_network_rates(&stats, prev_net_tx, prev_net_rx, prev_timestamp);
-
-                // Extract cumulative disk bytes exactly once per tick. The Docker API
-                // path is cheap; only the cgroups v2 filesystem fallback (moby#35352)
-                // touches the disk, and that runs off the async reactor via spawn_blocking.
-                let (disk_read_bytes, disk_write_bytes) = {
-                    let (read, write) = extract_disk_bytes(&stats);
-                    if read.is_some() || write.is_some() {
-                        (read, write)
-                    } else if is_local_host {
-                        let id = truncated_id.clone();
-                        let path = cached_cgroup_path.take();
-                        match tokio::task::spawn_blocking(move || {
-                            let mut path = path;
-                            let res = extract_cgroup_v2_disk_bytes(&id, &mut path);
-                            (res, path)
-                        })
-                        .await
-                        {
-                            Ok((res, path)) => {
-                                cached_cgroup_path = path;
-                                res
-                            }
-                            Err(_) => (None, None),
-                        }
-                    } else {
-                        (None, None)
-                    }
-                };
+                // Honor the per-host refresh interval by dropping samples until it has
+                // elapsed; rates are computed against prev_timestamp, so they still
+                // cover the whole interval
+                if let (Some(interval), Some(prev)) =
+                    (host.refresh_interval, tracker.prev_timestamp)
+                    && prev.elapsed() < interval
+                {
+                    continue;
+                }
 
-                let (disk_read_rate, disk_write_rate) = calculate_disk_rates(
-                    disk_read_bytes,
-                    disk_write_bytes,
-                    prev_disk_read,
-                    prev_disk_write,
-                    prev_timestamp,
-                );
-
-                // Update previous network values for next iteration
-                let (tx_bytes, rx_bytes) = extract_network_bytes(&stats);
-                prev_net_tx = tx_bytes;
-                prev_net_rx = rx_bytes;
-
-                // Update previous disk I/O values for next iteration
-                prev_disk_read = disk_read_bytes;
-                prev_disk_write = disk_write_bytes;
-
-                prev_timestamp = Some(Instant::now());
-
-                // Apply exponential moving average (first value passes through unsmoothed)
-                let cpu = ema(smoothed_cpu, cpu_percent, ALPHA);
-                let memory = ema(smoothed_memory, memory_percent, ALPHA);
-                let network_tx_bytes_per_sec = ema(smoothed_net_tx, net_tx_rate, ALPHA);
-                let network_rx_bytes_per_sec = ema(smoothed_net_rx, net_rx_rate, ALPHA);
-                let disk_read_bytes_per_sec = ema(smoothed_disk_read, disk_read_rate, ALPHA);
-                let disk_write_bytes_per_sec = ema(smoothed_disk_write, disk_write_rate, ALPHA);
-
-                // Update smoothed values for next iteration
-                smoothed_cpu = Some(cpu);
-                smoothed_memory = Some(memory);
-                smoothed_net_tx = Some(network_tx_bytes_per_sec);
-                smoothed_net_rx = Some(network_rx_bytes_per_sec);
-                smoothed_disk_read = Some(disk_read_bytes_per_sec);
-                smoothed_disk_write = Some(disk_write_bytes_per_sec);
-
-                // Extract raw memory bytes for display
-                let (memory_used_bytes, memory_limit_bytes) = extract_memory_bytes(&stats);
-
-                let stats = ContainerStats {
-                    cpu,
-                    memory,
-                    memory_used_bytes,
-                    memory_limit_bytes,
-                    network_tx_bytes_per_sec,
-                    network_rx_bytes_per_sec,
-                    disk_read_bytes_per_sec,
-                    disk_write_bytes_per_sec,
-                };
+                let stats = tracker
+                    .update(&stats, host.memory_mode, &truncated_id, is_local_host)
+                    .await;
 
-                let key = ContainerKey::new(host.host_id.clone(), truncated_id.clone());
-                if tx.send(AppEvent::ContainerStat(key, stats)).await.is_err() {
+                let sent = match &host.stats_tx {
+                    Some(stats_tx) => stats_tx.send((truncated_id.clone(), stats)).await.is_ok(),
+                    None => {
+                        let key = ContainerKey::new(host.host_id.clone(), truncated_id.clone());
+                        tx.send(AppEvent::ContainerStat(key, stats)).await.is_ok()
+                    }
+                };
+                if !sent {
                     break;
                 }
             }
@@ -151,6 +256,122 @@ pub async fn stream_container_stats(host: DockerHost, truncated_id: String, tx:
     );
 }
 
+/// Polls stats for all of a host's running containers from a single task
+/// (`stats_mode: poll`), instead of holding a stream open per container
+///
+/// Every refresh interval (the host's `refresh_interval`, or
+/// [`DEFAULT_POLL_INTERVAL`]) it lists the running containers and requests one
+/// sample from each, sending the round's results as one batch.
+///
+/// Without `one_shot`, Docker waits for a second reading before answering, so
+/// the sample includes `precpu_stats` and the CPU calculation works as when
+/// streaming. Each request holds a permit from the host's `stats_poll_limit`,
+/// bounding concurrent polls. Runs until aborted by the container manager or
+/// the event channel closes.
+pub async fn poll_host_stats(host: DockerHost, tx: EventSender) {
+    let mut interval =
+        tokio::time::interval(host.refresh_interval.unwrap_or(DEFAULT_POLL_INTERVAL));
+    // A slow round of polls shouldn't be followed by a burst of catch-up rounds
+    interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+    let is_local_host = is_local_host(&host);
+    let mut trackers: HashMap<String, StatsTracker> = HashMap::new();
+
+    loop {
+        interval.tick().await;
+
+        let ids = match host.running_container_ids().await {
+            Ok(ids) => ids,
+            Err(e) => {
+                tracing::debug!("Failed to list containers for {}: {}", host.host_id, e);
+                continue;
+            }
+        };
+        // Forget containers that stopped, so a restart starts from fresh rates
+        trackers.retain(|id, _| ids.contains(id));
+
+        let samples = join_all(ids.into_iter().map(|id| {
+            let host = &host;
+            async move {
+                let _permit = host.stats_poll_limit.acquire().await;
+                let stats_options = StatsOptions {
+                    stream: false,
+                    one_shot: false,
+                };
+                let sample = host.docker.stats(&id, Some(stats_options)).next().await;
+                (id, sample)
+            }
+        }))
+        .await;
+
+        let mut batch = Vec::with_capacity(samples.len());
+        for (id, sample) in samples {
+            // The container may have stopped since it was listed
+            let Some(Ok(stats)) = sample else {
+                continue;
+            };
+            let stats = trackers
+                .entry(id.clone())
+                .or_default()
+                .update(&stats, host.memory_mode, &id, is_local_host)
+                .await;
+
+            batch.push((id, stats));
+        }
+
+        // One event per round, however many containers were polled
+        if !batch.is_empty()
+            && tx
+                .send(AppEvent::ContainerStatBatch(host.host_id.clone(), batch))
+                .await
+                .is_err()
+        {
+            return;
+        }
+    }
+}
+
+/// Fetches a single, unsmoothed stats sample for a container (`stream: false`)
+///
+/// Docker waits for a second sample before answering, so CPU usage is computed
+/// from a real delta. Network and disk rates need two samples on our side and
+/// are returned as zero; callers should keep their previous rates.
+pub async fn fetch_container_stats_once(
+    host: &DockerHost,
+    truncated_id: &str,
+) -> Result<ContainerStats, String> {
+    let stats_options = StatsOptions {
+        stream: false,
+        one_shot: false,
+    };
+
+    let stats = host
+        .docker
+        .stats(truncated_id, Some(stats_options))
+        .next()
+        .await
+        .ok_or_else(|| "Stats stream closed without a sample".to_string())?
+        .map_err(|e| format!("Failed to fetch stats: {}", e))?;
+
+    Ok(compute_stats(&stats, host.memory_mode))
+}
+
+/// Computes the per-sample values (CPU and memory) from a single stats response
+///
+/// Values are raw (unsmoothed). Network and disk rates need two samples and are
+/// left at zero; `StatsTracker::update` fills them in.
+pub fn compute_stats(stats: &ContainerStatsResponse, memory_mode: MemoryMode) -> ContainerStats {
+    let (memory_used_bytes, memory_limit_bytes) = extract_memory_bytes(stats, memory_mode);
+
+    ContainerStats {
+        cpu: calculate_cpu_percentage(stats),
+        memory: calculate_memory_percentage(stats, memory_mode),
+        memory_used_bytes,
+        memory_limit_bytes,
+        ..Default::default()
+    }
+}
+
 /// Applies one step of an exponential moving average.
 ///
 /// Returns `sample` unchanged for the first value (when `prev` is `None`),
@@ -184,7 +405,20 @@ pub fn calculate_cpu_percentage(stats: &ContainerStatsResponse) -> f64 {
 
     let system_delta = cpu_stats.system_cpu_usage.unwrap_or(0) as f64
         - precpu_stats.system_cpu_usage.unwrap_or(0) as f64;
-    let number_cpus = cpu_stats.online_cpus.unwrap_or(1) as f64;
+    // Daemons before API 1.27 don't report online_cpus; count the per-CPU usage
+    // entries instead, as the docker CLI does
+    let number_cpus = cpu_stats
+        .online_cpus
+        .map(|n| n as usize)
+        .or_else(|| {
+            cpu_stats
+                .cpu_usage
+                .as_ref()
+                .and_then(|u| u.percpu_usage.as_ref())
+                .map(Vec::len)
+        })
+        .filter(|&n| n > 0)
+        .unwrap_or(1) as f64;
 
     if system_delta > 0.0 && cpu_delta > 0.0 {
         (cpu_delta / system_delta) * number_cpus * 100.0
@@ -195,16 +429,14 @@ pub fn calculate_cpu_percentage(stats: &ContainerStatsResponse) -> f64 {
 
 /// Calculates memory usage percentage from container stats
 ///
-/// Subtracts cache from raw usage to match `docker stats` behavior:
-/// - cgroups v2: subtract `inactive_file`
-/// - cgroups v1: subtract `cache`
-pub fn calculate_memory_percentage(stats: &ContainerStatsResponse) -> f64 {
+/// See [`calculate_used_memory`] for how usage is derived in each [`MemoryMode`].
+pub fn calculate_memory_percentage(stats: &ContainerStatsResponse, mode: MemoryMode) -> f64 {
     let memory_stats = match &stats.memory_stats {
         Some(ms) => ms,
         None => return 0.0,
     };
 
-    let memory_usage = calculate_used_memory(memory_stats);
+    let memory_usage = calculate_used_memory(memory_stats, mode);
     let memory_limit = memory_stats.limit.unwrap_or(1) as f64;
 
     if memory_limit > 0.0 {
@@ -215,25 +447,39 @@ pub fn calculate_memory_percentage(stats: &ContainerStatsResponse) -> f64 {
 }
 
 /// Extracts memory bytes (used, limit) from container stats
-/// Subtracts cache to match `docker stats` behavior
-fn extract_memory_bytes(stats: &ContainerStatsResponse) -> (u64, u64) {
+fn extract_memory_bytes(stats: &ContainerStatsResponse, mode: MemoryMode) -> (u64, u64) {
     let memory_stats = match &stats.memory_stats {
         Some(ms) => ms,
         None => return (0, 0),
     };
 
-    let memory_used = calculate_used_memory(memory_stats) as u64;
+    let memory_used = calculate_used_memory(memory_stats, mode) as u64;
     let memory_limit = memory_stats.limit.unwrap_or(0);
 
     (memory_used, memory_limit)
 }
 
-/// Calculates used memory by subtracting cache from raw usage,
-/// matching `docker stats` behavior.
+/// Calculates used memory in bytes.
 ///
-/// On cgroups v2, subtracts `inactive_file`. On cgroups v1, subtracts `cache`.
-/// Falls back to raw usage if neither is available.
-fn calculate_used_memory(memory_stats: &bollard::models::ContainerMemoryStats) -> f64 {
+/// - [`MemoryMode::Docker`] subtracts cache from raw usage, matching `docker stats`:
+///   `inactive_file` on cgroups v2, `cache` on cgroups v1. Falls back to raw
+///   usage if neither is available.
+/// - [`MemoryMode::Rss`] reports resident anonymous memory only: `anon` on
+///   cgroups v2, `rss` on cgroups v1. Falls back to the Docker calculation when
+///   the daemon doesn't report either.
+fn calculate_used_memory(
+    memory_stats: &bollard::models::ContainerMemoryStats,
+    mode: MemoryMode,
+) -> f64 {
+    if mode == MemoryMode::Rss
+        && let Some(rss) = memory_stats
+            .stats
+            .as_ref()
+            .and_then(|s| s.get("anon").or_else(|| s.get("rss")).copied())
+    {
+        return rss as f64;
+    }
+
     let usage = memory_stats.usage.unwrap_or(0) as f64;
 
     let cache = memory_stats
@@ -500,6 +746,26 @@ mod tests {
         assert_eq!(cpu, 120.0);
     }
 
+    #[test]
+    fn test_calculate_cpu_percentage_without_online_cpus() {
+        // Older daemons only report per-CPU usage, not online_cpus
+        let old_api = |total_usage, system_cpu_usage| {
+            let mut cpu_stats = create_cpu_stats(total_usage, system_cpu_usage, 0);
+            cpu_stats.online_cpus = None;
+            if let Some(usage) = cpu_stats.cpu_usage.as_mut() {
+                usage.percpu_usage = Some(vec![0; 4]);
+            }
+            cpu_stats
+        };
+        let stats = ContainerStatsResponse {
+            cpu_stats: Some(old_api(1_000_000_000, 2_000_000_000)),
+            precpu_stats: Some(old_api(500_000_000, 1_000_000_000)),
+            ..Default::default()
+        };
+
+        assert_eq!(calculate_cpu_percentage(&stats), 200.0);
+    }
+
     #[test]
     fn test_calculate_cpu_percentage_missing_cpu_stats() {
         let stats = ContainerStatsResponse {
@@ -562,7 +828,10 @@ mod tests {
             ..Default::default()
         };
 
-        assert_eq!(calculate_memory_percentage(&stats), 50.0);
+        assert_eq!(
+            calculate_memory_percentage(&stats, MemoryMode::Docker),
+            50.0
+        );
     }
 
     #[test]
@@ -581,7 +850,10 @@ mod tests {
             ..Default::default()
         };
 
-        assert_eq!(calculate_memory_percentage(&stats), 100.0);
+        assert_eq!(
+            calculate_memory_percentage(&stats, MemoryMode::Docker),
+            100.0
+        );
     }
 
     #[test]
@@ -600,7 +872,7 @@ mod tests {
             ..Default::default()
         };
 
-        assert_eq!(calculate_memory_percentage(&stats), 5.0);
+        assert_eq!(calculate_memory_percentage(&stats, MemoryMode::Docker), 5.0);
     }
 
     #[test]
@@ -610,7 +882,7 @@ mod tests {
             ..Default::default()
         };
 
-        assert_eq!(calculate_memory_percentage(&stats), 0.0);
+        assert_eq!(calculate_memory_percentage(&stats, MemoryMode::Docker), 0.0);
     }
 
     #[test]
@@ -629,7 +901,7 @@ mod tests {
             ..Default::default()
         };
 
-        assert_eq!(calculate_memory_percentage(&stats), 0.0);
+        assert_eq!(calculate_memory_percentage(&stats, MemoryMode::Docker), 0.0);
     }
 
     #[test]
@@ -649,7 +921,103 @@ mod tests {
         };
 
         // Should handle division by zero gracefully
-        assert_eq!(calculate_memory_percentage(&stats), 0.0);
+        assert_eq!(calculate_memory_percentage(&stats, MemoryMode::Docker), 0.0);
+    }
+
+    fn create_memory_stats(
+        usage: u64,
+        limit: u64,
+        entries: &[(&str, u64)],
+    ) -> ContainerMemoryStats {
+        ContainerMemoryStats {
+            usage: Some(usage),
+            limit: Some(limit),
+            max_usage: None,
+            stats: Some(entries.iter().map(|(k, v)| (k.to_string(), *v)).collect()),
+            failcnt: None,
+            commitbytes: None,
+            commitpeakbytes: None,
+            privateworkingset: None,
+        }
+    }
+
+    #[test]
+    fn test_compute_stats_docker_mode_subtracts_inactive_file() {
+        // cgroups v2: 800 MB usage, 300 MB inactive page cache, 400 MB anon
+        let stats = ContainerStatsResponse {
+            cpu_stats: Some(create_cpu_stats(1_000_000_000, 2_000_000_000, 4)),
+            precpu_stats: Some(create_cpu_stats(500_000_000, 1_000_000_000, 4)),
+            memory_stats: Some(create_memory_stats(
+                800_000_000,
+                1_000_000_000,
+                &[("inactive_file", 300_000_000), ("anon", 400_000_000)],
+            )),
+            ..Default::default()
+        };
+
+        let computed = compute_stats(&stats, MemoryMode::Docker);
+        assert_eq!(computed.cpu, 200.0);
+        assert_eq!(computed.memory_used_bytes, 500_000_000);
+        assert_eq!(computed.memory_limit_bytes, 1_000_000_000);
+        assert_eq!(computed.memory, 50.0);
+        // Rates need two samples
+        assert_eq!(computed.network_tx_bytes_per_sec, 0.0);
+        assert_eq!(computed.disk_read_bytes_per_sec, 0.0);
+    }
+
+    #[test]
+    fn test_compute_stats_rss_mode_uses_anon() {
+        let stats = ContainerStatsResponse {
+            memory_stats: Some(create_memory_stats(
+                800_000_000,
+                1_000_000_000,
+                &[("inactive_file", 300_000_000), ("anon", 400_000_000)],
+            )),
+            ..Default::default()
+        };
+
+        let computed = compute_stats(&stats, MemoryMode::Rss);
+        assert_eq!(computed.memory_used_bytes, 400_000_000);
+        assert_eq!(computed.memory, 40.0);
+    }
+
+    #[test]
+    fn test_compute_stats_rss_mode_cgroups_v1() {
+        // cgroups v1 reports `rss` and `cache`
+        let stats = ContainerStatsResponse {
+            memory_stats: Some(create_memory_stats(
+                900_000_000,
+                1_000_000_000,
+                &[("cache", 600_000_000), ("rss", 250_000_000)],
+            )),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            compute_stats(&stats, MemoryMode::Docker).memory_used_bytes,
+            300_000_000
+        );
+        assert_eq!(
+            compute_stats(&stats, MemoryMode::Rss).memory_used_bytes,
+            250_000_000
+        );
+    }
+
+    #[test]
+    fn test_compute_stats_rss_mode_falls_back_without_rss() {
+        let stats = ContainerStatsResponse {
+            memory_stats: Some(create_memory_stats(
+                800_000_000,
+                1_000_000_000,
+                &[("inactive_file", 300_000_000)],
+            )),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            compute_stats(&stats, MemoryMode::Rss).memory_used_bytes,
+            500_000_000
+        );
     }
 
     fn create_blkio_entry(op: &str, value: u64) -> ContainerBlkioStatEntry {
@@ -840,6 +1208,52 @@ mod tests {
         assert_eq!(write_rate, 0.0);
     }
 
+    #[tokio::test]
+    async fn test_stats_batcher_coalesces_samples() {
+        let (tx, mut rx) = mpsc::channel(10);
+        let (stats_tx, handle) = spawn_stats_batcher("local".to_string(), tx);
+        for id in ["aaa", "bbb", "ccc"] {
+            stats_tx
+                .send((id.to_string(), ContainerStats::default()))
+                .await
+                .unwrap();
+        }
+        drop(stats_tx);
+        handle.await.unwrap();
+
+        match rx.recv().await {
+            Some(AppEvent::ContainerStatBatch(host_id, batch)) => {
+                assert_eq!(host_id, "local");
+                let ids: Vec<_> = batch.iter().map(|(id, _)| id.as_str()).collect();
+                assert_eq!(ids, ["aaa", "bbb", "ccc"]);
+            }
+            other => panic!("expected one stats batch, got {:?}", other),
+        }
+        assert!(rx.recv().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_stats_tracker_smooths_successive_samples() {
+        let sample = |usage: u64| ContainerStatsResponse {
+            cpu_stats: Some(create_cpu_stats(usage, 2_000_000_000, 4)),
+            precpu_stats: Some(create_cpu_stats(500_000_000, 1_000_000_000, 4)),
+            ..Default::default()
+        };
+        let mut tracker = StatsTracker::default();
+
+        // The first sample passes through unsmoothed (200%)
+        let first = tracker
+            .update(&sample(1_000_000_000), MemoryMode::Docker, "abc123", false)
+            .await;
+        assert_eq!(first.cpu, 200.0);
+
+        // Then 0.3 * 100% + 0.7 * 200%
+        let second = tracker
+            .update(&sample(750_000_000), MemoryMode::Docker, "abc123", false)
+            .await;
+        assert!((second.cpu - 170.0).abs() < 1e-9);
+    }
+
     #[test]
     fn test_parse_io_stat_content() {
         let content = "8:0 rbytes=20480 wbytes=891289600 rios=2 wios=1700\n253:0 rbytes=10240 wbytes=10000 rios=1 wios=1";
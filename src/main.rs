@@ -17,8 +17,12 @@ static GLOBAL_ALLOC: alloc_counter::CountingAllocator = alloc_counter::CountingA
 use clap::Parser;
 use clap::builder::styling::{AnsiColor, Effects, Styles};
 use crossterm::{
+    clipboard::CopyToClipboard,
+    event::{DisableMouseCapture, EnableMouseCapture},
     execute,
-    terminal::{EnterAlternateScreen, LeaveAlternateScreen, disable_raw_mode, enable_raw_mode},
+    terminal::{
+        EnterAlternateScreen, LeaveAlternateScreen, SetTitle, disable_raw_mode, enable_raw_mode,
+    },
 };
 use ratatui::{Terminal, backend::CrosstermBackend};
 use std::collections::HashMap;
@@ -31,22 +35,54 @@ use tokio::sync::mpsc;
 use tracing_subscriber::EnvFilter;
 
 use cli::config::Config;
-use cli::connect::{establish_connections, spawn_remaining_connections_handler};
+use cli::connect::{create_host_id, spawn_connections};
+use cli::demo::{demo_host_ids, spawn_demo};
 use core::app_state::AppState;
-use core::types::{AppEvent, Column, ColumnConfig, RenderAction, SortDirection};
-use docker::connection::{DockerHost, container_manager};
+use core::types::{
+    AppEvent, Column, ColumnConfig, CustomAction, DisplayTimezone, RenderAction, SortDirection,
+    TimeFormat, TitleCount,
+};
 use ui::icons::IconStyle;
 use ui::input::keyboard_worker;
-use ui::render::{UiStyles, cleanup_expired_errors, render_ui};
+use ui::render::{UiStyles, cleanup_expired_errors, render_ui, terminal_title};
 
 /// Configuration for the event loop
 struct EventLoopConfig {
-    icon_style: IconStyle,
+    host_ids: Vec<String>,
+    styles: UiStyles,
     show_all: bool,
     sort_field: Column,
     sort_direction: Option<SortDirection>,
     column_config: ColumnConfig,
     config_path: Option<std::path::PathBuf>,
+    quit_on_all_disconnected: bool,
+    show_footer: bool,
+    max_name_width: Option<u16>,
+    title_count: TitleCount,
+    time_format: TimeFormat,
+    timezone: DisplayTimezone,
+    show_fleet_totals: bool,
+    host_separators: bool,
+    row_numbers: bool,
+    show_summary: bool,
+    confirm_destructive: bool,
+    stale_after_secs: u64,
+    show_last_stats: bool,
+    search_regex: bool,
+    terminal_title: bool,
+    log_max_lines: usize,
+    stats_history: usize,
+    log_timestamps: bool,
+    log_wrap: bool,
+    log_line_numbers: bool,
+    notification_ttl_secs: u64,
+    draw_interval: Duration,
+    mouse: bool,
+    scroll_lines: usize,
+    shell_transcript_dir: Option<std::path::PathBuf>,
+    custom_actions: Vec<CustomAction>,
+    #[cfg(feature = "control-socket")]
+    control_snapshot: Option<cli::control::SharedSnapshot>,
 }
 
 /// Returns custom styles for CLI help output
@@ -152,6 +188,56 @@ struct Args {
     /// The sort direction can be toggled in the UI by pressing the same key again.
     #[arg(short = 's', long = "sort", verbatim_doc_comment)]
     sort: Option<String>,
+
+    /// Disable mouse capture
+    ///
+    /// Lets you select and copy text (e.g. container IDs) with your terminal as usual.
+    /// Mouse features such as clicking a column header to sort won't work.
+    #[arg(long = "no-mouse", verbatim_doc_comment)]
+    no_mouse: bool,
+
+    /// Disable colors
+    ///
+    /// Draws the UI with bold and reverse video only, for terminals and logs
+    /// that don't handle ANSI colors. Also enabled by a non-empty NO_COLOR
+    /// environment variable.
+    #[arg(long = "no-color", verbatim_doc_comment)]
+    no_color: bool,
+
+    /// Listen for JSON-RPC requests on a Unix socket at this path
+    ///
+    /// One JSON request per line. Methods:
+    ///   containers                        (List containers with current stats)
+    ///   start, stop, restart              (Params: {"host": "local", "id": "<container id>"})
+    #[cfg(feature = "control-socket")]
+    #[arg(long = "control-socket", value_name = "PATH", verbatim_doc_comment)]
+    control_socket: Option<std::path::PathBuf>,
+
+    /// Print a snapshot of the containers and exit, without starting the UI
+    ///
+    /// Waits for every host's containers and their stats, then prints a table
+    /// (running containers only, unless --all). Useful for cron jobs and scripts.
+    #[arg(long, verbatim_doc_comment)]
+    once: bool,
+
+    /// Output format for --once
+    ///
+    /// Options:
+    ///   table  - Plain-text table (default)
+    ///   json   - All containers, in the same JSON as the 'x' export
+    #[arg(
+        long,
+        value_name = "FORMAT",
+        default_value = "table",
+        value_parser = ["table", "json"],
+        requires = "once",
+        verbatim_doc_comment
+    )]
+    format: String,
+
+    /// Show synthetic containers with fluctuating stats instead of connecting to Docker
+    #[arg(long, hide = true)]
+    demo: bool,
 }
 
 #[derive(clap::Subcommand, Debug)]
@@ -159,6 +245,10 @@ enum Command {
     /// Update dtop to the latest version
     #[cfg(feature = "self-update")]
     Update,
+
+    /// Print all keybindings as plain text
+    #[command(hide = true)]
+    Keys,
 }
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
@@ -175,6 +265,10 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             Command::Update => {
                 return cli::update::run_update();
             }
+            Command::Keys => {
+                print!("{}", ui::keys::format_key_bindings());
+                return Ok(());
+            }
         }
     }
 
@@ -241,25 +335,68 @@ async fn run_async(args: Args) -> Result<(), Box<dyn std::error::Error>> {
         IconStyle::Unicode
     };
 
+    // Build the UI styles up front so a bad theme color or threshold fails before the UI starts.
+    // NO_COLOR (https://no-color.org) only counts when set to a non-empty value.
+    let no_color = args.no_color || std::env::var_os("NO_COLOR").is_some_and(|v| !v.is_empty());
+    let mut styles = if no_color {
+        UiStyles::monochrome(icon_style)
+    } else {
+        UiStyles::with_theme(icon_style, &merged_config.theme.clone().unwrap_or_default())?
+    };
+    styles.cpu_thresholds = merged_config.cpu_thresholds.unwrap_or_default();
+    styles.cpu_thresholds.validate("cpu_thresholds")?;
+    styles.memory_thresholds = merged_config.memory_thresholds.unwrap_or_default();
+    styles.memory_thresholds.validate("memory_thresholds")?;
+
     // Determine show_all setting (CLI or config, defaults to false)
     let show_all = merged_config.all.unwrap_or(false);
 
     // Determine sort field (CLI or config, defaults to Uptime)
-    let sort_field = merged_config
-        .sort
-        .as_ref()
-        .and_then(|s| Column::from_sort_str(s))
-        .unwrap_or(Column::Uptime);
+    let sort_field = match merged_config.sort.as_deref() {
+        Some(s) => Column::from_sort_str(s).unwrap_or_else(|| {
+            tracing::warn!("Unknown sort field '{}', sorting by uptime", s);
+            Column::Uptime
+        }),
+        None => Column::Uptime,
+    };
 
     // Determine sort direction (from config, None means use column's default)
-    let sort_direction = merged_config
-        .sort_direction
+    let sort_direction = merged_config.sort_direction.as_deref().and_then(|s| {
+        let direction = SortDirection::from_config_str(s);
+        if direction.is_none() {
+            tracing::warn!("Unknown sort_direction '{}', using the column default", s);
+        }
+        direction
+    });
+
+    // Determine which count leads the table title (from config, defaults to running)
+    let title_count = merged_config
+        .title_count
         .as_ref()
-        .and_then(|s| match s.as_str() {
-            "asc" => Some(SortDirection::Ascending),
-            "desc" => Some(SortDirection::Descending),
-            _ => None,
-        });
+        .and_then(|s| s.parse::<TitleCount>().ok())
+        .unwrap_or_default();
+
+    // Determine how times are shown (from config, defaults to relative in local time)
+    let time_format = match merged_config.time_format.as_deref() {
+        Some(s) => s.parse::<TimeFormat>().unwrap_or_else(|_| {
+            tracing::warn!(
+                "Unknown time_format '{}' (expected relative or absolute), using relative",
+                s
+            );
+            TimeFormat::default()
+        }),
+        None => TimeFormat::default(),
+    };
+    let timezone = match merged_config.timezone.as_deref() {
+        Some(s) => s.parse::<DisplayTimezone>().unwrap_or_else(|_| {
+            tracing::warn!(
+                "Unknown timezone '{}' (expected local or utc), using local",
+                s
+            );
+            DisplayTimezone::default()
+        }),
+        None => DisplayTimezone::default(),
+    };
 
     let column_config = if let Some(ref cols) = merged_config.columns {
         ColumnConfig::from_config_strings(cols)
@@ -268,26 +405,48 @@ async fn run_async(args: Args) -> Result<(), Box<dyn std::error::Error>> {
     };
     // Only pass config_path when the config file is actually being used,
     // to avoid silently overwriting a config file the user didn't intend to use
-    let config_path_for_state = if cli_provided { None } else { config_path };
+    let config_path_for_state = if cli_provided || args.demo {
+        None
+    } else {
+        config_path
+    };
+
+    // Print a one-shot snapshot instead of starting the UI
+    if args.once {
+        return cli::once::run_once(&merged_config, args.format == "json").await;
+    }
 
     // Create event channel
     let (tx, mut rx) = mpsc::channel::<AppEvent>(1000);
 
-    // Establish connections to all configured hosts
-    let connection_result = establish_connections(&merged_config, tx.clone()).await?;
-
-    // Store first connected host
-    let mut connected_hosts: HashMap<String, DockerHost> = HashMap::new();
-    connected_hosts.insert(
-        connection_result.first_host.host_id.clone(),
-        connection_result.first_host.clone(),
-    );
+    // Connect to all configured hosts in the background; the UI shows their progress.
+    // Demo mode feeds synthetic containers through the same events instead.
+    let host_ids = if args.demo {
+        spawn_demo(tx.clone());
+        demo_host_ids()
+    } else {
+        spawn_connections(&merged_config, tx.clone());
+        merged_config
+            .hosts
+            .iter()
+            .map(|host_config| create_host_id(&host_config.host))
+            .collect()
+    };
 
-    // Start container manager for first host
-    spawn_container_manager(connection_result.first_host, tx.clone());
+    // Start the control socket, if requested
+    #[cfg(feature = "control-socket")]
+    let control_snapshot = match args.control_socket {
+        Some(ref path) => {
+            let snapshot = cli::control::SharedSnapshot::default();
+            cli::control::spawn_control_socket(path, snapshot.clone(), tx.clone())?;
+            Some(snapshot)
+        }
+        None => None,
+    };
 
-    // Handle remaining connections in background
-    spawn_remaining_connections_handler(connection_result.remaining_rx, tx.clone());
+    // Dump a state summary to the debug log on SIGUSR1
+    #[cfg(unix)]
+    spawn_dump_signal_handler(tx.clone());
 
     // Create pause flag for keyboard worker
     let keyboard_paused = Arc::new(AtomicBool::new(false));
@@ -295,23 +454,54 @@ async fn run_async(args: Args) -> Result<(), Box<dyn std::error::Error>> {
     // Spawn keyboard worker in blocking thread
     spawn_keyboard_worker(tx.clone(), keyboard_paused.clone());
 
+    // Mouse capture is on unless disabled (CLI --no-mouse takes precedence over config)
+    let mouse = !args.no_mouse && merged_config.mouse.unwrap_or(true);
+
     // Setup terminal
-    let mut terminal = setup_terminal()?;
+    let mut terminal = setup_terminal(mouse)?;
 
     // Run main event loop
-    run_event_loop(
+    let (exit_message, exit_error) = run_event_loop(
         &mut terminal,
         &mut rx,
         tx.clone(),
-        connected_hosts,
         keyboard_paused,
         EventLoopConfig {
-            icon_style,
+            host_ids,
+            styles,
             show_all,
             sort_field,
             sort_direction,
             column_config,
             config_path: config_path_for_state,
+            quit_on_all_disconnected: merged_config.quit_on_all_disconnected.unwrap_or(false),
+            show_footer: merged_config.show_footer.unwrap_or(false),
+            max_name_width: merged_config.max_name_width,
+            title_count,
+            time_format,
+            timezone,
+            show_fleet_totals: merged_config.show_fleet_totals.unwrap_or(false),
+            host_separators: merged_config.host_separators.unwrap_or(false),
+            row_numbers: merged_config.row_numbers.unwrap_or(false),
+            show_summary: merged_config.show_summary.unwrap_or(true),
+            confirm_destructive: merged_config.confirm_destructive.unwrap_or(true),
+            stale_after_secs: merged_config.stale_after_secs.unwrap_or(30),
+            show_last_stats: merged_config.show_last_stats.unwrap_or(false),
+            search_regex: merged_config.search_regex.unwrap_or(false),
+            terminal_title: merged_config.terminal_title.unwrap_or(false),
+            log_max_lines: merged_config.log_max_lines.unwrap_or(10_000),
+            stats_history: merged_config.stats_history.unwrap_or(60),
+            log_timestamps: merged_config.log_timestamps.unwrap_or(true),
+            log_wrap: merged_config.log_wrap.unwrap_or(true),
+            log_line_numbers: merged_config.log_line_numbers.unwrap_or(false),
+            notification_ttl_secs: merged_config.notification_ttl_secs.unwrap_or(10),
+            draw_interval: merged_config.draw_interval(),
+            mouse,
+            scroll_lines: merged_config.scroll_lines.unwrap_or(3),
+            shell_transcript_dir: merged_config.shell_transcript_dir.clone(),
+            custom_actions: merged_config.custom_actions.clone().unwrap_or_default(),
+            #[cfg(feature = "control-socket")]
+            control_snapshot,
         },
     )
     .await?;
@@ -319,14 +509,31 @@ async fn run_async(args: Args) -> Result<(), Box<dyn std::error::Error>> {
     // Restore terminal
     cleanup_terminal(&mut terminal)?;
 
+    #[cfg(feature = "control-socket")]
+    if let Some(ref path) = args.control_socket {
+        cli::control::remove_control_socket(path);
+    }
+
+    if let Some(message) = exit_message {
+        if exit_error {
+            return Err(message.into());
+        }
+        eprintln!("{}", message);
+    }
+
     Ok(())
 }
 
 /// Sets up the terminal for TUI rendering
-fn setup_terminal() -> Result<Terminal<CrosstermBackend<io::Stdout>>, Box<dyn std::error::Error>> {
+fn setup_terminal(
+    mouse: bool,
+) -> Result<Terminal<CrosstermBackend<io::Stdout>>, Box<dyn std::error::Error>> {
     enable_raw_mode()?;
     let mut stdout = io::stdout();
     execute!(stdout, EnterAlternateScreen)?;
+    if mouse {
+        execute!(stdout, EnableMouseCapture)?;
+    }
     let backend = CrosstermBackend::new(stdout);
     Ok(Terminal::new(backend)?)
 }
@@ -336,18 +543,15 @@ fn cleanup_terminal(
     terminal: &mut Terminal<CrosstermBackend<io::Stdout>>,
 ) -> Result<(), Box<dyn std::error::Error>> {
     disable_raw_mode()?;
-    execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
+    execute!(
+        terminal.backend_mut(),
+        LeaveAlternateScreen,
+        DisableMouseCapture
+    )?;
     terminal.show_cursor()?;
     Ok(())
 }
 
-/// Spawns the container manager task for a specific host
-fn spawn_container_manager(docker_host: DockerHost, tx: mpsc::Sender<AppEvent>) {
-    tokio::spawn(async move {
-        container_manager(docker_host, tx).await;
-    });
-}
-
 /// Spawns the keyboard input worker thread
 fn spawn_keyboard_worker(tx: mpsc::Sender<AppEvent>, paused: Arc<AtomicBool>) {
     std::thread::spawn(move || {
@@ -355,17 +559,39 @@ fn spawn_keyboard_worker(tx: mpsc::Sender<AppEvent>, paused: Arc<AtomicBool>) {
     });
 }
 
-/// Main event loop that processes events and renders the UI
+/// Spawns a task that asks the event loop to log a state summary on SIGUSR1.
+/// The channel depth is logged here too, so a stalled event loop still leaves a trace.
+#[cfg(unix)]
+fn spawn_dump_signal_handler(tx: mpsc::Sender<AppEvent>) {
+    use tokio::signal::unix::{SignalKind, signal};
+
+    tokio::spawn(async move {
+        let Ok(mut sigusr1) = signal(SignalKind::user_defined1()) else {
+            tracing::warn!("Failed to install SIGUSR1 handler");
+            return;
+        };
+
+        while sigusr1.recv().await.is_some() {
+            let queued_events = tx.max_capacity() - tx.capacity();
+            tracing::info!("SIGUSR1 received, {} events queued", queued_events);
+            if tx.try_send(AppEvent::DumpState).is_err() {
+                tracing::warn!("Event channel full, state dump skipped");
+            }
+        }
+    });
+}
+
+/// Main event loop that processes events and renders the UI.
+/// Returns a message to print after the terminal is restored, if dtop quit on its own.
 async fn run_event_loop(
     terminal: &mut Terminal<CrosstermBackend<io::Stdout>>,
     rx: &mut mpsc::Receiver<AppEvent>,
     tx: mpsc::Sender<AppEvent>,
-    connected_hosts: HashMap<String, DockerHost>,
     keyboard_paused: Arc<AtomicBool>,
     config: EventLoopConfig,
-) -> Result<(), Box<dyn std::error::Error>> {
+) -> Result<(Option<String>, bool), Box<dyn std::error::Error>> {
     let mut state = AppState::new(
-        connected_hosts,
+        HashMap::new(),
         tx,
         config.show_all,
         config.sort_field,
@@ -373,35 +599,132 @@ async fn run_event_loop(
         config.column_config,
         config.config_path,
     );
-    let draw_interval = Duration::from_millis(500); // Refresh UI every 500ms
+    state.start_connecting(config.host_ids);
+    state.quit_on_all_disconnected = config.quit_on_all_disconnected;
+    state.show_footer = config.show_footer;
+    state.max_name_width = config.max_name_width;
+    state.title_count = config.title_count;
+    state.time_format = config.time_format;
+    state.timezone = config.timezone;
+    state.show_fleet_totals = config.show_fleet_totals;
+    state.host_separators = config.host_separators;
+    state.show_row_numbers = config.row_numbers;
+    state.show_summary = config.show_summary;
+    state.confirm_destructive = config.confirm_destructive;
+    state.stale_after_secs = config.stale_after_secs;
+    state.show_last_stats = config.show_last_stats;
+    state.search_regex = config.search_regex;
+    state.log_max_lines = config.log_max_lines;
+    state.stats_history_len = config.stats_history;
+    state.show_log_timestamps = config.log_timestamps;
+    state.log_wrap = config.log_wrap;
+    state.show_log_line_numbers = config.log_line_numbers;
+    state.notification_ttl_secs = config.notification_ttl_secs;
+    state.scroll_lines = config.scroll_lines;
+    state.custom_actions = config.custom_actions;
+    let draw_interval = config.draw_interval;
     let mut last_draw = std::time::Instant::now();
+    // Due right away, unless the clock can't go back that far (e.g. just after boot)
+    #[cfg(feature = "control-socket")]
+    let mut last_snapshot = std::time::Instant::now()
+        .checked_sub(draw_interval)
+        .unwrap_or_else(std::time::Instant::now);
+
+    // Styles are pre-allocated to avoid recreation every frame
+    let styles = config.styles;
 
-    // Pre-allocate styles to avoid recreation every frame
-    let styles = UiStyles::with_icon_style(config.icon_style);
+    // Last title sent to the terminal, so it is only rewritten when the count changes
+    let mut last_title: Option<String> = None;
 
     while !state.should_quit {
-        // Wait for events with timeout - handles both throttling and waiting
-        let action = process_events(rx, &mut state, draw_interval).await;
+        // Wait for events until the next draw is due - handles both throttling and
+        // waiting. Timing out on schedule keeps time-based text such as the
+        // "2 hours ago" ages current even when nothing else happens.
+        let next_draw = draw_interval.saturating_sub(last_draw.elapsed());
+        let action = process_events(rx, &mut state, next_draw).await;
 
         // Clean up expired connection errors outside of render
         cleanup_expired_errors(&mut state);
 
+        if config.terminal_title {
+            let title = terminal_title(&state);
+            if last_title.as_ref() != Some(&title) {
+                execute!(io::stdout(), SetTitle(&title))?;
+                last_title = Some(title);
+            }
+        }
+
+        // Refresh the control socket's view at most once per draw interval
+        #[cfg(feature = "control-socket")]
+        if let Some(ref snapshot) = config.control_snapshot
+            && last_snapshot.elapsed() >= draw_interval
+        {
+            cli::control::update_snapshot(snapshot, &state);
+            last_snapshot = std::time::Instant::now();
+        }
+
         match action {
             RenderAction::StartShell(container_key) => {
                 // Handle shell request - this takes over the terminal
                 if let Some(host) = state.connected_hosts.get(&container_key.host_id) {
                     // Pause keyboard worker during shell session
                     keyboard_paused.store(true, Ordering::Relaxed);
+                    if config.mouse {
+                        execute!(io::stdout(), DisableMouseCapture)?;
+                    }
 
                     // Run shell session - this blocks until shell exits
-                    if let Err(e) = host.run_shell_session(&container_key.container_id).await {
-                        tracing::error!("Shell session error: {}", e);
+                    let transcript_dir = config.shell_transcript_dir.as_deref();
+                    match host
+                        .run_shell_session(&container_key.container_id, transcript_dir)
+                        .await
+                    {
+                        Ok(Some(transcript)) => state.show_notification(&format!(
+                            "Shell transcript saved to {}",
+                            transcript.display()
+                        )),
+                        Ok(None) => {}
+                        Err(e) => {
+                            tracing::error!("Shell session error: {}", e);
+                            state.show_error_notification(&e.to_string());
+                        }
                     }
 
                     // Resume keyboard worker
                     keyboard_paused.store(false, Ordering::Relaxed);
+                    if config.mouse {
+                        execute!(io::stdout(), EnableMouseCapture)?;
+                    }
+
+                    // Force full redraw after returning from shell; the shell may
+                    // also have changed the terminal title
+                    last_title = None;
+                    terminal.clear()?;
+                    terminal.draw(|f| {
+                        render_ui(f, &mut state, &styles);
+                    })?;
+                    last_draw = std::time::Instant::now();
+                }
+            }
+            RenderAction::OpenPager(container_key) => {
+                // Like the shell, the pager takes over the terminal until it exits
+                if let Some(host) = state.connected_hosts.get(&container_key.host_id) {
+                    keyboard_paused.store(true, Ordering::Relaxed);
+                    if config.mouse {
+                        execute!(io::stdout(), DisableMouseCapture)?;
+                    }
+
+                    if let Err(e) = host.run_pager_session(&container_key.container_id).await {
+                        tracing::error!("Pager session error: {}", e);
+                        state.show_error_notification(&e.to_string());
+                    }
+
+                    keyboard_paused.store(false, Ordering::Relaxed);
+                    if config.mouse {
+                        execute!(io::stdout(), EnableMouseCapture)?;
+                    }
 
-                    // Force full redraw after returning from shell
+                    // Force full redraw after returning from the pager
                     terminal.clear()?;
                     terminal.draw(|f| {
                         render_ui(f, &mut state, &styles);
@@ -409,6 +732,14 @@ async fn run_event_loop(
                     last_draw = std::time::Instant::now();
                 }
             }
+            RenderAction::CopyToClipboard(text) => {
+                // OSC 52: the terminal puts the text on the clipboard
+                execute!(io::stdout(), CopyToClipboard::to_clipboard_from(text))?;
+                terminal.draw(|f| {
+                    render_ui(f, &mut state, &styles);
+                })?;
+                last_draw = std::time::Instant::now();
+            }
             RenderAction::Render => {
                 // Force draw requested
                 terminal.draw(|f| {
@@ -428,7 +759,12 @@ async fn run_event_loop(
         }
     }
 
-    Ok(())
+    // Clear our title so the terminal falls back to its own
+    if config.terminal_title {
+        execute!(io::stdout(), SetTitle(""))?;
+    }
+
+    Ok((state.exit_message.take(), state.exit_error))
 }
 
 /// Processes all pending events from the event channel
@@ -453,8 +789,11 @@ async fn process_events(
         }
     };
 
-    // If we got a shell request, return immediately
-    if matches!(result, RenderAction::StartShell(_)) {
+    // If we got a shell, pager or clipboard request, return immediately
+    if matches!(
+        result,
+        RenderAction::StartShell(_) | RenderAction::OpenPager(_) | RenderAction::CopyToClipboard(_)
+    ) {
         return result;
     }
 
@@ -462,8 +801,13 @@ async fn process_events(
     while let Ok(event) = rx.try_recv() {
         let action = state.handle_event(event);
 
-        // StartShell takes priority
-        if matches!(action, RenderAction::StartShell(_)) {
+        // StartShell, OpenPager and CopyToClipboard take priority
+        if matches!(
+            action,
+            RenderAction::StartShell(_)
+                | RenderAction::OpenPager(_)
+                | RenderAction::CopyToClipboard(_)
+        ) {
             return action;
         }
 
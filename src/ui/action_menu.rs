@@ -25,15 +25,45 @@ pub fn render_action_menu(f: &mut Frame, state: &mut AppState, styles: &UiStyles
 
     // Create a centered popup (40% width, auto height based on actions)
     let available_actions = ContainerAction::available_for_state(&container.state);
+    let custom_actions = state.custom_actions_for(&container.state);
 
     // If no actions available, don't show the menu
-    if available_actions.is_empty() {
+    if available_actions.is_empty() && custom_actions.is_empty() {
         return;
     }
 
-    // Calculate height: title (3 lines) + actions + footer (2 lines) + padding
-    let popup_height = (available_actions.len() as u16 + 6).min(area.height.saturating_sub(4));
-    let popup_width = 40u16.min(area.width.saturating_sub(4));
+    // Calculate height: title (3 lines) + actions + footer (2 lines) + padding,
+    // plus two lines for the health-check command when the container has one
+    let item_count = available_actions.len() + custom_actions.len();
+    let health_check_lines = if container.health_check.is_some() {
+        2
+    } else {
+        0
+    };
+    let popup_height =
+        (item_count as u16 + 6 + health_check_lines).min(area.height.saturating_sub(4));
+
+    // Name the container and its current state, so actions don't land on the wrong
+    // one after the list reshuffled. The host only matters with several hosts.
+    let title = if state.has_multiple_hosts() {
+        format!(
+            " Actions: {} ({}, {}) ",
+            truncate_string(&container.name, 20),
+            container.state.as_str(),
+            truncate_string(&container_key.host_id, 10)
+        )
+    } else {
+        format!(
+            " Actions: {} ({}) ",
+            truncate_string(&container.name, 20),
+            container.state.as_str()
+        )
+    };
+
+    // Wide enough for the title
+    let popup_width = (title.chars().count() as u16 + 4)
+        .max(40)
+        .min(area.width.saturating_sub(4));
 
     let popup_x = (area.width.saturating_sub(popup_width)) / 2;
     let popup_y = (area.height.saturating_sub(popup_height)) / 2;
@@ -43,13 +73,6 @@ pub fn render_action_menu(f: &mut Frame, state: &mut AppState, styles: &UiStyles
     // Clear the background area first to prevent bleed-through
     f.render_widget(Clear, popup_area);
 
-    // Create the title with container name
-    let title = format!(
-        " Actions: {} ({}) ",
-        truncate_string(&container.name, 20),
-        truncate_string(&container_key.host_id, 10)
-    );
-
     // Render the popup block
     let block = Block::default()
         .title(title)
@@ -69,7 +92,7 @@ pub fn render_action_menu(f: &mut Frame, state: &mut AppState, styles: &UiStyles
     // Render the border first
     f.render_widget(block, popup_area);
 
-    // Create list items from available actions
+    // Create list items from available actions, then the user's custom actions
     let list_items: Vec<ListItem> = available_actions
         .iter()
         .map(|action| {
@@ -77,6 +100,10 @@ pub fn render_action_menu(f: &mut Frame, state: &mut AppState, styles: &UiStyles
             let text = format!(" {}  {}", icon, action.display_name());
             ListItem::new(text).style(Style::default().fg(Color::White))
         })
+        .chain(custom_actions.iter().map(|action| {
+            let text = format!(" {}  {}", styles.icons.custom_action(), action.label);
+            ListItem::new(text).style(Style::default().fg(Color::White))
+        }))
         .collect();
 
     // Create the list widget
@@ -92,6 +119,23 @@ pub fn render_action_menu(f: &mut Frame, state: &mut AppState, styles: &UiStyles
     // Render the list with state
     f.render_stateful_widget(list, inner_area, &mut state.action_menu_state);
 
+    // Show what the health check runs, so a failing check can be understood at a glance
+    if let Some(command) = &container.health_check {
+        let health_area = Rect::new(
+            popup_area.x + 2,
+            popup_area.y + popup_area.height.saturating_sub(4),
+            popup_area.width.saturating_sub(4),
+            1,
+        );
+        let max_len = health_area.width.saturating_sub(8) as usize;
+        let health = ratatui::widgets::Paragraph::new(format!(
+            "Health: {}",
+            truncate_string(command, max_len)
+        ))
+        .style(Style::default().fg(Color::Gray));
+        f.render_widget(health, health_area);
+    }
+
     // Render footer with keybindings
     let footer_y = popup_area.y + popup_area.height.saturating_sub(2);
     let footer_area = Rect::new(
@@ -110,7 +154,7 @@ pub fn render_action_menu(f: &mut Frame, state: &mut AppState, styles: &UiStyles
 }
 
 /// Truncates a string to the specified character length, adding ellipsis if needed
-fn truncate_string(s: &str, max_len: usize) -> String {
+pub(super) fn truncate_string(s: &str, max_len: usize) -> String {
     if s.chars().count() <= max_len {
         s.to_string()
     } else {
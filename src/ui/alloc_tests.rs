@@ -27,7 +27,9 @@ mod tests {
     fn make_container(i: usize, host: &str) -> Container {
         Container {
             id: format!("container{i:08}"),
+            full_id: format!("container{i:08}"),
             name: format!("service-{i}"),
+            image: format!("registry.example.com/service-{i}:1.0"),
             state: ContainerState::Running,
             health: None,
             created: Some(Utc::now() - chrono::Duration::hours(i as i64 + 1)),
@@ -40,11 +42,17 @@ mod tests {
                 network_rx_bytes_per_sec: (i as f64) * 2048.0,
                 disk_read_bytes_per_sec: 0.0,
                 disk_write_bytes_per_sec: 0.0,
+                disk_io_unavailable: false,
             },
             host_id: host.to_string(),
             dozzle_url: None,
             restart_count: Some(i as i64 % 4),
             compose_project: Some(format!("project-{}", i % 3)),
+            size: None,
+            health_check: None,
+            last_stats: None,
+            exit_code: None,
+            ports: Vec::new(),
         }
     }
 
@@ -0,0 +1,68 @@
+use ratatui::{
+    Frame,
+    layout::{Alignment, Rect},
+    style::{Color, Style},
+    text::Line,
+    widgets::{Block, Borders, Clear, Paragraph},
+};
+
+use crate::core::app_state::AppState;
+use crate::core::types::ViewState;
+use crate::ui::action_menu::truncate_string;
+use crate::ui::render::UiStyles;
+
+/// Renders a centered popup asking to confirm a destructive action
+pub fn render_confirm_action(f: &mut Frame, state: &AppState, styles: &UiStyles) {
+    let ViewState::ConfirmAction(ref container_key, action) = state.view_state else {
+        return;
+    };
+
+    let name = state
+        .containers
+        .get(container_key)
+        .map_or(container_key.container_id.as_str(), |c| c.name.as_str());
+    let host = AppState::host_display_name(&state.connected_hosts, &container_key.host_id);
+
+    let question = format!(
+        "{} {} on {}?",
+        action.display_name(),
+        truncate_string(name, 30),
+        truncate_string(host, 20)
+    );
+    let footer = format!("y: {}  n/Esc: Cancel", action.display_name());
+
+    let area = f.area();
+    let popup_width = (question.chars().count() as u16 + 6)
+        .max(40)
+        .min(area.width.saturating_sub(4));
+    let popup_height = 7.min(area.height);
+    let popup_area = Rect::new(
+        area.width.saturating_sub(popup_width) / 2,
+        area.height.saturating_sub(popup_height) / 2,
+        popup_width,
+        popup_height,
+    );
+
+    // Clear the background area first to prevent bleed-through
+    f.render_widget(Clear, popup_area);
+
+    let block = Block::default()
+        .title(" Confirm ")
+        .title_alignment(Alignment::Center)
+        .borders(Borders::ALL)
+        .border_style(styles.high)
+        .style(Style::default().bg(Color::Black));
+
+    let lines = vec![
+        Line::from(""),
+        Line::styled(question, styles.high),
+        Line::styled("This can't be undone.", styles.border),
+        Line::from(""),
+        Line::styled(footer, styles.border),
+    ];
+
+    let paragraph = Paragraph::new(lines)
+        .block(block)
+        .alignment(Alignment::Center);
+    f.render_widget(paragraph, popup_area);
+}
@@ -0,0 +1,100 @@
+use ratatui::{
+    Frame,
+    layout::{Alignment, Constraint, Rect},
+    style::{Color, Style},
+    text::Span,
+    widgets::{Block, Borders, Cell, Paragraph, Row, Table},
+};
+
+use crate::core::app_state::AppState;
+use crate::core::types::HostStatus;
+use crate::ui::render::UiStyles;
+
+/// Renders the startup screen listing each configured host and its connection status.
+/// Shown until the first host has listed its containers.
+pub fn render_connection_progress(f: &mut Frame, area: Rect, state: &AppState, styles: &UiStyles) {
+    // Sort so hosts keep their position as their status changes
+    let mut host_ids: Vec<&String> = state.host_status.keys().collect();
+    host_ids.sort();
+
+    let rows: Vec<Row> = host_ids
+        .iter()
+        .map(|host_id| {
+            let status = match state.host_status[*host_id] {
+                HostStatus::Connecting => Span::styled("Connecting...", styles.medium),
+                HostStatus::Connected => Span::styled("Connected", styles.low),
+                HostStatus::Failed => Span::styled("Failed", styles.high),
+            };
+            // Errors expire from `connection_errors`; the Failed status stays.
+            // Connected hosts show the API version agreed with their daemon.
+            let detail = match state.connection_errors.get(*host_id) {
+                Some((error, _)) => error.clone(),
+                None if state.host_status[*host_id] == HostStatus::Failed => {
+                    "Connection failed".to_string()
+                }
+                None => state
+                    .connected_hosts
+                    .get(*host_id)
+                    .and_then(|host| host.api_version.as_ref())
+                    .map(|version| format!("API {}", version))
+                    .unwrap_or_default(),
+            };
+
+            Row::new(vec![
+                Cell::from(host_id.as_str()),
+                Cell::from(status),
+                Cell::from(Span::styled(detail, Style::default().fg(Color::Gray))),
+            ])
+        })
+        .collect();
+
+    let popup_width = 100u16.min(area.width.saturating_sub(4));
+    // border(2) + rows + footer(2)
+    let popup_height = (rows.len() as u16 + 4).min(area.height.saturating_sub(2));
+
+    let popup_area = Rect::new(
+        area.x + (area.width.saturating_sub(popup_width)) / 2,
+        area.y + (area.height.saturating_sub(popup_height)) / 2,
+        popup_width,
+        popup_height,
+    );
+
+    let block = Block::default()
+        .title(" Connecting to Docker hosts ")
+        .title_alignment(Alignment::Center)
+        .borders(Borders::ALL)
+        .border_style(styles.header);
+
+    f.render_widget(block, popup_area);
+
+    let table_area = Rect::new(
+        popup_area.x + 2,
+        popup_area.y + 1,
+        popup_area.width.saturating_sub(4),
+        popup_area.height.saturating_sub(4),
+    );
+
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Length(24),
+            Constraint::Length(14),
+            Constraint::Fill(1),
+        ],
+    );
+
+    f.render_widget(table, table_area);
+
+    let footer_area = Rect::new(
+        popup_area.x + 2,
+        popup_area.y + popup_area.height.saturating_sub(2),
+        popup_area.width.saturating_sub(4),
+        1,
+    );
+
+    let footer = Paragraph::new("q: Quit")
+        .style(Style::default().fg(Color::Gray))
+        .alignment(Alignment::Center);
+
+    f.render_widget(footer, footer_area);
+}
@@ -1,16 +1,36 @@
 use crate::core::app_state::AppState;
-use crate::core::types::{Column, Container, ContainerState, HealthStatus, SortState};
-use crate::ui::formatters::{format_bytes_per_sec, format_time_elapsed, write_bytes};
+use crate::core::types::{
+    Column, Container, ContainerState, ContainerStats, DisplayTimezone, HealthStatus, HostStatus,
+    SortState, StateFilter, Thresholds, TimeFormat, TitleCount,
+};
+use crate::ui::action_menu::truncate_string;
+use crate::ui::formatters::{format_bytes_per_sec, format_ports, format_time_elapsed, write_bytes};
 use crate::ui::render::UiStyles;
 use ratatui::{
     Frame,
-    layout::Constraint,
-    style::{Color, Style},
-    widgets::{Block, Borders, Cell, Row, Table},
+    layout::{Alignment, Constraint, Layout},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{
+        Block, Borders, Cell, Paragraph, Row, Scrollbar, ScrollbarOrientation, ScrollbarState,
+        Table, TableState,
+    },
 };
 
 const VERSION: &str = env!("CARGO_PKG_VERSION");
 
+/// Shortest list area that still shows the summary line (leaves at least 3 rows)
+const MIN_SUMMARY_HEIGHT: u16 = 9;
+
+/// Narrowest list area that shows the Image column, when it is enabled
+const MIN_IMAGE_COLUMN_WIDTH: u16 = 160;
+
+/// Width of the Image column; longer references are cut with an ellipsis
+const IMAGE_COLUMN_WIDTH: usize = 30;
+
+/// Restart counts above this are highlighted in the Restarts column
+const HIGH_RESTART_COUNT: i64 = 5;
+
 /// Renders the container list view
 pub fn render_container_list(
     f: &mut Frame,
@@ -22,65 +42,304 @@ pub fn render_container_list(
     let width = area.width;
     let show_progress_bars = width >= 128;
 
+    // The summary line replaces the table's bottom padding row, and the table
+    // gives up its last row in exchange so the search bar still has a blank line
+    let summary_area = (app_state.show_summary && area.height >= MIN_SUMMARY_HEIGHT).then(|| {
+        ratatui::layout::Rect::new(
+            area.x + 2,
+            area.y + area.height - 2,
+            area.width.saturating_sub(4),
+            1,
+        )
+    });
+    let area = if summary_area.is_some() {
+        ratatui::layout::Rect {
+            height: area.height - 1,
+            ..area
+        }
+    } else {
+        area
+    };
+
     // Track visible data rows for page up/down navigation.
-    // Layout consumes: proportional(1) block padding (top+bottom = 2 rows) and
-    // the header row plus its bottom_margin(1) = 2 rows.
-    app_state.last_list_viewport_height = (area.height as usize).saturating_sub(4).max(1);
+    // Layout consumes: the title row, proportional(1) block padding (top+bottom = 2 rows)
+    // and the header row plus its bottom_margin(1) = 2 rows.
+    app_state.last_list_viewport_height = (area.height as usize).saturating_sub(5).max(1);
 
     app_state.sort_containers();
 
     // Refresh the reusable visible-columns buffer in place (no per-frame alloc),
     // then borrow it for the rest of the render.
     app_state.refresh_visible_columns();
+    // Image references are long, so they only get room on wide terminals
+    if width < MIN_IMAGE_COLUMN_WIDTH {
+        app_state
+            .visible_columns_cache
+            .retain(|col| *col != Column::Image);
+    }
     let visible_columns = &app_state.visible_columns_cache;
 
-    let rows: Vec<Row> = app_state
-        .sorted_container_keys
-        .iter()
-        .filter_map(|key| app_state.containers.get(key))
-        .map(|c| {
-            create_container_row(
+    // Sorted positions where a host group starts; each gets a separator row above it
+    let group_starts = host_group_starts(app_state, show_host_column);
+    let show_row_numbers = app_state.show_row_numbers;
+    let absolute_times =
+        (app_state.time_format == TimeFormat::Absolute).then_some(app_state.timezone);
+
+    // Hosts that went quiet: their rows are dimmed and the title names them
+    let stale_hosts: Vec<&str> = app_state
+        .host_last_update
+        .keys()
+        .map(String::as_str)
+        .filter(|host_id| app_state.is_host_stale(host_id))
+        .collect();
+
+    let mut rows: Vec<Row> = Vec::with_capacity(app_state.sorted_container_keys.len());
+    for (i, key) in app_state.sorted_container_keys.iter().enumerate() {
+        if group_starts.binary_search(&i).is_ok() {
+            rows.push(create_host_separator_row(
+                AppState::host_display_name(&app_state.connected_hosts, &key.host_id),
+                visible_columns,
+                show_row_numbers,
+                area.width,
+                styles,
+            ));
+        }
+        if let Some(c) = app_state.containers.get(key) {
+            let row = create_container_row(
                 c,
+                show_row_numbers.then_some(i + 1),
                 styles,
                 visible_columns,
-                show_host_column,
+                show_host_column
+                    .then(|| AppState::host_display_name(&app_state.connected_hosts, &key.host_id)),
                 show_progress_bars,
-            )
-        })
-        .collect();
+                absolute_times,
+            );
+            rows.push(if stale_hosts.contains(&key.host_id.as_str()) {
+                row.style(Style::default().add_modifier(Modifier::DIM))
+            } else {
+                row
+            });
+        }
+    }
 
     let header = create_header_row(
         styles,
         visible_columns,
         show_host_column,
+        show_row_numbers,
         app_state.sort_state,
     );
-    let table = create_table(
-        rows,
-        header,
-        app_state.sorted_container_keys.len(),
-        styles,
+    let mut constraints = column_constraints(
         visible_columns,
         show_host_column,
         show_progress_bars,
+        app_state.show_last_stats,
+        app_state.max_name_width,
     );
+    if show_row_numbers {
+        let digits = app_state.sorted_container_keys.len().max(1).ilog10() + 1;
+        constraints.insert(0, Constraint::Length(digits as u16));
+    }
+
+    // Remember where each header cell lands so clicks on it can sort by that column.
+    // Mirrors the table layout: the title row plus proportional(1) padding put the
+    // header 2 rows down and 2 columns in, and cells are separated by 1 space.
+    let header_area =
+        ratatui::layout::Rect::new(area.x + 2, area.y + 2, area.width.saturating_sub(4), 1);
+    let cells = Layout::horizontal(&constraints)
+        .spacing(1)
+        .split(header_area);
+    app_state.header_columns.clear();
+    app_state.header_columns.extend(
+        cells
+            .iter()
+            .skip(usize::from(show_row_numbers))
+            .copied()
+            .zip(
+                visible_columns
+                    .iter()
+                    .copied()
+                    .filter(|col| *col != Column::Host || show_host_column),
+            ),
+    );
+
+    let row_count = rows.len();
+    let mut title = format!(
+        "dtop v{} - {} ('?' for help, 'q' to quit)",
+        VERSION,
+        title_counts(app_state)
+    );
+    if !stale_hosts.is_empty() {
+        let mut names: Vec<&str> = stale_hosts
+            .iter()
+            .map(|host_id| AppState::host_display_name(&app_state.connected_hosts, host_id))
+            .collect();
+        names.sort_unstable();
+        title.push_str(" - stale: ");
+        title.push_str(&names.join(", "));
+    }
+    // The host strip shares the title row (inside the 2-column side padding)
+    let strip_width = (area.width as usize).saturating_sub(4 + title.chars().count() + 1);
+    let host_status = host_status_line(app_state, styles, strip_width);
+    let table = create_table(rows, header, title, host_status, styles, constraints);
+
+    // Selection and offsets count containers; separator rows shift where they land
+    let selected_row = app_state
+        .table_state
+        .selected()
+        .map(|i| i + group_starts.partition_point(|&start| start <= i));
+    let offset_row = |offset: usize| offset + group_starts.partition_point(|&start| start < offset);
+    let offset_container = |row: usize| {
+        row - group_starts
+            .iter()
+            .enumerate()
+            .take_while(|&(k, &start)| start + k < row)
+            .count()
+    };
+
+    // While wheel-scrolled, render at the wheel's offset and only highlight the
+    // selection if it is on screen (ratatui would otherwise scroll back to it)
+    let viewport = app_state.last_list_viewport_height;
+    let offset = if let Some(offset) = app_state.list_scroll {
+        let offset = offset_row(offset).min(row_count.saturating_sub(viewport));
+        app_state.list_scroll = Some(offset_container(offset));
+        let selected = selected_row.filter(|i| (offset..offset + viewport).contains(i));
+        let mut scrolled = TableState::new()
+            .with_offset(offset)
+            .with_selected(selected);
+        f.render_stateful_widget(table, area, &mut scrolled);
+        offset
+    } else {
+        let mut state = TableState::new()
+            .with_offset(offset_row(app_state.table_state.offset()))
+            .with_selected(selected_row);
+        f.render_stateful_widget(table, area, &mut state);
+        // Keep the offset ratatui scrolled to, so the viewport stays put next frame
+        *app_state.table_state.offset_mut() = offset_container(state.offset());
+        state.offset()
+    };
+
+    // Show how far the list extends beyond the screen, alongside the rows
+    if row_count > viewport {
+        let rows_area = ratatui::layout::Rect::new(area.x, area.y + 4, area.width, viewport as u16);
+        let mut scrollbar_state = ScrollbarState::new(row_count.saturating_sub(viewport))
+            .viewport_content_length(viewport)
+            .position(offset);
+        let scrollbar = Scrollbar::default()
+            .orientation(ScrollbarOrientation::VerticalRight)
+            .style(styles.border);
+        f.render_stateful_widget(scrollbar, rows_area, &mut scrollbar_state);
+    }
+
+    if let Some(summary_area) = summary_area {
+        f.render_widget(
+            Paragraph::new(summary_line(app_state)).style(Style::default().fg(Color::Gray)),
+            summary_area,
+        );
+    }
+}
+
+/// Builds the summary line: CPU, memory and network summed over the running
+/// containers in the list (so it follows the search filter and show-all)
+fn summary_line(app_state: &AppState) -> String {
+    let (count, cpu, memory, tx, rx) = app_state
+        .sorted_container_keys
+        .iter()
+        .filter_map(|key| app_state.containers.get(key))
+        .filter(|c| c.state == ContainerState::Running)
+        .fold(
+            (0usize, 0.0, 0.0, 0.0, 0.0),
+            |(count, cpu, memory, tx, rx), c| {
+                (
+                    count + 1,
+                    cpu + c.stats.cpu,
+                    memory + c.stats.memory,
+                    tx + c.stats.network_tx_bytes_per_sec,
+                    rx + c.stats.network_rx_bytes_per_sec,
+                )
+            },
+        );
+
+    format!(
+        "Total ({} running): CPU {:.1}%  Mem {:.1}%  Net TX {}  RX {}",
+        count,
+        cpu,
+        memory,
+        format_bytes_per_sec(tx),
+        format_bytes_per_sec(rx)
+    )
+}
+
+/// Returns the sorted positions where a new host's containers begin, or nothing
+/// when host separators are off or only one host is shown
+fn host_group_starts(app_state: &AppState, show_host_column: bool) -> Vec<usize> {
+    if !app_state.host_separators || !show_host_column {
+        return Vec::new();
+    }
+    let keys = &app_state.sorted_container_keys;
+    (0..keys.len())
+        .filter(|&i| i == 0 || keys[i].host_id != keys[i - 1].host_id)
+        .collect()
+}
 
-    f.render_stateful_widget(table, area, &mut app_state.table_state);
+/// Creates the separator row shown above a host's group: a rule across every
+/// column, with the host name in the Host column (or the first, if Host is hidden)
+fn create_host_separator_row<'a>(
+    host_id: &str,
+    visible_columns: &[Column],
+    show_row_numbers: bool,
+    width: u16,
+    styles: &UiStyles,
+) -> Row<'a> {
+    let gutter = usize::from(show_row_numbers);
+    let label_index = visible_columns
+        .iter()
+        .position(|col| *col == Column::Host)
+        .map_or(0, |i| i + gutter);
+    // Cells truncate their content, so one full-width rule covers any column
+    let rule = "─".repeat(width as usize);
+    let cells: Vec<Cell> = (0..visible_columns.len() + gutter)
+        .map(|i| {
+            if i == label_index {
+                Cell::from(Line::from(vec![
+                    Span::styled(host_id.to_string(), styles.header),
+                    Span::raw(" "),
+                    Span::raw(rule.clone()),
+                ]))
+            } else {
+                Cell::from(rule.clone())
+            }
+        })
+        .collect();
+    Row::new(cells).style(Style::default().fg(Color::DarkGray))
 }
 
-/// Creates a table row for a single container
+/// Creates a table row for a single container, led by its row number if given.
+/// The Host column is shown when `host_name` is given (see
+/// [`AppState::host_display_name`]). With `absolute_times` the Created column
+/// shows the date and time in that zone.
 fn create_container_row<'a>(
     container: &'a Container,
+    row_number: Option<usize>,
     styles: &'a UiStyles,
     visible_columns: &[Column],
-    show_host_column: bool,
+    host_name: Option<&'a str>,
     show_progress_bars: bool,
+    absolute_times: Option<DisplayTimezone>,
 ) -> Row<'a> {
     let is_running = container.state == ContainerState::Running;
 
-    let cells: Vec<Cell> = visible_columns
+    let mut cells: Vec<Cell> = Vec::new();
+    if let Some(n) = row_number {
+        cells.push(
+            Cell::from(Line::from(n.to_string()).alignment(Alignment::Right))
+                .style(Style::default().fg(Color::DarkGray)),
+        );
+    }
+    let column_cells = visible_columns
         .iter()
-        .filter(|col| **col != Column::Host || show_host_column)
+        .filter(|col| **col != Column::Host || host_name.is_some())
         .map(|col| match col {
             Column::Id => Cell::from(container.id.as_str()),
             Column::Status => {
@@ -88,9 +347,23 @@ fn create_container_row<'a>(
                     get_status_icon(&container.state, &container.health, styles);
                 Cell::from(icon).style(icon_style)
             }
-            Column::Name => Cell::from(container.name.as_str()),
-            Column::Host => Cell::from(container.host_id.as_str()),
+            Column::Name => match get_status_hint(container) {
+                // Make transitional states readable without relying on the icon alone
+                Some(hint) => Cell::from(Line::from(vec![
+                    Span::raw(container.name.as_str()),
+                    Span::styled(hint, styles.medium),
+                ])),
+                None => Cell::from(container.name.as_str()),
+            },
+            Column::Host => Cell::from(host_name.unwrap_or_default()),
             Column::Compose => Cell::from(container.compose_project.as_deref().unwrap_or("")),
+            Column::Image => {
+                if container.image.chars().count() <= IMAGE_COLUMN_WIDTH {
+                    Cell::from(container.image.as_str())
+                } else {
+                    Cell::from(truncate_string(&container.image, IMAGE_COLUMN_WIDTH))
+                }
+            }
             Column::Cpu => {
                 if is_running {
                     let display = if show_progress_bars {
@@ -98,9 +371,15 @@ fn create_container_row<'a>(
                     } else {
                         format!("{:5.1}%", container.stats.cpu)
                     };
-                    Cell::from(display).style(get_percentage_style(container.stats.cpu, styles))
+                    Cell::from(display).style(get_percentage_style(
+                        container.stats.cpu,
+                        styles.cpu_thresholds,
+                        styles,
+                    ))
+                } else if container.state == ContainerState::Paused {
+                    Cell::from(Span::styled("paused", styles.medium))
                 } else {
-                    Cell::from("")
+                    last_stat_cell(container, |s| format!("{:5.1}%", s.cpu))
                 }
             }
             Column::Memory => {
@@ -115,9 +394,13 @@ fn create_container_row<'a>(
                     } else {
                         format!("{:5.1}%", container.stats.memory)
                     };
-                    Cell::from(display).style(get_percentage_style(container.stats.memory, styles))
+                    Cell::from(display).style(get_percentage_style(
+                        container.stats.memory,
+                        styles.memory_thresholds,
+                        styles,
+                    ))
                 } else {
-                    Cell::from("")
+                    last_stat_cell(container, |s| format!("{:5.1}%", s.memory))
                 }
             }
             Column::NetTx => {
@@ -126,7 +409,9 @@ fn create_container_row<'a>(
                         container.stats.network_tx_bytes_per_sec,
                     ))
                 } else {
-                    Cell::from("")
+                    last_stat_cell(container, |s| {
+                        format_bytes_per_sec(s.network_tx_bytes_per_sec)
+                    })
                 }
             }
             Column::NetRx => {
@@ -135,11 +420,26 @@ fn create_container_row<'a>(
                         container.stats.network_rx_bytes_per_sec,
                     ))
                 } else {
-                    Cell::from("")
+                    last_stat_cell(container, |s| {
+                        format_bytes_per_sec(s.network_rx_bytes_per_sec)
+                    })
                 }
             }
-            Column::DiskRead => {
+            Column::NetIo => {
                 if is_running {
+                    Cell::from(format_bytes_per_sec(
+                        container.stats.network_bytes_per_sec(),
+                    ))
+                } else {
+                    last_stat_cell(container, |s| {
+                        format_bytes_per_sec(s.network_bytes_per_sec())
+                    })
+                }
+            }
+            Column::DiskRead => {
+                if is_running && container.stats.disk_io_unavailable {
+                    Cell::from("N/A")
+                } else if is_running {
                     Cell::from(format_bytes_per_sec(
                         container.stats.disk_read_bytes_per_sec,
                     ))
@@ -148,7 +448,9 @@ fn create_container_row<'a>(
                 }
             }
             Column::DiskWrite => {
-                if is_running {
+                if is_running && container.stats.disk_io_unavailable {
+                    Cell::from("N/A")
+                } else if is_running {
                     Cell::from(format_bytes_per_sec(
                         container.stats.disk_write_bytes_per_sec,
                     ))
@@ -156,21 +458,44 @@ fn create_container_row<'a>(
                     Cell::from("")
                 }
             }
+            Column::DiskIo => {
+                if is_running && container.stats.disk_io_unavailable {
+                    Cell::from("N/A")
+                } else if is_running {
+                    Cell::from(format_bytes_per_sec(
+                        container.stats.disk_io_bytes_per_sec(),
+                    ))
+                } else {
+                    Cell::from("")
+                }
+            }
             Column::Uptime => {
                 if is_running {
-                    Cell::from(format_time_elapsed(container.created.as_ref()))
+                    Cell::from(match (absolute_times, container.created.as_ref()) {
+                        (Some(timezone), Some(created)) => timezone.format(created, "%b %d %H:%M"),
+                        _ => format_time_elapsed(container.created.as_ref()),
+                    })
                 } else {
-                    Cell::from("N/A")
+                    exit_code_cell(container, styles)
                 }
             }
-            Column::Restarts => Cell::from(
-                container
-                    .restart_count
-                    .map(|c| c.to_string())
-                    .unwrap_or_default(),
-            ),
-        })
-        .collect();
+            Column::Restarts => restart_count_cell(container, styles),
+            Column::Size => match container.size {
+                // Same layout as `docker ps --size`: writable layer (total)
+                Some(size) => {
+                    let mut display = String::new();
+                    write_bytes(&mut display, size.rw);
+                    display.push_str(" (");
+                    write_bytes(&mut display, size.root_fs);
+                    display.push(')');
+                    Cell::from(display)
+                }
+                // Not collected (config: `container_size`) or not reported
+                None => Cell::from("N/A"),
+            },
+            Column::Ports => Cell::from(format_ports(&container.ports)),
+        });
+    cells.extend(column_cells);
 
     Row::new(cells)
 }
@@ -218,6 +543,19 @@ fn create_memory_progress_bar(percentage: f64, used: u64, limit: u64, width: usi
     result
 }
 
+/// Returns a short text hint for containers that are up but not yet ready,
+/// shown next to the name (e.g. while a health check is still starting during
+/// a rolling restart).
+fn get_status_hint(container: &Container) -> Option<&'static str> {
+    if container.state == ContainerState::Restarting {
+        return Some(" (restarting)");
+    }
+    if container.health == Some(HealthStatus::Starting) {
+        return Some(" (starting)");
+    }
+    None
+}
+
 /// Returns the status icon and color based on container health (if available) or state
 fn get_status_icon<'a>(
     state: &ContainerState,
@@ -251,29 +589,83 @@ fn get_status_icon<'a>(
 }
 
 /// Returns the appropriate style based on percentage value
-fn get_percentage_style(value: f64, styles: &UiStyles) -> Style {
-    if value > 80.0 {
+fn get_percentage_style(value: f64, thresholds: Thresholds, styles: &UiStyles) -> Style {
+    if value > thresholds.critical {
         styles.high
-    } else if value > 50.0 {
+    } else if value > thresholds.warn {
         styles.medium
     } else {
         styles.low
     }
 }
 
+/// Shows a stopped container's value from when it stopped, dimmed and marked
+/// "last:", or nothing if its stats weren't kept (config: `show_last_stats`)
+fn last_stat_cell<'a>(
+    container: &Container,
+    value: impl Fn(&ContainerStats) -> String,
+) -> Cell<'a> {
+    match &container.last_stats {
+        Some(stats) => {
+            Cell::from(format!("last:{}", value(stats))).style(Style::default().fg(Color::DarkGray))
+        }
+        None => Cell::from(""),
+    }
+}
+
+/// Shows why a stopped container stopped, e.g. "Exited (137)"; non-zero codes
+/// are highlighted. "N/A" when the exit code isn't known.
+fn exit_code_cell<'a>(container: &Container, styles: &UiStyles) -> Cell<'a> {
+    match container.exit_code {
+        Some(code) if container.state == ContainerState::Exited => {
+            let cell = Cell::from(format!("Exited ({})", code));
+            if code == 0 {
+                cell
+            } else {
+                cell.style(styles.high)
+            }
+        }
+        _ => Cell::from("N/A"),
+    }
+}
+
+/// Shows how often Docker restarted the container; a flapping container
+/// (more than [`HIGH_RESTART_COUNT`] restarts) is highlighted. "N/A" when the
+/// daemon didn't report it.
+fn restart_count_cell<'a>(container: &Container, styles: &UiStyles) -> Cell<'a> {
+    match container.restart_count {
+        Some(count) if count > HIGH_RESTART_COUNT => {
+            Cell::from(count.to_string()).style(styles.high)
+        }
+        Some(count) => Cell::from(count.to_string()),
+        None => Cell::from("N/A"),
+    }
+}
+
 /// Creates the table header row
 fn create_header_row(
     styles: &UiStyles,
     visible_columns: &[Column],
     show_host_column: bool,
+    show_row_numbers: bool,
     sort_state: SortState,
 ) -> Row<'static> {
     use std::borrow::Cow;
 
     let sort_symbol = sort_state.direction.symbol();
     let sort_field = sort_state.field;
+    // Sorting by a hidden combined column (e.g. Net I/O) marks the columns it adds up
+    let sort_components = if visible_columns.contains(&sort_field) {
+        &[]
+    } else {
+        sort_field.components()
+    };
 
-    let headers: Vec<Cow<'static, str>> = visible_columns
+    let mut headers: Vec<Cow<'static, str>> = Vec::new();
+    if show_row_numbers {
+        headers.push(Cow::Borrowed("#"));
+    }
+    let column_headers = visible_columns
         .iter()
         .filter(|col| **col != Column::Host || show_host_column)
         .map(|col| {
@@ -283,71 +675,190 @@ fn create_header_row(
                 Column::Id => "ID",
                 Column::Host => "Host",
                 Column::Compose => "Compose",
+                Column::Image => "Image",
                 Column::Cpu => "CPU %",
                 Column::Memory => "Memory %",
                 Column::NetTx => "Net TX",
                 Column::NetRx => "Net RX",
+                Column::NetIo => "Net I/O",
                 Column::DiskRead => "Disk R",
                 Column::DiskWrite => "Disk W",
+                Column::DiskIo => "Disk I/O",
                 Column::Uptime => "Created",
                 Column::Restarts => "Restarts",
+                Column::Size => "Size",
+                Column::Ports => "Ports",
             };
-            if *col == sort_field && !base_label.is_empty() {
+            let is_sorted = *col == sort_field || sort_components.contains(col);
+            if is_sorted && !base_label.is_empty() {
                 Cow::Owned(format!("{} {}", base_label, sort_symbol))
             } else {
                 Cow::Borrowed(base_label)
             }
-        })
-        .collect();
+        });
+    headers.extend(column_headers);
 
     Row::new(headers).style(styles.header).bottom_margin(1)
 }
 
-/// Creates the complete table widget
-fn create_table<'a>(
-    rows: Vec<Row<'a>>,
-    header: Row<'static>,
-    container_count: usize,
-    styles: &UiStyles,
+/// Computes the width constraint of each visible column
+fn column_constraints(
     visible_columns: &[Column],
     show_host_column: bool,
     show_progress_bars: bool,
-) -> Table<'a> {
-    let cpu_width = if show_progress_bars { 28 } else { 7 };
-    let mem_width = if show_progress_bars { 33 } else { 7 };
+    show_last_stats: bool,
+    max_name_width: Option<u16>,
+) -> Vec<Constraint> {
+    // Last-known stats need room for their "last:" prefix
+    let (cpu_width, mem_width) = match (show_progress_bars, show_last_stats) {
+        (true, _) => (28, 33),
+        (false, true) => (11, 11),
+        (false, false) => (7, 7),
+    };
+    let net_width = if show_last_stats { 14 } else { 12 };
 
-    let constraints: Vec<Constraint> = visible_columns
+    visible_columns
         .iter()
         .filter(|col| **col != Column::Host || show_host_column)
         .map(|col| match col {
             Column::Id => Constraint::Length(12),
             Column::Status => Constraint::Length(1),
-            Column::Name => Constraint::Min(8),
+            // Capped names leave spare width unused instead of pushing stats to the far right
+            Column::Name => match max_name_width {
+                Some(width) => Constraint::Max(width.max(8)),
+                None => Constraint::Min(8),
+            },
             Column::Host => Constraint::Length(20),
             Column::Compose => Constraint::Length(20),
+            Column::Image => Constraint::Length(IMAGE_COLUMN_WIDTH as u16),
             Column::Cpu => Constraint::Length(cpu_width),
             Column::Memory => Constraint::Length(mem_width),
-            Column::NetTx => Constraint::Length(12),
-            Column::NetRx => Constraint::Length(12),
+            Column::NetTx => Constraint::Length(net_width),
+            Column::NetRx => Constraint::Length(net_width),
+            Column::NetIo => Constraint::Length(net_width),
             Column::DiskRead => Constraint::Length(12),
             Column::DiskWrite => Constraint::Length(12),
+            Column::DiskIo => Constraint::Length(12),
             Column::Uptime => Constraint::Length(15),
             Column::Restarts => Constraint::Length(10),
+            Column::Size => Constraint::Length(18),
+            Column::Ports => Constraint::Length(20),
         })
-        .collect();
+        .collect()
+}
+
+/// Builds the title's container counts, e.g. "12 running / 15 total".
+/// Counts every known container, so they don't change with search or show-all.
+fn title_counts(app_state: &AppState) -> String {
+    let total = host_containers(app_state).count();
+    let running = host_containers(app_state)
+        .filter(|c| c.state == ContainerState::Running)
+        .count();
+
+    let mut counts = match app_state.title_count {
+        TitleCount::Running => format!("{} running / {} total", running, total),
+        TitleCount::Total => format!("{} total / {} running", total, running),
+    };
+    if app_state.state_filter != StateFilter::All {
+        counts = format!("{} - only {}", counts, app_state.state_filter.label());
+    }
+    if let Some(host_id) = &app_state.host_filter {
+        counts = format!(
+            "{} - host {}",
+            counts,
+            AppState::host_display_name(&app_state.connected_hosts, host_id)
+        );
+    }
+
+    if app_state.show_fleet_totals {
+        format!("{} - {}", counts, fleet_totals(app_state))
+    } else {
+        counts
+    }
+}
+
+/// Builds the fleet-wide usage summary, e.g. "fleet: 340% CPU, 62% mem avg".
+/// Summed over the running containers of every host, ignoring search and show-all.
+fn fleet_totals(app_state: &AppState) -> String {
+    let (count, cpu, memory) = host_containers(app_state)
+        .filter(|c| c.state == ContainerState::Running)
+        .fold((0usize, 0.0, 0.0), |(count, cpu, memory), c| {
+            (count + 1, cpu + c.stats.cpu, memory + c.stats.memory)
+        });
+    let memory_avg = if count > 0 {
+        memory / count as f64
+    } else {
+        0.0
+    };
+
+    format!("fleet: {:.0}% CPU, {:.0}% mem avg", cpu, memory_avg)
+}
+
+/// Iterates the containers of the host chosen in the host picker, or of every host
+fn host_containers(app_state: &AppState) -> impl Iterator<Item = &Container> {
+    app_state.containers.values().filter(|c| {
+        app_state
+            .host_filter
+            .as_ref()
+            .is_none_or(|host_id| *host_id == c.host_id)
+    })
+}
+
+/// Builds the right-aligned host strip of the title, e.g. "● local ● prod", with
+/// each dot colored by the host's connection status. Only shown with several hosts,
+/// and left out when it would not fit in `max_width` next to the title.
+fn host_status_line(
+    app_state: &AppState,
+    styles: &UiStyles,
+    max_width: usize,
+) -> Option<Line<'static>> {
+    if app_state.host_status.len() < 2 {
+        return None;
+    }
+
+    let mut hosts: Vec<_> = app_state.host_status.iter().collect();
+    hosts.sort_by(|a, b| a.0.cmp(b.0));
+
+    let spans = hosts
+        .into_iter()
+        .flat_map(|(host_id, status)| {
+            let dot_style = match status {
+                HostStatus::Connected => styles.low,
+                HostStatus::Connecting => styles.medium,
+                HostStatus::Failed => styles.high,
+            };
+            [
+                Span::styled("● ", dot_style),
+                Span::raw(format!("{} ", host_id)),
+            ]
+        })
+        .collect::<Vec<_>>();
+
+    let line = Line::from(spans).right_aligned();
+    (line.width() <= max_width).then_some(line)
+}
+
+/// Creates the complete table widget
+fn create_table<'a>(
+    rows: Vec<Row<'a>>,
+    header: Row<'static>,
+    title: String,
+    host_status: Option<Line<'static>>,
+    styles: &UiStyles,
+    constraints: Vec<Constraint>,
+) -> Table<'a> {
+    let mut block = Block::default()
+        .borders(Borders::NONE)
+        .padding(ratatui::widgets::Padding::proportional(1))
+        .title(title)
+        .style(styles.border);
+    if let Some(host_status) = host_status {
+        block = block.title(host_status);
+    }
 
     Table::new(rows, constraints)
         .header(header)
-        .block(
-            Block::default()
-                .borders(Borders::NONE)
-                .padding(ratatui::widgets::Padding::proportional(1))
-                .title(format!(
-                    "dtop v{} - {} containers ('?' for help, 'q' to quit)",
-                    VERSION, container_count
-                ))
-                .style(styles.border),
-        )
+        .block(block)
         .row_highlight_style(styles.selected)
 }
 
@@ -355,6 +866,21 @@ fn create_table<'a>(
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_column_constraints_name_width() {
+        let columns = [Column::Name, Column::Cpu];
+
+        let unlimited = column_constraints(&columns, false, false, false, None);
+        assert_eq!(unlimited[0], Constraint::Min(8));
+
+        let capped = column_constraints(&columns, false, false, false, Some(30));
+        assert_eq!(capped[0], Constraint::Max(30));
+
+        // Never narrower than the unlimited minimum
+        let tiny = column_constraints(&columns, false, false, false, Some(3));
+        assert_eq!(tiny[0], Constraint::Max(8));
+    }
+
     #[test]
     fn test_create_memory_progress_bar_format() {
         let bar = create_memory_progress_bar(50.0, 512 * 1024 * 1024, 1024 * 1024 * 1024, 20);
@@ -383,27 +909,117 @@ mod tests {
         assert!(bar.starts_with("████████████████████")); // Still fully filled
     }
 
+    #[test]
+    fn test_status_hint_for_transitional_states() {
+        let mut container = Container {
+            id: "abc123def456".to_string(),
+            full_id: "abc123def456".to_string(),
+            name: "web".to_string(),
+            image: "nginx:latest".to_string(),
+            state: ContainerState::Running,
+            health: Some(HealthStatus::Starting),
+            created: None,
+            stats: Default::default(),
+            host_id: "local".to_string(),
+            dozzle_url: None,
+            restart_count: None,
+            compose_project: None,
+            size: None,
+            health_check: None,
+            last_stats: None,
+            exit_code: None,
+            ports: Vec::new(),
+        };
+        assert_eq!(get_status_hint(&container), Some(" (starting)"));
+
+        container.health = Some(HealthStatus::Healthy);
+        assert_eq!(get_status_hint(&container), None);
+
+        container.health = None;
+        assert_eq!(get_status_hint(&container), None);
+
+        container.state = ContainerState::Restarting;
+        assert_eq!(get_status_hint(&container), Some(" (restarting)"));
+    }
+
     #[test]
     fn test_percentage_style_thresholds() {
         let styles = UiStyles::default();
 
         // Test low threshold (green)
-        let low_style = get_percentage_style(30.0, &styles);
+        let low_style = get_percentage_style(30.0, Thresholds::default(), &styles);
         assert_eq!(low_style.fg, Some(Color::Green));
 
         // Test medium threshold (yellow)
-        let medium_style = get_percentage_style(65.0, &styles);
+        let medium_style = get_percentage_style(65.0, Thresholds::default(), &styles);
         assert_eq!(medium_style.fg, Some(Color::Yellow));
 
         // Test high threshold (red)
-        let high_style = get_percentage_style(85.0, &styles);
+        let high_style = get_percentage_style(85.0, Thresholds::default(), &styles);
         assert_eq!(high_style.fg, Some(Color::Red));
 
         // Test boundary cases
-        assert_eq!(get_percentage_style(50.0, &styles).fg, Some(Color::Green));
-        assert_eq!(get_percentage_style(50.1, &styles).fg, Some(Color::Yellow));
-        assert_eq!(get_percentage_style(80.0, &styles).fg, Some(Color::Yellow));
-        assert_eq!(get_percentage_style(80.1, &styles).fg, Some(Color::Red));
+        assert_eq!(
+            get_percentage_style(50.0, Thresholds::default(), &styles).fg,
+            Some(Color::Green)
+        );
+        assert_eq!(
+            get_percentage_style(50.1, Thresholds::default(), &styles).fg,
+            Some(Color::Yellow)
+        );
+        assert_eq!(
+            get_percentage_style(80.0, Thresholds::default(), &styles).fg,
+            Some(Color::Yellow)
+        );
+        assert_eq!(
+            get_percentage_style(80.1, Thresholds::default(), &styles).fg,
+            Some(Color::Red)
+        );
+    }
+
+    #[test]
+    fn test_percentage_style_custom_thresholds() {
+        let styles = UiStyles::default();
+        let thresholds = Thresholds {
+            warn: 30.0,
+            critical: 60.0,
+        };
+
+        // Boundaries are exclusive, as with the defaults
+        assert_eq!(
+            get_percentage_style(30.0, thresholds, &styles).fg,
+            Some(Color::Green)
+        );
+        assert_eq!(
+            get_percentage_style(30.1, thresholds, &styles).fg,
+            Some(Color::Yellow)
+        );
+        assert_eq!(
+            get_percentage_style(60.0, thresholds, &styles).fg,
+            Some(Color::Yellow)
+        );
+        assert_eq!(
+            get_percentage_style(60.1, thresholds, &styles).fg,
+            Some(Color::Red)
+        );
+
+        // warn must be below critical
+        assert!(thresholds.validate("memory_thresholds").is_ok());
+        let err = Thresholds {
+            warn: 80.0,
+            critical: 80.0,
+        }
+        .validate("memory_thresholds")
+        .unwrap_err();
+        assert!(err.contains("memory_thresholds"));
+        assert!(
+            Thresholds {
+                warn: -1.0,
+                critical: 50.0
+            }
+            .validate("cpu_thresholds")
+            .is_err()
+        );
     }
 
     #[test]
@@ -412,32 +1028,32 @@ mod tests {
 
         // Test exact boundary values
         assert_eq!(
-            get_percentage_style(0.0, &styles).fg,
+            get_percentage_style(0.0, Thresholds::default(), &styles).fg,
             Some(Color::Green),
             "0% should be green"
         );
         assert_eq!(
-            get_percentage_style(50.0, &styles).fg,
+            get_percentage_style(50.0, Thresholds::default(), &styles).fg,
             Some(Color::Green),
             "50% should be green"
         );
         assert_eq!(
-            get_percentage_style(50.1, &styles).fg,
+            get_percentage_style(50.1, Thresholds::default(), &styles).fg,
             Some(Color::Yellow),
             "50.1% should be yellow"
         );
         assert_eq!(
-            get_percentage_style(80.0, &styles).fg,
+            get_percentage_style(80.0, Thresholds::default(), &styles).fg,
             Some(Color::Yellow),
             "80% should be yellow"
         );
         assert_eq!(
-            get_percentage_style(80.1, &styles).fg,
+            get_percentage_style(80.1, Thresholds::default(), &styles).fg,
             Some(Color::Red),
             "80.1% should be red"
         );
         assert_eq!(
-            get_percentage_style(100.0, &styles).fg,
+            get_percentage_style(100.0, Thresholds::default(), &styles).fg,
             Some(Color::Red),
             "100% should be red"
         );
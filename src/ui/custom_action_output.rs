@@ -0,0 +1,83 @@
+use ratatui::{
+    Frame,
+    layout::{Alignment, Rect},
+    style::{Color, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+};
+
+use crate::core::app_state::AppState;
+use crate::ui::render::UiStyles;
+
+/// Renders the output popup of the custom action run from the action menu
+pub fn render_custom_action_output(f: &mut Frame, state: &mut AppState, styles: &UiStyles) {
+    let Some(run) = &mut state.custom_action_run else {
+        return;
+    };
+
+    let area = f.area();
+    let popup_width = 100u16.min(area.width.saturating_sub(4));
+    let popup_height = area.height.saturating_sub(4);
+
+    let popup_x = (area.width.saturating_sub(popup_width)) / 2;
+    let popup_y = (area.height.saturating_sub(popup_height)) / 2;
+
+    let popup_area = Rect::new(popup_x, popup_y, popup_width, popup_height);
+
+    f.render_widget(Clear, popup_area);
+
+    let container_name = state
+        .containers
+        .get(&run.container_key)
+        .map_or(run.container_key.container_id.as_str(), |c| c.name.as_str());
+
+    let block = Block::default()
+        .title(format!(" {}: {} ", run.label, container_name))
+        .title_alignment(Alignment::Center)
+        .borders(Borders::ALL)
+        .border_style(styles.header)
+        .style(Style::default().bg(Color::Black));
+
+    f.render_widget(block, popup_area);
+
+    let output_area = Rect::new(
+        popup_area.x + 2,
+        popup_area.y + 1,
+        popup_area.width.saturating_sub(4),
+        popup_area.height.saturating_sub(4),
+    );
+
+    let lines: Vec<Line> = match &run.output {
+        None => vec![Line::from(Span::styled("Running...", styles.medium))],
+        Some(Err(error)) => vec![Line::from(Span::styled(error.as_str(), styles.high))],
+        Some(Ok(output)) if output.is_empty() => {
+            vec![Line::from(Span::styled("(no output)", styles.medium))]
+        }
+        Some(Ok(output)) => output.lines().map(Line::raw).collect(),
+    };
+
+    // Keep the end of long output in view unless scrolled back; that's where
+    // results and errors are
+    let hidden = lines.len().saturating_sub(output_area.height as usize);
+    run.scroll_back = run.scroll_back.min(hidden);
+    let hidden = hidden - run.scroll_back;
+    let output = Paragraph::new(lines)
+        .style(Style::default().fg(Color::White))
+        .scroll((hidden as u16, 0));
+
+    f.render_widget(output, output_area);
+
+    let footer_y = popup_area.y + popup_area.height.saturating_sub(2);
+    let footer_area = Rect::new(
+        popup_area.x + 2,
+        footer_y,
+        popup_area.width.saturating_sub(4),
+        1,
+    );
+
+    let footer = Paragraph::new("↑/↓/PgUp/PgDn: Scroll  Esc: Close")
+        .style(Style::default().fg(Color::Gray))
+        .alignment(Alignment::Center);
+
+    f.render_widget(footer, footer_area);
+}
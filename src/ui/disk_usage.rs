@@ -0,0 +1,157 @@
+use ratatui::{
+    Frame,
+    layout::{Alignment, Constraint, Rect},
+    style::{Color, Style},
+    text::Span,
+    widgets::{Block, Borders, Cell, Clear, Paragraph, Row, Table},
+};
+
+use crate::core::app_state::AppState;
+use crate::core::types::DiskUsageCategory;
+use crate::ui::formatters::write_bytes;
+use crate::ui::render::UiStyles;
+
+/// Renders the disk usage summary popup (`docker system df` per host)
+pub fn render_disk_usage(f: &mut Frame, state: &AppState, styles: &UiStyles) {
+    let area = f.area();
+
+    // Include hosts that reported results even if they have since disconnected,
+    // and sort so the popup has a stable order between renders
+    let mut host_ids: Vec<&String> = state
+        .connected_hosts
+        .keys()
+        .chain(state.disk_usage.keys())
+        .collect();
+    host_ids.sort();
+    host_ids.dedup();
+
+    let mut rows: Vec<Row> = Vec::new();
+    for host_id in &host_ids {
+        match state.disk_usage.get(*host_id) {
+            None => rows.push(Row::new(vec![
+                Cell::from(host_id.as_str()),
+                Cell::from(Span::styled("Loading...", styles.medium)),
+            ])),
+            Some(Err(error)) => {
+                rows.push(Row::new(vec![
+                    Cell::from(host_id.as_str()),
+                    Cell::from(Span::styled("Unavailable", styles.high)),
+                ]));
+                // The error message goes on its own row in the (widest) host column
+                rows.push(Row::new(vec![Cell::from(Span::styled(
+                    error.as_str(),
+                    styles.high,
+                ))]));
+            }
+            Some(Ok(summary)) => {
+                let categories = [
+                    ("Images", &summary.images),
+                    ("Containers", &summary.containers),
+                    ("Local Volumes", &summary.volumes),
+                    ("Build Cache", &summary.build_cache),
+                ];
+                for (i, (label, category)) in categories.into_iter().enumerate() {
+                    // Only show the host name on the first row of each group
+                    let host_cell = if i == 0 { host_id.as_str() } else { "" };
+                    rows.push(create_category_row(host_cell, label, category));
+                }
+            }
+        }
+    }
+
+    if host_ids.is_empty() {
+        rows.push(Row::new(vec![
+            Cell::from(""),
+            Cell::from("No connected hosts"),
+        ]));
+    }
+
+    let popup_width = 90u16.min(area.width.saturating_sub(4));
+    // border(2) + header(1) + rows + footer(2)
+    let popup_height = (rows.len() as u16 + 5).min(area.height.saturating_sub(4));
+
+    let popup_x = (area.width.saturating_sub(popup_width)) / 2;
+    let popup_y = (area.height.saturating_sub(popup_height)) / 2;
+
+    let popup_area = Rect::new(popup_x, popup_y, popup_width, popup_height);
+
+    f.render_widget(Clear, popup_area);
+
+    let block = Block::default()
+        .title(" Disk Usage ")
+        .title_alignment(Alignment::Center)
+        .borders(Borders::ALL)
+        .border_style(styles.header)
+        .style(Style::default().bg(Color::Black));
+
+    f.render_widget(block, popup_area);
+
+    let table_area = Rect::new(
+        popup_area.x + 1,
+        popup_area.y + 1,
+        popup_area.width.saturating_sub(2),
+        popup_area.height.saturating_sub(4),
+    );
+
+    let header = Row::new(vec![
+        "Host",
+        "Type",
+        "Total",
+        "Active",
+        "Size",
+        "Reclaimable",
+    ])
+    .style(styles.header);
+
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Fill(1),
+            Constraint::Length(14),
+            Constraint::Length(6),
+            Constraint::Length(6),
+            Constraint::Length(8),
+            Constraint::Length(14),
+        ],
+    )
+    .header(header)
+    .style(Style::default().fg(Color::White));
+
+    f.render_widget(table, table_area);
+
+    let footer_y = popup_area.y + popup_area.height.saturating_sub(2);
+    let footer_area = Rect::new(
+        popup_area.x + 2,
+        footer_y,
+        popup_area.width.saturating_sub(4),
+        1,
+    );
+
+    let footer = Paragraph::new("Esc: Close")
+        .style(Style::default().fg(Color::Gray))
+        .alignment(Alignment::Center);
+
+    f.render_widget(footer, footer_area);
+}
+
+/// Creates a table row for a single disk usage category
+fn create_category_row<'a>(host: &'a str, label: &'a str, category: &DiskUsageCategory) -> Row<'a> {
+    let mut size = String::new();
+    write_bytes(&mut size, category.total_size);
+
+    let mut reclaimable = String::new();
+    write_bytes(&mut reclaimable, category.reclaimable);
+    if category.total_size > 0 {
+        let percent = category.reclaimable as f64 / category.total_size as f64 * 100.0;
+        reclaimable.push_str(&format!(" ({:.0}%)", percent));
+    }
+
+    Row::new(vec![
+        Cell::from(host),
+        Cell::from(label),
+        Cell::from(category.total_count.to_string()),
+        Cell::from(category.active_count.to_string()),
+        Cell::from(size),
+        Cell::from(reclaimable),
+    ])
+}
@@ -5,17 +5,21 @@ use std::fmt::Write;
 use std::sync::LazyLock;
 use timeago::Formatter;
 
+use crate::core::types::PublishedPort;
+
 static TIMEAGO_FORMATTER: LazyLock<Formatter> = LazyLock::new(Formatter::new);
 
 const KB: f64 = 1024.0;
 const MB: f64 = KB * 1024.0;
 const GB: f64 = MB * 1024.0;
+const TB: f64 = GB * 1024.0;
 
 /// Writes a byte value with the appropriate unit into an existing buffer.
 ///
 /// This is the allocation-free core used by both the `String`-returning
 /// helpers and the hot-path renderers that format directly into a reused
-/// buffer.
+/// buffer. Terabytes share the gigabyte precision. With at most two decimals,
+/// every value fits the 12-character rate columns (at most "1024.00GB/s").
 fn write_byte_value(
     buf: &mut String,
     value: f64,
@@ -27,15 +31,10 @@ fn write_byte_value(
     let b = if include_b { "B" } else { "" };
 
     // `write!` to a `String` is infallible, so each `let _` discards the Result.
-    if value >= GB {
-        let gb = value / GB;
-        // When precision is 0, show one decimal for fractional values so
-        // 1.5G doesn't render as 2G. Whole numbers stay clean (e.g. "4G").
-        if gb_prec == 0 && (gb - gb.round()).abs() >= 0.05 {
-            let _ = write!(buf, "{:.1}G{}{}", gb, b, suffix);
-        } else {
-            let _ = write!(buf, "{:.prec$}G{}{}", gb, b, suffix, prec = gb_prec);
-        }
+    if value >= TB {
+        write_large_value(buf, value / TB, "T", b, suffix, gb_prec);
+    } else if value >= GB {
+        write_large_value(buf, value / GB, "G", b, suffix, gb_prec);
     } else if value >= MB {
         let _ = write!(buf, "{:.prec$}M{}{}", value / MB, b, suffix, prec = mb_prec);
     } else if value >= KB {
@@ -45,12 +44,30 @@ fn write_byte_value(
     }
 }
 
-/// Writes a human-readable byte value (B, K, M, G) into an existing buffer.
+/// Writes a value already scaled to gigabytes or terabytes.
+fn write_large_value(
+    buf: &mut String,
+    scaled: f64,
+    unit: &str,
+    b: &str,
+    suffix: &str,
+    prec: usize,
+) {
+    // When precision is 0, show one decimal for fractional values so
+    // 1.5G doesn't render as 2G. Whole numbers stay clean (e.g. "4G").
+    if prec == 0 && (scaled - scaled.round()).abs() >= 0.05 {
+        let _ = write!(buf, "{:.1}{}{}{}", scaled, unit, b, suffix);
+    } else {
+        let _ = write!(buf, "{:.prec$}{}{}{}", scaled, unit, b, suffix, prec = prec);
+    }
+}
+
+/// Writes a human-readable byte value (B, K, M, G, T) into an existing buffer.
 pub fn write_bytes(buf: &mut String, bytes: u64) {
     write_byte_value(buf, bytes as f64, "", false, (0, 0, 0, 0));
 }
 
-/// Formats bytes into a human-readable string (B, K, M, G).
+/// Formats bytes into a human-readable string (B, K, M, G, T).
 ///
 /// Production rendering uses `write_bytes` to format directly into a reused
 /// buffer; this `String`-returning convenience wrapper is retained for tests.
@@ -61,13 +78,29 @@ pub fn format_bytes(bytes: u64) -> String {
     s
 }
 
-/// Formats bytes per second into a human-readable string (KB/s, MB/s, GB/s)
+/// Formats bytes per second into a human-readable string (KB/s, MB/s, GB/s, TB/s)
 pub fn format_bytes_per_sec(bytes_per_sec: f64) -> String {
     let mut s = String::new();
     write_byte_value(&mut s, bytes_per_sec, "/s", true, (2, 2, 1, 0));
     s
 }
 
+/// Formats published ports compactly, host port first: "8080→80, 5353→53/udp".
+/// TCP, the common case, has no suffix.
+pub fn format_ports(ports: &[PublishedPort]) -> String {
+    let mut s = String::new();
+    for (i, port) in ports.iter().enumerate() {
+        if i > 0 {
+            s.push_str(", ");
+        }
+        let _ = write!(s, "{}→{}", port.host_port, port.container_port);
+        if port.protocol != "tcp" {
+            let _ = write!(s, "/{}", port.protocol);
+        }
+    }
+    s
+}
+
 /// Formats the time elapsed since container creation
 pub fn format_time_elapsed(created: Option<&chrono::DateTime<Utc>>) -> String {
     match created {
@@ -83,6 +116,20 @@ pub fn format_time_elapsed(created: Option<&chrono::DateTime<Utc>>) -> String {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_format_ports() {
+        let port = |host_port, container_port, protocol: &str| PublishedPort {
+            host_port,
+            container_port,
+            protocol: protocol.to_string(),
+        };
+        assert_eq!(format_ports(&[]), "");
+        assert_eq!(
+            format_ports(&[port(8080, 80, "tcp"), port(5353, 53, "udp")]),
+            "8080→80, 5353→53/udp"
+        );
+    }
+
     #[test]
     fn test_format_bytes_zero() {
         assert_eq!(format_bytes(0), "0B");
@@ -134,4 +181,22 @@ mod tests {
         assert_eq!(format_bytes_per_sec(1048576.0), "1.00MB/s");
         assert_eq!(format_bytes_per_sec(1073741824.0), "1.00GB/s");
     }
+
+    #[test]
+    fn test_format_bytes_per_sec_high_throughput() {
+        // ~100GbE line rate
+        assert_eq!(format_bytes_per_sec(12.5 * GB), "12.50GB/s");
+        // Just under a terabyte per second rounds up to the widest value
+        let widest = format_bytes_per_sec(TB - 1.0);
+        assert_eq!(widest, "1024.00GB/s");
+        assert!(widest.len() <= 12, "must fit the 12-wide rate columns");
+        assert_eq!(format_bytes_per_sec(TB), "1.00TB/s");
+        assert_eq!(format_bytes_per_sec(2.5 * TB), "2.50TB/s");
+    }
+
+    #[test]
+    fn test_format_bytes_terabytes() {
+        assert_eq!(format_bytes(1024 * 1024 * 1024 * 1024), "1T");
+        assert_eq!(format_bytes(1536 * 1024 * 1024 * 1024), "1.5T");
+    }
 }
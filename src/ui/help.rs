@@ -6,7 +6,7 @@ use ratatui::{
     widgets::{Block, Borders, Clear, Paragraph, Wrap},
 };
 
-use crate::core::types::{ContainerState, HealthStatus};
+use crate::core::types::{ContainerAction, ContainerState, HealthStatus};
 use crate::ui::render::UiStyles;
 
 /// Renders a centered help popup
@@ -23,16 +23,24 @@ pub fn render_help_popup(f: &mut Frame, styles: &UiStyles) {
                 .add_modifier(Modifier::BOLD),
         )]),
         Line::from(
-            "  ↑/↓, j/k    Navigate/scroll (1 line)    →/l    View logs      ←/h    Exit logs",
+            "  ↑/↓, j/k    Navigate/scroll (1 line)    →/l, v Logs / Split   ←/h    Exit logs",
         ),
         Line::from(
             "  Enter       Action menu                 Esc    Close menu     ?      Toggle help",
         ),
         Line::from(
-            "  a           Show all containers         /      Filter         o      Open Dozzle",
+            "  a / p       All / Cycle state filter    /      Filter         o      Open Dozzle",
+        ),
+        Line::from(
+            "  s / t       Sort by / by net traffic    c      Column visibility  d      Disk usage",
         ),
-        Line::from("  s           Sort by                     c      Column visibility"),
         Line::from("  PgUp/PgDn   Page up/down                Home   First          End    Last"),
+        Line::from(
+            "  u / r / i   Stats / Reload / Inspect    f      Key legend footer  0-9 ⏎  Go to row",
+        ),
+        Line::from(
+            "  e / E / y   Copy error / errors / ID    H      Host filter    x      Export JSON",
+        ),
         Line::from(""),
         Line::from(vec![Span::styled(
             "Preferences",
@@ -51,7 +59,10 @@ pub fn render_help_popup(f: &mut Frame, styles: &UiStyles) {
         Line::from(
             "  g/Home      Top              Ctrl+U, b, PgUp    Page up     Ctrl+D, Space, PgDn  Page down",
         ),
-        Line::from("  G/End       Bottom"),
+        Line::from(
+            "  G/End, F    Bottom/Follow    n / T / w          Line numbers / Timestamps / Wrap",
+        ),
+        Line::from("  f / s       Filter lines / Save to file   ←/→  Scroll sideways (wrap off)"),
         Line::from(""),
         Line::from(vec![Span::styled(
             "Status Icons",
@@ -92,6 +103,33 @@ pub fn render_help_popup(f: &mut Frame, styles: &UiStyles) {
             Span::raw("Exited"),
         ]),
         Line::from(""),
+        Line::from(vec![
+            Span::styled(
+                "Actions (Enter)",
+                Style::default()
+                    .fg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(
+                "  (Shell / Exec: running containers only)",
+                Style::default().fg(Color::Gray),
+            ),
+        ]),
+        Line::from(
+            ContainerAction::ALL
+                .iter()
+                .flat_map(|action| {
+                    [
+                        Span::styled(
+                            format!("{} ", styles.icons.action(*action)),
+                            Style::default().fg(Color::White),
+                        ),
+                        Span::raw(format!("{}  ", action.display_name())),
+                    ]
+                })
+                .collect::<Vec<_>>(),
+        ),
+        Line::from(""),
         Line::from(vec![Span::styled(
             "Colors",
             Style::default()
@@ -0,0 +1,160 @@
+use ratatui::{
+    Frame,
+    layout::{Alignment, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, Paragraph},
+};
+
+use crate::core::app_state::AppState;
+use crate::core::types::{ContainerState, HostStatus};
+use crate::ui::action_menu::truncate_string;
+use crate::ui::render::UiStyles;
+
+/// Renders the host picker popup: a query line above the fuzzy-filtered host list,
+/// one row per host with its connection status, container counts and usage
+pub fn render_host_picker(f: &mut Frame, state: &mut AppState, styles: &UiStyles) {
+    let area = f.area();
+
+    let hosts = state.host_picker_matches();
+    let item_count = hosts.len().max(1) as u16;
+
+    // border(2) + query(1) + spacer(1) + items + footer(2)
+    let popup_width = 72u16.min(area.width.saturating_sub(4));
+    let popup_height = (item_count + 6).min(area.height.saturating_sub(4));
+
+    let popup_x = (area.width.saturating_sub(popup_width)) / 2;
+    let popup_y = (area.height.saturating_sub(popup_height)) / 2;
+
+    let popup_area = Rect::new(popup_x, popup_y, popup_width, popup_height);
+
+    f.render_widget(Clear, popup_area);
+
+    let block = Block::default()
+        .title(" Hosts ")
+        .title_alignment(Alignment::Center)
+        .borders(Borders::ALL)
+        .border_style(styles.header)
+        .style(Style::default().bg(Color::Black));
+
+    f.render_widget(block, popup_area);
+
+    let query_area = Rect::new(
+        popup_area.x + 2,
+        popup_area.y + 1,
+        popup_area.width.saturating_sub(4),
+        1,
+    );
+    let query = Paragraph::new(Line::from(vec![
+        Span::styled("> ", styles.header),
+        Span::raw(state.host_picker_input.value()),
+        Span::styled("_", Style::default().fg(Color::Gray)),
+    ]));
+    f.render_widget(query, query_area);
+
+    let list_area = Rect::new(
+        popup_area.x + 1,
+        popup_area.y + 3,
+        popup_area.width.saturating_sub(2),
+        popup_area.height.saturating_sub(6),
+    );
+
+    let list_items: Vec<ListItem> = if hosts.is_empty() {
+        vec![ListItem::new("  No matching hosts").style(Style::default().fg(Color::Gray))]
+    } else {
+        let name_width = hosts
+            .iter()
+            .map(|host_id| {
+                AppState::host_display_name(&state.connected_hosts, host_id)
+                    .chars()
+                    .count()
+            })
+            .max()
+            .unwrap_or(0)
+            .min(30);
+        hosts
+            .iter()
+            .map(|host_id| {
+                let dot_style = match state.host_status.get(*host_id) {
+                    Some(HostStatus::Connected) => styles.low,
+                    Some(HostStatus::Connecting) => styles.medium,
+                    Some(HostStatus::Failed) | None => styles.high,
+                };
+                let name = truncate_string(
+                    AppState::host_display_name(&state.connected_hosts, host_id),
+                    name_width,
+                );
+                ListItem::new(Line::from(vec![
+                    Span::styled("● ", dot_style),
+                    Span::raw(format!(
+                        "{:<width$}  {}",
+                        name,
+                        host_usage(state, host_id),
+                        width = name_width
+                    )),
+                ]))
+                .style(Style::default().fg(Color::White))
+            })
+            .collect()
+    };
+
+    let list = List::new(list_items)
+        .highlight_style(
+            Style::default()
+                .bg(Color::DarkGray)
+                .fg(Color::White)
+                .add_modifier(Modifier::BOLD),
+        )
+        .highlight_symbol("> ");
+
+    f.render_stateful_widget(list, list_area, &mut state.host_picker_state);
+
+    let footer_y = popup_area.y + popup_area.height.saturating_sub(2);
+    let footer_area = Rect::new(
+        popup_area.x + 2,
+        footer_y,
+        popup_area.width.saturating_sub(4),
+        1,
+    );
+
+    let footer = Paragraph::new("Type to filter  Enter: Show only this host  Esc: Close")
+        .style(Style::default().fg(Color::Gray))
+        .alignment(Alignment::Center);
+
+    f.render_widget(footer, footer_area);
+}
+
+/// Summarizes a host's containers, e.g. "12/15 running  340% CPU  62% mem avg".
+/// CPU is summed and memory averaged over the running containers, like the
+/// title's fleet totals.
+fn host_usage(state: &AppState, host_id: &str) -> String {
+    let (total, running, cpu, memory) = state
+        .containers
+        .values()
+        .filter(|c| c.host_id == host_id)
+        .fold(
+            (0usize, 0usize, 0.0, 0.0),
+            |(total, running, cpu, memory), c| {
+                if c.state == ContainerState::Running {
+                    (
+                        total + 1,
+                        running + 1,
+                        cpu + c.stats.cpu,
+                        memory + c.stats.memory,
+                    )
+                } else {
+                    (total + 1, running, cpu, memory)
+                }
+            },
+        );
+    let memory_avg = if running > 0 {
+        memory / running as f64
+    } else {
+        0.0
+    };
+
+    format!(
+        "{}/{} running  {:.0}% CPU  {:.0}% mem avg",
+        running, total, cpu, memory_avg
+    )
+}
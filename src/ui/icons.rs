@@ -106,6 +106,9 @@ impl Icons {
                 ContainerAction::Restart => "↻",
                 ContainerAction::Remove => "✕",
                 ContainerAction::Shell => ">_",
+                ContainerAction::Exec => "»",
+                ContainerAction::Rename => "✎",
+                ContainerAction::Pager => "≡",
             },
             IconStyle::Nerd => match action {
                 ContainerAction::Start => "\u{f04b}",   // nf-fa-play
@@ -113,7 +116,18 @@ impl Icons {
                 ContainerAction::Restart => "\u{f01e}", // nf-fa-refresh
                 ContainerAction::Remove => "\u{f1f8}",  // nf-fa-trash
                 ContainerAction::Shell => "\u{f120}",   // nf-fa-terminal
+                ContainerAction::Exec => "\u{f121}",    // nf-fa-code
+                ContainerAction::Rename => "\u{f044}",  // nf-fa-pencil_square_o
+                ContainerAction::Pager => "\u{f15c}",   // nf-fa-file_text
             },
         }
     }
+
+    /// Icon for user-defined actions from the `custom_actions` config
+    pub fn custom_action(&self) -> &'static str {
+        match self.style {
+            IconStyle::Unicode => "$",
+            IconStyle::Nerd => "\u{f489}", // nf-oct-terminal
+        }
+    }
 }
@@ -1,4 +1,4 @@
-use crossterm::event::{self, Event, KeyCode, KeyModifiers};
+use crossterm::event::{self, Event, KeyCode, KeyModifiers, MouseButton, MouseEventKind};
 use std::sync::Arc;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::time::Duration;
@@ -31,6 +31,15 @@ pub fn keyboard_worker(tx: EventSender, paused: Arc<AtomicBool>) {
                         let _ = tx.blocking_send(AppEvent::KeyInput(key));
                     }
                 }
+                Event::Mouse(mouse) if mouse.kind == MouseEventKind::Down(MouseButton::Left) => {
+                    let _ = tx.blocking_send(AppEvent::MouseClick(mouse.column, mouse.row));
+                }
+                Event::Mouse(mouse) if mouse.kind == MouseEventKind::ScrollUp => {
+                    let _ = tx.blocking_send(AppEvent::MouseScrollUp);
+                }
+                Event::Mouse(mouse) if mouse.kind == MouseEventKind::ScrollDown => {
+                    let _ = tx.blocking_send(AppEvent::MouseScrollDown);
+                }
                 Event::Resize(_, _) => {
                     let _ = tx.blocking_send(AppEvent::Resize);
                 }
@@ -0,0 +1,123 @@
+use std::collections::VecDeque;
+
+use ratatui::{
+    Frame,
+    layout::{Constraint, Layout, Rect},
+    text::{Line, Text},
+    widgets::{Block, Paragraph, Scrollbar, ScrollbarOrientation, ScrollbarState, Sparkline},
+};
+
+use crate::core::app_state::AppState;
+use crate::core::types::{ContainerKey, StatsHistory};
+
+use super::render::UiStyles;
+
+/// Renders the inspect view for a specific container
+pub fn render_inspect(
+    f: &mut Frame,
+    area: Rect,
+    container_key: &ContainerKey,
+    state: &mut AppState,
+    styles: &UiStyles,
+) {
+    let Some(view) = &mut state.inspect_view else {
+        return;
+    };
+
+    let container_name = state
+        .containers
+        .get(container_key)
+        .map(|c| c.name.as_str())
+        .unwrap_or("Unknown");
+
+    let block = Block::default()
+        .title(format!(
+            "Inspect: {} ({}) - Press ESC to return",
+            container_name, container_key.host_id
+        ))
+        .style(styles.border);
+    let text_area = block.inner(area);
+    f.render_widget(block, area);
+
+    // CPU and memory sparklines above the summary, once there are samples
+    let text_area = match state.stats_history.get(container_key) {
+        Some(history) if !history.cpu.is_empty() => {
+            let [charts_area, rest] =
+                Layout::vertical([Constraint::Length(4), Constraint::Min(0)]).areas(text_area);
+            render_history(f, charts_area, history, styles);
+            rest
+        }
+        _ => text_area,
+    };
+
+    let Some(text) = &view.text else {
+        f.render_widget(
+            Paragraph::new("Inspecting...").style(styles.medium),
+            text_area,
+        );
+        return;
+    };
+
+    // Page up/down move by half the viewport, like the log view
+    let visible_height = text_area.height as usize;
+    state.last_viewport_height = visible_height;
+
+    let lines: Vec<Line> = text.lines().map(Line::raw).collect();
+    let max_scroll = lines.len().saturating_sub(visible_height);
+    view.scroll_offset = view.scroll_offset.min(max_scroll);
+
+    f.render_widget(
+        Paragraph::new(Text::from(lines)).scroll((view.scroll_offset as u16, 0)),
+        text_area,
+    );
+
+    if max_scroll > 0 {
+        let mut scrollbar_state = ScrollbarState::new(max_scroll)
+            .viewport_content_length(visible_height)
+            .position(view.scroll_offset);
+        let scrollbar = Scrollbar::default().orientation(ScrollbarOrientation::VerticalRight);
+        f.render_stateful_widget(scrollbar, area, &mut scrollbar_state);
+    }
+}
+
+/// Renders CPU and memory sparklines side by side, newest sample on the right
+fn render_history(f: &mut Frame, area: Rect, history: &StatsHistory, styles: &UiStyles) {
+    let [cpu_area, memory_area] = Layout::horizontal([Constraint::Fill(1), Constraint::Fill(1)])
+        .spacing(2)
+        .areas(area);
+
+    for (label, samples, area) in [
+        ("CPU", &history.cpu, cpu_area),
+        ("Memory", &history.memory, memory_area),
+    ] {
+        let latest = samples.back().copied().unwrap_or_default();
+        let block =
+            Block::default().title(format!("{} {:.1}% (last {})", label, latest, samples.len()));
+        let chart_area = block.inner(area);
+        f.render_widget(block, area);
+
+        let sparkline = Sparkline::default()
+            .data(sparkline_data(samples, chart_area.width as usize))
+            .max(sparkline_max(samples))
+            .style(styles.header);
+        f.render_widget(sparkline, chart_area);
+    }
+}
+
+/// The newest samples that fit in `width` columns, in tenths of a percent
+/// (sparklines take integers)
+fn sparkline_data(samples: &VecDeque<f64>, width: usize) -> Vec<u64> {
+    let skip = samples.len().saturating_sub(width);
+    samples
+        .iter()
+        .skip(skip)
+        .map(|value| (value.max(0.0) * 10.0).round() as u64)
+        .collect()
+}
+
+/// Percentages are drawn against 100%, or the highest sample if above it
+/// (CPU over several cores)
+fn sparkline_max(samples: &VecDeque<f64>) -> u64 {
+    let highest = samples.iter().copied().fold(100.0, f64::max);
+    (highest * 10.0).round() as u64
+}
@@ -0,0 +1,124 @@
+/// All keybindings as (section, [(keys, description)]), used by `dtop keys` to
+/// print a plain-text reference. Keep in sync with `AppState::handle_key_input`.
+pub const KEY_BINDINGS: &[(&str, &[(&str, &str)])] = &[
+    (
+        "Navigation",
+        &[
+            ("↑/↓, j/k", "Move selection (scroll 1 line in logs)"),
+            ("PgUp/PgDn", "Page up/down"),
+            ("Ctrl+U/Ctrl+D", "Page up/down"),
+            ("Home/End", "First/last container"),
+            ("Enter", "Open action menu"),
+            ("Esc", "Close menu or popup"),
+            ("→/l", "View logs"),
+            (
+                "v",
+                "Split view: list on top, selected container's logs below",
+            ),
+            ("←/h", "Exit logs"),
+            (
+                "H",
+                "Hosts: status and usage per host, Enter lists only that host",
+            ),
+            ("?", "Toggle help"),
+            ("q", "Quit"),
+        ],
+    ),
+    (
+        "Container List",
+        &[
+            ("/", "Filter by name or ID"),
+            ("a", "Show all containers"),
+            ("p", "Cycle state filter (all/running/exited/unhealthy)"),
+            ("s", "Sort by"),
+            ("t", "Sort by network traffic (TX + RX)"),
+            ("0-9 then Enter/G", "Go to row number"),
+            ("c", "Column visibility"),
+            ("d", "Disk usage"),
+            ("o", "Open Dozzle"),
+            ("u", "Refresh selected stats now"),
+            ("r", "Reload the container list from every host"),
+            ("i", "Inspect (image, command, ports, env, mounts, labels)"),
+            ("f", "Key legend footer"),
+            ("e", "Copy last error"),
+            ("E", "Copy all recent errors"),
+            ("y", "Copy the full container ID"),
+            ("x", "Export a JSON snapshot of all containers"),
+        ],
+    ),
+    (
+        "Preferences",
+        &[
+            ("Ctrl+S", "Save preferences"),
+            ("Ctrl+R", "Reset to defaults"),
+        ],
+    ),
+    (
+        "Log View",
+        &[
+            ("g/Home", "Top"),
+            ("G/End", "Bottom"),
+            ("F", "Follow new lines / pause"),
+            ("b, PgUp, Ctrl+U", "Page up"),
+            ("Space, PgDn, Ctrl+D", "Page down"),
+            ("n", "Toggle line numbers"),
+            ("T", "Toggle timestamps"),
+            ("w", "Toggle line wrapping"),
+            ("←/→", "Scroll sideways (wrapping off)"),
+            ("f", "Filter lines (Enter keeps, Esc clears)"),
+            ("s", "Save loaded lines to <container>-<timestamp>.log"),
+        ],
+    ),
+];
+
+/// Formats [`KEY_BINDINGS`] as plain text, one binding per line
+pub fn format_key_bindings() -> String {
+    let width = KEY_BINDINGS
+        .iter()
+        .flat_map(|(_, bindings)| bindings.iter())
+        .map(|(keys, _)| keys.chars().count())
+        .max()
+        .unwrap_or(0);
+
+    let mut output = String::new();
+    for (i, (section, bindings)) in KEY_BINDINGS.iter().enumerate() {
+        if i > 0 {
+            output.push('\n');
+        }
+        output.push_str(section);
+        output.push('\n');
+        for (keys, description) in bindings.iter() {
+            let padding = width - keys.chars().count();
+            output.push_str(&format!(
+                "  {}{}  {}\n",
+                keys,
+                " ".repeat(padding),
+                description
+            ));
+        }
+    }
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_key_bindings() {
+        let output = format_key_bindings();
+        assert!(output.starts_with("Navigation\n"));
+        assert!(output.contains("\nPreferences\n"));
+
+        // Descriptions line up in a single column
+        let quit = output.lines().find(|l| l.ends_with("Quit")).unwrap();
+        let save = output
+            .lines()
+            .find(|l| l.ends_with("Save preferences"))
+            .unwrap();
+        assert_eq!(
+            quit.chars().count() - "Quit".len(),
+            save.chars().count() - "Save preferences".len()
+        );
+    }
+}
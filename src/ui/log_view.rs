@@ -1,5 +1,7 @@
 use ratatui::{
     Frame,
+    layout::{Alignment, Rect},
+    style::{Color, Style},
     text::{Line, Text},
     widgets::{Block, Paragraph, Scrollbar, ScrollbarOrientation, ScrollbarState, Wrap},
 };
@@ -62,8 +64,20 @@ pub fn render_log_view(
     // Calculate visible height (subtract 2 for top and bottom border)
     let visible_height = area.height.saturating_sub(2) as usize;
 
-    // Inner width for wrap calculations (subtract 2 for left/right border)
-    let inner_width = area.width.saturating_sub(2) as usize;
+    // Line numbers go in a gutter wide enough for the highest number plus a space
+    let gutter_width = if state.show_log_line_numbers {
+        log_state.formatted_lines.len().max(1).to_string().len() + 1
+    } else {
+        0
+    };
+
+    // Width for wrap calculations (subtract 2 for left/right border and the gutter).
+    // Without wrapping every entry takes exactly one row.
+    let inner_width = if state.log_wrap {
+        (area.width as usize).saturating_sub(2 + gutter_width)
+    } else {
+        usize::MAX
+    };
 
     // Store viewport dimensions for scroll calculations
     state.last_viewport_height = visible_height;
@@ -81,8 +95,16 @@ pub fn render_log_view(
     // Max scroll: enough so that the last visual line is at the bottom of the viewport
     let max_scroll = total_rows.saturating_sub(visible_height);
 
-    // Determine actual scroll offset (in visual lines)
-    let actual_scroll = if state.is_at_bottom {
+    // Following pins the view to the newest line whenever lines arrive, however far
+    // up the user has scrolled. Between arrivals the view stays where they left it.
+    if state.log_follow && max_scroll > log_state.max_scroll {
+        state.is_at_bottom = true;
+    }
+    log_state.max_scroll = max_scroll;
+
+    // Determine actual scroll offset (in visual lines). Paused, the view stays put
+    // even at the bottom.
+    let actual_scroll = if state.log_follow && state.is_at_bottom {
         max_scroll
     } else {
         log_state.scroll_offset.min(max_scroll)
@@ -99,19 +121,18 @@ pub fn render_log_view(
     let (first_entry_idx, sub_line_offset) =
         find_visible_start(all_lines, actual_scroll, inner_width);
 
-    // Determine status indicator
-    let status_indicator = if log_state.fetching_older {
-        "[Loading...]".to_string()
-    } else if state.is_at_bottom {
-        "[LIVE]".to_string()
-    } else if let Some(progress) = log_state.calculate_progress(first_entry_idx) {
-        if log_state.has_more_history || progress > 0.0 {
-            format!("[{:.0}%]", progress)
-        } else {
-            "[0%]".to_string()
-        }
+    // Determine status indicator; the follow state is always shown
+    let follow_indicator = if state.log_follow {
+        "[FOLLOW]"
+    } else {
+        "[PAUSED]"
+    };
+    let status_indicator = if log_state.host_disconnected {
+        format!("[HOST DISCONNECTED] {}", follow_indicator)
+    } else if log_state.fetching_older {
+        format!("[Loading...] {}", follow_indicator)
     } else {
-        String::new()
+        follow_indicator.to_string()
     };
 
     // Collect only the visible slice of lines — O(viewport) instead of O(n).
@@ -119,34 +140,99 @@ pub fn render_log_view(
     // for the partial first entry that gets scrolled past).
     let needed_rows = visible_height + sub_line_offset;
     let mut visible_lines: Vec<Line> = Vec::new();
+    // One number per entry, padded with blank rows for its wrapped continuation
+    let mut gutter_lines: Vec<Line> = Vec::new();
     let mut rows_collected = 0;
 
-    for line in &all_lines[first_entry_idx..] {
+    for (i, line) in all_lines[first_entry_idx..].iter().enumerate() {
         let rows = wrapped_line_height(line, inner_width);
         visible_lines.push(line.clone());
+        if gutter_width > 0 {
+            let number = first_entry_idx + i + 1;
+            gutter_lines.push(Line::from(format!("{:>1$}", number, gutter_width - 1)));
+            gutter_lines.extend((1..rows).map(|_| Line::default()));
+        }
         rows_collected += rows;
         if rows_collected >= needed_rows {
             break;
         }
     }
 
-    let visible_text = Text::from(visible_lines);
+    let filter_label = match &state.log_filter {
+        Some(query) => format!("[filter: {}] ", query),
+        None => String::new(),
+    };
+
+    // Older lines were dropped to respect `log_max_lines`
+    let truncated_label = if log_state.truncated {
+        "(truncated) "
+    } else {
+        ""
+    };
+
+    let block = Block::default()
+        .title(format!(
+            "Logs: {} ({}) {}{}- Press ESC to return {}",
+            container_name,
+            AppState::host_display_name(&state.connected_hosts, &container_key.host_id),
+            truncated_label,
+            filter_label,
+            status_indicator
+        ))
+        .style(styles.border);
+    let text_area = block.inner(area);
+    f.render_widget(block, area);
+
+    // Nothing arrived before the host went away; say why instead of showing a blank view
+    if log_state.host_disconnected && all_lines.is_empty() {
+        let message = Paragraph::new(format!(
+            "Host {} is disconnected - logs are unavailable",
+            container_key.host_id
+        ))
+        .style(styles.high)
+        .alignment(Alignment::Center);
+        f.render_widget(message, text_area);
+        return;
+    }
+
+    let text_area = if gutter_width > 0 {
+        let gutter_area = Rect::new(
+            text_area.x,
+            text_area.y,
+            gutter_width as u16,
+            text_area.height,
+        );
+        let gutter = Paragraph::new(Text::from(gutter_lines))
+            .style(Style::default().fg(Color::DarkGray))
+            .scroll((sub_line_offset as u16, 0));
+        f.render_widget(gutter, gutter_area);
+
+        Rect::new(
+            text_area.x + gutter_width as u16,
+            text_area.y,
+            text_area.width.saturating_sub(gutter_width as u16),
+            text_area.height,
+        )
+    } else {
+        text_area
+    };
 
     // Paragraph::scroll() only needs the sub-line offset within the first entry,
     // since we already sliced to the visible window.
-    let log_widget = Paragraph::new(visible_text)
-        .block(
-            Block::default()
-                .title(format!(
-                    "Logs: {} ({}) - Press ESC to return {}",
-                    container_name, container_key.host_id, status_indicator
-                ))
-                .style(styles.border),
-        )
-        .wrap(Wrap { trim: false })
-        .scroll((sub_line_offset as u16, 0));
+    let log_widget = if state.log_wrap {
+        Paragraph::new(Text::from(visible_lines))
+            .scroll((sub_line_offset as u16, 0))
+            .wrap(Wrap { trim: false })
+    } else {
+        // Stop once the widest visible line's end is in view
+        let widest = visible_lines.iter().map(Line::width).max().unwrap_or(0);
+        let max_offset = widest.saturating_sub(text_area.width as usize);
+        log_state.horizontal_offset = log_state.horizontal_offset.min(max_offset);
+        Paragraph::new(Text::from(visible_lines))
+            .scroll((sub_line_offset as u16, log_state.horizontal_offset as u16))
+    };
 
-    f.render_widget(log_widget, area);
+    f.render_widget(log_widget, text_area);
 
     // Render scrollbar on the right side
     let mut scrollbar_state = ScrollbarState::default()
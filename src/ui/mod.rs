@@ -1,10 +1,17 @@
 pub mod action_menu;
 pub mod column_selector;
+pub mod confirm_action;
+pub mod connection_progress;
 pub mod container_list;
+pub mod custom_action_output;
+pub mod disk_usage;
 pub mod formatters;
 pub mod help;
+pub mod host_picker;
 pub mod icons;
 pub mod input;
+pub mod inspect;
+pub mod keys;
 pub mod log_view;
 pub mod render;
 pub mod sort_selector;
@@ -1,19 +1,26 @@
 use ratatui::{
     Frame,
-    layout::{Alignment, Rect},
+    layout::{Alignment, Constraint, Layout, Rect},
     style::{Color, Modifier, Style},
     text::{Line, Span},
     widgets::{Block, Borders, Paragraph},
 };
 
+use crate::cli::config::ThemeConfig;
 use crate::core::app_state::AppState;
-use crate::core::types::ViewState;
+use crate::core::types::{ContainerAction, ContainerState, Thresholds, ViewState};
 
 use crate::ui::action_menu::render_action_menu;
 use crate::ui::column_selector::render_column_selector;
+use crate::ui::confirm_action::render_confirm_action;
+use crate::ui::connection_progress::render_connection_progress;
 use crate::ui::container_list::render_container_list;
+use crate::ui::custom_action_output::render_custom_action_output;
+use crate::ui::disk_usage::render_disk_usage;
 use crate::ui::help::render_help_popup;
+use crate::ui::host_picker::render_host_picker;
 use crate::ui::icons::{IconStyle, Icons};
+use crate::ui::inspect::render_inspect;
 use crate::ui::log_view::render_log_view;
 use crate::ui::sort_selector::render_sort_selector;
 
@@ -27,6 +34,12 @@ pub struct UiStyles {
     pub selected: Style,
     pub search_bar: Style,
     pub icons: Icons,
+    /// When CPU turns medium/high (config: `cpu_thresholds`)
+    pub cpu_thresholds: Thresholds,
+    /// When memory turns medium/high (config: `memory_thresholds`)
+    pub memory_thresholds: Thresholds,
+    /// Draw without colors (`--no-color` or `NO_COLOR`); see [`UiStyles::monochrome`]
+    pub monochrome: bool,
 }
 
 impl Default for UiStyles {
@@ -46,6 +59,9 @@ impl Default for UiStyles {
                 .fg(Color::Yellow)
                 .add_modifier(Modifier::BOLD),
             icons: Icons::default(),
+            cpu_thresholds: Thresholds::default(),
+            memory_thresholds: Thresholds::default(),
+            monochrome: false,
         }
     }
 }
@@ -58,13 +74,87 @@ impl UiStyles {
             ..Default::default()
         }
     }
+
+    /// Create UiStyles without colors, for `--no-color` and `NO_COLOR`. High usage
+    /// is bold and the selected row reversed. Colors hardcoded elsewhere (state
+    /// icons, popups, ANSI colors in logs) are removed from each frame by `render_ui`.
+    pub fn monochrome(icon_style: IconStyle) -> Self {
+        let bold = Style::default().add_modifier(Modifier::BOLD);
+        Self {
+            high: bold,
+            medium: Style::default(),
+            low: Style::default(),
+            header: bold,
+            border: Style::default(),
+            selected: Style::default().add_modifier(Modifier::REVERSED),
+            search_bar: bold,
+            monochrome: true,
+            ..Self::with_icon_style(icon_style)
+        }
+    }
+
+    /// Create UiStyles with a specific icon style and the colors from the `theme`
+    /// config section. Fails on the first color that isn't a known name, index or hex.
+    pub fn with_theme(icon_style: IconStyle, theme: &ThemeConfig) -> Result<Self, String> {
+        let mut styles = Self::with_icon_style(icon_style);
+        if let Some(color) = parse_theme_color("high", &theme.high)? {
+            styles.high = styles.high.fg(color);
+        }
+        if let Some(color) = parse_theme_color("medium", &theme.medium)? {
+            styles.medium = styles.medium.fg(color);
+        }
+        if let Some(color) = parse_theme_color("low", &theme.low)? {
+            styles.low = styles.low.fg(color);
+        }
+        if let Some(color) = parse_theme_color("header", &theme.header)? {
+            styles.header = styles.header.fg(color);
+        }
+        if let Some(color) = parse_theme_color("border", &theme.border)? {
+            styles.border = styles.border.fg(color);
+        }
+        if let Some(color) = parse_theme_color("selected", &theme.selected)? {
+            styles.selected = styles.selected.bg(color);
+        }
+        Ok(styles)
+    }
+}
+
+/// Parses one `theme` color, if set
+fn parse_theme_color(name: &str, value: &Option<String>) -> Result<Option<Color>, String> {
+    let Some(value) = value else {
+        return Ok(None);
+    };
+    value.trim().parse::<Color>().map(Some).map_err(|_| {
+        format!(
+            "Invalid color '{}' for theme.{}: use a name (e.g. red, lightblue), \
+             a 256-color index (e.g. 208) or hex (e.g. #ff8700)",
+            value, name
+        )
+    })
+}
+
+/// Builds the terminal window title (config: `terminal_title`), counting running
+/// containers on every host, e.g. "dtop — 12 containers"
+pub fn terminal_title(state: &AppState) -> String {
+    let running = state
+        .containers
+        .values()
+        .filter(|c| c.state == ContainerState::Running)
+        .count();
+    let noun = if running == 1 {
+        "container"
+    } else {
+        "containers"
+    };
+    format!("dtop — {} {}", running, noun)
 }
 
 /// Cleans up expired connection errors and notifications. Call this from the event loop, not during rendering.
 pub fn cleanup_expired_errors(state: &mut AppState) {
+    let ttl = state.notification_ttl_secs;
     state
         .connection_errors
-        .retain(|_, (_, timestamp)| timestamp.elapsed().as_secs() < 10);
+        .retain(|_, (_, timestamp)| timestamp.elapsed().as_secs() < ttl);
 
     // Clear expired notifications
     state.clear_expired_notification();
@@ -74,29 +164,84 @@ pub fn cleanup_expired_errors(state: &mut AppState) {
 pub fn render_ui(f: &mut Frame, state: &mut AppState, styles: &UiStyles) {
     let size = f.area();
 
+    // Reserve the bottom row for the key legend; the log and inspect views have their own layout
+    let show_footer = state.show_footer
+        && !matches!(
+            state.view_state,
+            ViewState::LogView(_) | ViewState::Inspect(_)
+        );
+    let list_area = if show_footer {
+        Rect {
+            height: size.height.saturating_sub(1),
+            ..size
+        }
+    } else {
+        size
+    };
+
     // Render main content
     match &state.view_state {
+        ViewState::ContainerList if state.connecting => {
+            render_connection_progress(f, size, state, styles);
+        }
         ViewState::ContainerList | ViewState::SearchMode => {
             let show_host_column = state.has_multiple_hosts();
-            render_container_list(f, size, state, styles, show_host_column);
+            let log_key = state
+                .log_state
+                .as_ref()
+                .filter(|_| state.split_logs)
+                .map(|log_state| log_state.container_key.clone());
+            if let Some(log_key) = log_key {
+                let [top, bottom] =
+                    Layout::vertical([Constraint::Percentage(50), Constraint::Percentage(50)])
+                        .areas(list_area);
+                render_container_list(f, top, state, styles, show_host_column);
+                render_log_view(f, bottom, &log_key, state, styles);
+            } else {
+                render_container_list(f, list_area, state, styles, show_host_column);
+            }
         }
         ViewState::LogView(container_key) => {
             let container_key = container_key.clone();
             render_log_view(f, size, &container_key, state, styles);
         }
+        ViewState::Inspect(container_key) => {
+            let container_key = container_key.clone();
+            render_inspect(f, size, &container_key, state, styles);
+        }
         ViewState::ColumnSelector | ViewState::SortSelector => {
             let show_host_column = state.has_multiple_hosts();
-            render_container_list(f, size, state, styles, show_host_column);
+            render_container_list(f, list_area, state, styles, show_host_column);
             if state.view_state == ViewState::ColumnSelector {
                 render_column_selector(f, state, styles);
             } else {
                 render_sort_selector(f, state, styles);
             }
         }
+        ViewState::DiskUsage => {
+            let show_host_column = state.has_multiple_hosts();
+            render_container_list(f, list_area, state, styles, show_host_column);
+            render_disk_usage(f, state, styles);
+        }
+        ViewState::CustomActionOutput => {
+            let show_host_column = state.has_multiple_hosts();
+            render_container_list(f, list_area, state, styles, show_host_column);
+            render_custom_action_output(f, state, styles);
+        }
+        ViewState::HostPicker => {
+            let show_host_column = state.has_multiple_hosts();
+            render_container_list(f, list_area, state, styles, show_host_column);
+            render_host_picker(f, state, styles);
+        }
+        ViewState::ConfirmAction(..) => {
+            let show_host_column = state.has_multiple_hosts();
+            render_container_list(f, list_area, state, styles, show_host_column);
+            render_confirm_action(f, state, styles);
+        }
         ViewState::ActionMenu(_) => {
             // First render the container list in the background
             let show_host_column = state.has_multiple_hosts();
-            render_container_list(f, size, state, styles, show_host_column);
+            render_container_list(f, list_area, state, styles, show_host_column);
 
             // Then render the action menu on top
             render_action_menu(f, state, styles);
@@ -107,14 +252,22 @@ pub fn render_ui(f: &mut Frame, state: &mut AppState, styles: &UiStyles) {
     let show_search_bar = state.view_state == ViewState::SearchMode
         || (!state.search_input.value().is_empty() && state.view_state == ViewState::ContainerList);
 
+    let bottom_row = Rect {
+        x: size.x,
+        y: size.y + size.height.saturating_sub(1),
+        width: size.width,
+        height: 1,
+    };
+
+    // The search bar takes the footer's place while it is shown
     if show_search_bar {
-        let search_area = ratatui::layout::Rect {
-            x: size.x,
-            y: size.y + size.height.saturating_sub(1),
-            width: size.width,
-            height: 1,
-        };
-        render_search_bar(f, search_area, state, styles);
+        render_search_bar(f, bottom_row, state, styles);
+    } else if state.log_filter_editing {
+        render_log_filter_bar(f, bottom_row, state, styles);
+    } else if state.action_prompt.is_some() {
+        render_action_prompt(f, bottom_row, state, styles);
+    } else if show_footer {
+        render_footer(f, bottom_row, styles);
     }
 
     // Render help popup on top if shown
@@ -127,6 +280,49 @@ pub fn render_ui(f: &mut Frame, state: &mut AppState, styles: &UiStyles) {
 
     // Render notification (save/reset confirmations) at the bottom
     render_notification(f, state);
+
+    if styles.monochrome {
+        strip_colors(f.buffer_mut());
+    }
+}
+
+/// Removes every color from a rendered frame, keeping bold and other modifiers.
+/// Popups paint a black background, anything else with a background is a
+/// highlight (e.g. a menu's selected item), so those cells are reversed instead.
+fn strip_colors(buffer: &mut ratatui::buffer::Buffer) {
+    for cell in buffer.content.iter_mut() {
+        if !matches!(cell.bg, Color::Reset | Color::Black) {
+            cell.modifier.insert(Modifier::REVERSED);
+        }
+        cell.fg = Color::Reset;
+        cell.bg = Color::Reset;
+    }
+}
+
+/// Most common keys, shown in the footer as (key, description)
+const FOOTER_KEYS: [(&str, &str); 8] = [
+    ("q", "quit"),
+    ("/", "search"),
+    ("enter", "menu"),
+    ("→", "logs"),
+    ("s", "sort"),
+    ("a", "all"),
+    ("?", "help"),
+    ("f", "hide"),
+];
+
+/// Renders the one-line key legend at the bottom of the screen
+fn render_footer(f: &mut Frame, area: Rect, styles: &UiStyles) {
+    let mut spans = Vec::with_capacity(FOOTER_KEYS.len() * 2);
+    for (key, description) in FOOTER_KEYS {
+        spans.push(Span::styled(format!(" {}", key), styles.header));
+        spans.push(Span::styled(
+            format!(":{} ", description),
+            Style::default().fg(Color::Gray),
+        ));
+    }
+
+    f.render_widget(Paragraph::new(Line::from(spans)), area);
 }
 
 /// Renders the search bar at the bottom of the screen (vi-style)
@@ -149,11 +345,15 @@ fn render_search_bar(
         format!("Filtering: {}", state.search_input.value())
     };
 
+    // Dimmed while a regex query doesn't compile (it matches as plain text)
+    let style = if state.search_regex_invalid() {
+        styles.search_bar.fg(Color::DarkGray)
+    } else {
+        styles.search_bar
+    };
+
     // Create a paragraph with the search text using the search_bar style
-    let search_widget = Paragraph::new(Line::from(vec![Span::styled(
-        search_text,
-        styles.search_bar,
-    )]));
+    let search_widget = Paragraph::new(Line::from(vec![Span::styled(search_text, style)]));
 
     f.render_widget(search_widget, area);
 
@@ -169,6 +369,50 @@ fn render_search_bar(
     }
 }
 
+/// Renders the log filter input over the bottom row of the log view
+fn render_log_filter_bar(f: &mut Frame, area: Rect, state: &AppState, styles: &UiStyles) {
+    const PREFIX: &str = "Filter logs: ";
+
+    let text = format!("{}{}", PREFIX, state.log_filter_input.value());
+    f.render_widget(
+        Paragraph::new(Line::from(Span::styled(text, styles.search_bar))),
+        area,
+    );
+
+    let cursor_x = area.x + (PREFIX.len() + state.log_filter_input.visual_cursor()) as u16;
+    f.set_cursor_position((cursor_x, area.y));
+}
+
+/// Renders the action prompt (Exec command, Rename) over the bottom row of the
+/// container list, followed by the reason the input is invalid, if any
+fn render_action_prompt(f: &mut Frame, area: Rect, state: &AppState, styles: &UiStyles) {
+    let Some((key, action)) = &state.action_prompt else {
+        return;
+    };
+    let name = state
+        .containers
+        .get(key)
+        .map_or(key.container_id.as_str(), |c| c.name.as_str());
+
+    let prefix = match action {
+        ContainerAction::Rename => format!("Rename {} to: ", name),
+        _ => format!("Exec in {}: ", name),
+    };
+    let value = state.action_prompt_input.value();
+    let mut spans = vec![Span::styled(
+        format!("{}{}", prefix, value),
+        styles.search_bar,
+    )];
+    if let Some(error) = state.action_prompt_error() {
+        spans.push(Span::styled(format!("  ({})", error), styles.high));
+    }
+    f.render_widget(Paragraph::new(Line::from(spans)), area);
+
+    let cursor_x =
+        area.x + (prefix.chars().count() + state.action_prompt_input.visual_cursor()) as u16;
+    f.set_cursor_position((cursor_x, area.y));
+}
+
 /// Renders connection error notifications in the top right corner
 fn render_error_notifications(f: &mut Frame, state: &AppState, styles: &UiStyles) {
     if state.connection_errors.is_empty() {
@@ -244,22 +488,20 @@ fn render_notification(f: &mut Frame, state: &AppState) {
         height: 3,
     };
 
-    // Use different styling based on whether it's a confirmation prompt
-    let is_confirm = state.reset_confirm_pending;
-    let border_style = if is_confirm {
-        Style::default().fg(Color::Yellow)
-    } else {
-        Style::default().fg(Color::Green)
-    };
-    let text_style = if is_confirm {
-        Style::default()
-            .fg(Color::Yellow)
-            .add_modifier(Modifier::BOLD)
+    // Prompts waiting for an answer are yellow, failures red and everything else green
+    let is_confirm = state.reset_confirm_pending
+        || state.quit_confirm_pending
+        || state.shell_confirm_pending.is_some()
+        || state.pending_row_number.is_some();
+    let color = if is_confirm {
+        Color::Yellow
+    } else if state.notification_is_error {
+        Color::Red
     } else {
-        Style::default()
-            .fg(Color::Green)
-            .add_modifier(Modifier::BOLD)
+        Color::Green
     };
+    let border_style = Style::default().fg(color);
+    let text_style = Style::default().fg(color).add_modifier(Modifier::BOLD);
 
     let notification_widget = Paragraph::new(Line::from(vec![Span::styled(message, text_style)]))
         .block(
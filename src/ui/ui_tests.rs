@@ -3,7 +3,7 @@ mod tests {
     use crate::core::app_state::AppState;
     use crate::core::types::{
         AppEvent, Column, ColumnConfig, Container, ContainerKey, ContainerState, ContainerStats,
-        ViewState,
+        HealthStatus, ViewState,
     };
     use crate::ui::render::{UiStyles, render_ui};
     use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
@@ -89,7 +89,9 @@ mod tests {
 
         Container {
             id: id.to_string(),
+            full_id: id.to_string(),
             name: name.to_string(),
+            image: "nginx:latest".to_string(),
             state: ContainerState::Running,
             health: None,
             created,
@@ -102,11 +104,17 @@ mod tests {
                 network_rx_bytes_per_sec: net_rx,
                 disk_read_bytes_per_sec: disk_read,
                 disk_write_bytes_per_sec: disk_write,
+                disk_io_unavailable: false,
             },
             host_id: host_id.to_string(),
             dozzle_url: None,
             restart_count: None,
             compose_project: None,
+            size: None,
+            health_check: None,
+            last_stats: None,
+            exit_code: None,
+            ports: Vec::new(),
         }
     }
 
@@ -297,7 +305,7 @@ mod tests {
 
         // Create empty log state
         use crate::core::types::LogState;
-        let log_state = LogState::new(key.clone(), None);
+        let log_state = LogState::new(key.clone());
         state.log_state = Some(log_state);
 
         let backend = TestBackend::new(120, 25);
@@ -362,7 +370,7 @@ mod tests {
             .unwrap(),
         ];
 
-        let mut log_state = LogState::new(key.clone(), None);
+        let mut log_state = LogState::new(key.clone());
         log_state.set_entries(log_entries);
         state.log_state = Some(log_state);
 
@@ -422,7 +430,7 @@ mod tests {
             .unwrap(),
         ];
 
-        let mut log_state = LogState::new(key.clone(), None);
+        let mut log_state = LogState::new(key.clone());
         log_state.set_entries(log_entries);
         log_state.scroll_offset = 5;
         state.log_state = Some(log_state);
@@ -472,7 +480,9 @@ mod tests {
         let stopped_containers = vec![
             Container {
                 id: "stop12345678".to_string(),
+                full_id: "stop12345678".to_string(),
                 name: "old-redis".to_string(),
+                image: "nginx:latest".to_string(),
                 state: ContainerState::Exited,
                 health: None,
                 created: Some(Utc::now() - chrono::Duration::days(1)),
@@ -481,10 +491,17 @@ mod tests {
                 dozzle_url: None,
                 restart_count: None,
                 compose_project: None,
+                size: None,
+                health_check: None,
+                last_stats: None,
+                exit_code: None,
+                ports: Vec::new(),
             },
             Container {
                 id: "dead12345678".to_string(),
+                full_id: "dead12345678".to_string(),
                 name: "failed-app".to_string(),
+                image: "nginx:latest".to_string(),
                 state: ContainerState::Dead,
                 health: None,
                 created: Some(Utc::now() - chrono::Duration::hours(3)),
@@ -493,6 +510,11 @@ mod tests {
                 dozzle_url: None,
                 restart_count: None,
                 compose_project: None,
+                size: None,
+                health_check: None,
+                last_stats: None,
+                exit_code: None,
+                ports: Vec::new(),
             },
         ];
 
@@ -801,6 +823,81 @@ mod tests {
         assert_snapshot_with_redaction!(output);
     }
 
+    #[test]
+    fn test_disk_usage_popup() {
+        use crate::core::types::{DiskUsageCategory, DiskUsageSummary};
+
+        let mut state = create_test_app_state();
+
+        let container =
+            create_test_container("abc123def456", "nginx", "local", 25.0, 50.0, 1024.0, 2048.0);
+        let key = ContainerKey::new("local".to_string(), "abc123def456".to_string());
+        state.containers.insert(key, container);
+        state.force_sort_containers();
+        state.table_state.select(Some(0));
+
+        state.view_state = ViewState::DiskUsage;
+        state.disk_usage.insert(
+            "local".to_string(),
+            Ok(DiskUsageSummary {
+                images: DiskUsageCategory {
+                    total_count: 12,
+                    active_count: 5,
+                    total_size: 4_294_967_296,
+                    reclaimable: 1_073_741_824,
+                },
+                containers: DiskUsageCategory {
+                    total_count: 7,
+                    active_count: 5,
+                    total_size: 52_428_800,
+                    reclaimable: 0,
+                },
+                volumes: DiskUsageCategory {
+                    total_count: 3,
+                    active_count: 2,
+                    total_size: 536_870_912,
+                    reclaimable: 268_435_456,
+                },
+                build_cache: DiskUsageCategory::default(),
+            }),
+        );
+        state.disk_usage.insert(
+            "user@server1".to_string(),
+            Err("Failed to fetch disk usage: connection refused".to_string()),
+        );
+
+        let styles = UiStyles::default();
+        let backend = TestBackend::new(100, 30);
+        let mut terminal = Terminal::new(backend).unwrap();
+
+        terminal
+            .draw(|f| {
+                render_ui(f, &mut state, &styles);
+            })
+            .unwrap();
+
+        let buffer = terminal.backend().buffer().clone();
+        let output = buffer_to_string(&buffer);
+        assert_snapshot_with_redaction!(output);
+    }
+
+    #[test]
+    fn test_disk_usage_popup_toggles_with_d() {
+        let mut state = create_test_app_state();
+
+        state.handle_event(AppEvent::KeyInput(KeyEvent::new(
+            KeyCode::Char('d'),
+            KeyModifiers::NONE,
+        )));
+        assert_eq!(state.view_state, ViewState::DiskUsage);
+
+        state.handle_event(AppEvent::KeyInput(KeyEvent::new(
+            KeyCode::Esc,
+            KeyModifiers::NONE,
+        )));
+        assert_eq!(state.view_state, ViewState::ContainerList);
+    }
+
     #[test]
     fn test_container_list_with_hidden_columns() {
         let mut state = create_test_app_state();
@@ -937,4 +1034,2929 @@ mod tests {
         )));
         assert_eq!(state.table_state.selected(), Some(0));
     }
+
+    #[test]
+    fn test_mouse_wheel_scrolls_list_without_moving_selection() {
+        let mut state = create_test_app_state();
+        populate_containers(&mut state, 50);
+        state.last_list_viewport_height = 10;
+
+        state.handle_event(AppEvent::MouseScrollDown);
+        state.handle_event(AppEvent::MouseScrollDown);
+        assert_eq!(state.list_scroll, Some(6));
+        assert_eq!(state.table_state.selected(), Some(0));
+
+        // Clamped so the last page stays full
+        for _ in 0..20 {
+            state.handle_event(AppEvent::MouseScrollDown);
+        }
+        assert_eq!(state.list_scroll, Some(40));
+
+        state.handle_event(AppEvent::MouseScrollUp);
+        assert_eq!(state.list_scroll, Some(37));
+
+        // Keyboard navigation hands the viewport back to the selection
+        state.handle_event(AppEvent::KeyInput(KeyEvent::new(
+            KeyCode::Down,
+            KeyModifiers::NONE,
+        )));
+        assert_eq!(state.list_scroll, None);
+        assert_eq!(state.table_state.offset(), 37);
+        assert_eq!(state.table_state.selected(), Some(1));
+    }
+
+    #[test]
+    fn test_scroll_lines_sets_wheel_step() {
+        let mut state = create_test_app_state();
+        populate_containers(&mut state, 50);
+        state.last_list_viewport_height = 10;
+        state.scroll_lines = 10;
+
+        state.handle_event(AppEvent::MouseScrollDown);
+        assert_eq!(state.list_scroll, Some(10));
+
+        // Zero would make the wheel do nothing; it still moves one row
+        state.scroll_lines = 0;
+        state.handle_event(AppEvent::MouseScrollUp);
+        assert_eq!(state.list_scroll, Some(9));
+    }
+
+    #[test]
+    fn test_scrollbar_when_list_overflows() {
+        let mut state = create_test_app_state();
+        populate_containers(&mut state, 30);
+        state.list_scroll = Some(10);
+
+        let styles = UiStyles::default();
+        let mut terminal = Terminal::new(TestBackend::new(100, 16)).unwrap();
+        terminal
+            .draw(|f| render_ui(f, &mut state, &styles))
+            .unwrap();
+
+        let output = buffer_to_string(terminal.backend().buffer());
+        assert_snapshot_with_redaction!(output);
+    }
+
+    #[test]
+    fn test_connection_errors_expire_after_notification_ttl() {
+        use crate::ui::render::cleanup_expired_errors;
+
+        let mut state = create_test_app_state();
+        state.handle_event(AppEvent::ConnectionError(
+            "server1".to_string(),
+            "connection refused".to_string(),
+        ));
+        cleanup_expired_errors(&mut state);
+        assert!(state.connection_errors.contains_key("server1"));
+
+        state.notification_ttl_secs = 0;
+        cleanup_expired_errors(&mut state);
+        assert!(state.connection_errors.is_empty());
+    }
+
+    #[test]
+    fn test_copy_recent_errors() {
+        use crate::core::types::{ContainerAction, RenderAction};
+
+        let mut state = create_test_app_state();
+        let copy = |state: &mut AppState, c: char| {
+            state.handle_event(AppEvent::KeyInput(KeyEvent::new(
+                KeyCode::Char(c),
+                KeyModifiers::NONE,
+            )))
+        };
+
+        assert_eq!(copy(&mut state, 'e'), RenderAction::Render);
+        assert!(state.notification.is_some());
+
+        let key = ContainerKey::new("local".to_string(), "abc123".to_string());
+        state.handle_event(AppEvent::ConnectionError(
+            "server1".to_string(),
+            "connection refused".to_string(),
+        ));
+        state.handle_event(AppEvent::ActionError(
+            key,
+            ContainerAction::Stop,
+            "permission denied".to_string(),
+        ));
+
+        assert_eq!(
+            copy(&mut state, 'e'),
+            RenderAction::CopyToClipboard("[local] Stop abc123 failed: permission denied".into())
+        );
+        assert_eq!(
+            copy(&mut state, 'E'),
+            RenderAction::CopyToClipboard(
+                "server1: connection refused\n[local] Stop abc123 failed: permission denied".into()
+            )
+        );
+
+        // Only the most recent errors are kept
+        for i in 0..30 {
+            state.handle_event(AppEvent::ConnectionError(
+                format!("host{i}"),
+                "timeout".to_string(),
+            ));
+        }
+        assert_eq!(state.recent_errors.len(), 20);
+        assert_eq!(
+            state.recent_errors.front().map(String::as_str),
+            Some("host10: timeout")
+        );
+    }
+
+    #[test]
+    fn test_custom_actions_in_action_menu_and_output_popup() {
+        use crate::core::types::{CustomAction, CustomActionRun};
+
+        let mut state = create_test_app_state();
+        state.custom_actions = vec![CustomAction {
+            label: "Tail access log".to_string(),
+            command: "tail /var/log/nginx/access.log".to_string(),
+        }];
+        let container =
+            create_test_container("abc123456789", "nginx", "local", 25.5, 45.2, 1024.0, 2048.0);
+        let key = ContainerKey::new(container.host_id.clone(), container.id.clone());
+        state.containers.insert(key.clone(), container);
+        state.sorted_container_keys.push(key.clone());
+        state.table_state.select(Some(0));
+        state.view_state = ViewState::ActionMenu(key.clone());
+        state.action_menu_state.select(Some(0));
+
+        // Custom actions come after the seven built-in actions of a running container
+        for _ in 0..10 {
+            state.handle_event(AppEvent::KeyInput(KeyEvent::new(
+                KeyCode::Down,
+                KeyModifiers::NONE,
+            )));
+        }
+        assert_eq!(state.action_menu_state.selected(), Some(7));
+
+        let styles = UiStyles::default();
+        let mut terminal = Terminal::new(TestBackend::new(100, 20)).unwrap();
+        terminal
+            .draw(|f| render_ui(f, &mut state, &styles))
+            .unwrap();
+        let output = buffer_to_string(terminal.backend().buffer());
+        assert!(output.contains("$  Tail access log"));
+
+        // Results for the open popup are shown; Esc closes it
+        state.view_state = ViewState::CustomActionOutput;
+        state.custom_action_run = Some(CustomActionRun {
+            container_key: key.clone(),
+            label: "Tail access log".to_string(),
+            output: None,
+            scroll_back: 0,
+        });
+        state.handle_event(AppEvent::CustomActionFinished(
+            key,
+            "Tail access log".to_string(),
+            Ok("10.0.0.1 - GET / 200\n10.0.0.2 - GET /health 200\n".to_string()),
+        ));
+        terminal
+            .draw(|f| render_ui(f, &mut state, &styles))
+            .unwrap();
+        let output = buffer_to_string(terminal.backend().buffer());
+        assert_snapshot_with_redaction!(output);
+
+        state.handle_event(AppEvent::KeyInput(KeyEvent::new(
+            KeyCode::Esc,
+            KeyModifiers::NONE,
+        )));
+        assert_eq!(state.view_state, ViewState::ContainerList);
+        assert!(state.custom_action_run.is_none());
+    }
+
+    #[test]
+    fn test_host_picker_fuzzy_filters_and_jumps() {
+        use crate::core::types::HostStatus;
+
+        let mut state = create_test_app_state();
+        for (i, host) in ["local", "ssh://prod-web-1", "ssh://prod-web-2"]
+            .iter()
+            .enumerate()
+        {
+            let container = create_test_container(
+                &format!("abc{i}"),
+                &format!("app{i}"),
+                host,
+                1.0,
+                1.0,
+                0.0,
+                0.0,
+            );
+            let key = ContainerKey::new(container.host_id.clone(), container.id.clone());
+            state.containers.insert(key.clone(), container);
+            state.sorted_container_keys.push(key);
+            state
+                .host_status
+                .insert(host.to_string(), HostStatus::Connected);
+        }
+        state.table_state.select(Some(0));
+
+        let press = |state: &mut AppState, code: KeyCode| {
+            state.handle_event(AppEvent::KeyInput(KeyEvent::new(code, KeyModifiers::NONE)));
+        };
+        press(&mut state, KeyCode::Char('H'));
+        assert_eq!(state.view_state, ViewState::HostPicker);
+        for c in "pw2".chars() {
+            press(&mut state, KeyCode::Char(c));
+        }
+        assert_eq!(state.host_picker_matches(), vec!["ssh://prod-web-2"]);
+
+        let styles = UiStyles::default();
+        let mut terminal = Terminal::new(TestBackend::new(100, 20)).unwrap();
+        terminal
+            .draw(|f| render_ui(f, &mut state, &styles))
+            .unwrap();
+        let output = buffer_to_string(terminal.backend().buffer());
+        assert_snapshot_with_redaction!(output);
+
+        // Enter lists only that host's containers and names it in the title
+        state.show_fleet_totals = true;
+        press(&mut state, KeyCode::Enter);
+        assert_eq!(state.view_state, ViewState::ContainerList);
+        assert_eq!(state.host_filter.as_deref(), Some("ssh://prod-web-2"));
+        assert_eq!(state.table_state.selected(), Some(0));
+        terminal
+            .draw(|f| render_ui(f, &mut state, &styles))
+            .unwrap();
+        let output = buffer_to_string(terminal.backend().buffer());
+        assert!(output.contains("1 running / 1 total - host ssh://prod-web-2"));
+        assert!(output.contains("fleet: 1% CPU"));
+        assert!(output.contains("app2"));
+        assert!(!output.contains("app0") && !output.contains("app1"));
+
+        // Choosing the same host again shows every host, as does Esc
+        press(&mut state, KeyCode::Char('H'));
+        press(&mut state, KeyCode::Char('2'));
+        press(&mut state, KeyCode::Enter);
+        assert!(state.host_filter.is_none());
+        assert_eq!(state.sorted_container_keys.len(), 3);
+        state.host_filter = Some("local".to_string());
+        state.force_sort_containers();
+        assert_eq!(state.sorted_container_keys.len(), 1);
+        press(&mut state, KeyCode::Esc);
+        assert!(state.host_filter.is_none());
+        assert_eq!(state.sorted_container_keys.len(), 3);
+    }
+
+    #[test]
+    fn test_action_menu_shows_health_check_command() {
+        let mut state = create_test_app_state();
+        let styles = UiStyles::default();
+
+        let mut container =
+            create_test_container("abc123456789", "postgres", "local", 5.0, 20.0, 0.0, 0.0);
+        container.health = Some(HealthStatus::Unhealthy);
+        container.health_check = Some("pg_isready -U postgres".to_string());
+        let key = ContainerKey::new(container.host_id.clone(), container.id.clone());
+        state.containers.insert(key.clone(), container);
+        state.sorted_container_keys.push(key.clone());
+        state.table_state.select(Some(0));
+        state.view_state = ViewState::ActionMenu(key);
+        state.action_menu_state.select(Some(0));
+
+        let mut terminal = Terminal::new(TestBackend::new(100, 20)).unwrap();
+        terminal
+            .draw(|f| render_ui(f, &mut state, &styles))
+            .unwrap();
+        let output = buffer_to_string(terminal.backend().buffer());
+
+        assert!(output.contains("Health: pg_isready -U postgres"));
+        assert_snapshot_with_redaction!(output);
+    }
+
+    #[test]
+    fn test_sort_by_total_disk_io() {
+        let mut state = create_test_app_state();
+        populate_containers(&mut state, 3);
+
+        // c0 reads the most and c2 writes the most, but c1 has the highest total
+        let rates = [(500.0, 0.0), (300.0, 300.0), (0.0, 550.0)];
+        for (i, (read, write)) in rates.into_iter().enumerate() {
+            let key = state.sorted_container_keys[i].clone();
+            let stats = &mut state.containers.get_mut(&key).unwrap().stats;
+            stats.disk_read_bytes_per_sec = read;
+            stats.disk_write_bytes_per_sec = write;
+        }
+
+        state.handle_event(AppEvent::SetSortField(Column::DiskIo));
+
+        let names: Vec<&str> = state
+            .sorted_container_keys
+            .iter()
+            .map(|key| state.containers[key].name.as_str())
+            .collect();
+        assert_eq!(names, vec!["c1", "c2", "c0"]);
+    }
+
+    #[test]
+    fn test_uptime_sort_orders_missing_created_times_stably() {
+        let mut state = create_test_app_state();
+        let mut containers = Vec::new();
+        for (id, name, hours) in [
+            ("ddd444444444", "no-time-b", None),
+            ("aaa111111111", "old", Some(5)),
+            ("ccc333333333", "no-time-a", None),
+            ("bbb222222222", "new", Some(1)),
+        ] {
+            let mut container = create_test_container(id, name, "local", 0.0, 0.0, 0.0, 0.0);
+            container.created = hours.map(|h| chrono::Utc::now() - chrono::Duration::hours(h));
+            containers.push(container);
+        }
+        state.handle_event(AppEvent::InitialContainerList(
+            "local".to_string(),
+            containers,
+        ));
+        let names = |state: &AppState| -> Vec<String> {
+            state
+                .sorted_container_keys
+                .iter()
+                .map(|key| state.containers[key].name.clone())
+                .collect()
+        };
+
+        // Newest first; containers without a time come last, in ID order
+        state.sort_state = crate::core::types::SortState::new(Column::Uptime);
+        for _ in 0..5 {
+            state.force_sort_containers();
+            assert_eq!(names(&state), ["new", "old", "no-time-a", "no-time-b"]);
+        }
+
+        // Ascending puts them first, still in ID order
+        state.handle_event(AppEvent::SetSortField(Column::Uptime));
+        assert_eq!(names(&state), ["no-time-a", "no-time-b", "old", "new"]);
+    }
+
+    #[tokio::test]
+    async fn test_missing_created_time_is_looked_up_once_when_selected() {
+        use crate::docker::connection::DockerHost;
+
+        let mut state = create_test_app_state();
+        let docker = bollard::Docker::connect_with_http(
+            "tcp://localhost:2375",
+            4,
+            bollard::API_DEFAULT_VERSION,
+        )
+        .unwrap();
+        let host = DockerHost::new("local".to_string(), docker, None, HashMap::new());
+        state.connected_hosts.insert(host.host_id.clone(), host);
+        let mut container =
+            create_test_container("aaa111111111", "api", "local", 0.0, 0.0, 0.0, 0.0);
+        container.created = None;
+        let key = ContainerKey::new("local".to_string(), container.id.clone());
+        state.handle_event(AppEvent::InitialContainerList(
+            "local".to_string(),
+            vec![container],
+        ));
+        assert!(state.created_lookups.contains(&key));
+
+        let created = chrono::Utc::now() - chrono::Duration::hours(3);
+        state.handle_event(AppEvent::ContainerCreatedAt(key.clone(), created));
+        assert_eq!(state.containers[&key].created, Some(created));
+
+        let styles = UiStyles::default();
+        let mut terminal = Terminal::new(TestBackend::new(120, 10)).unwrap();
+        terminal
+            .draw(|f| render_ui(f, &mut state, &styles))
+            .unwrap();
+        let output = buffer_to_string(terminal.backend().buffer());
+        assert!(output.contains("3 hours ago"), "{}", output);
+        assert!(!output.contains("Unknown"));
+    }
+
+    #[test]
+    fn test_log_display_preferences_carry_across_containers() {
+        use crate::docker::logs::LogEntry;
+
+        let mut state = create_test_app_state();
+        populate_containers(&mut state, 2);
+        let press = |state: &mut AppState, code: KeyCode| {
+            state.handle_event(AppEvent::KeyInput(KeyEvent::new(code, KeyModifiers::NONE)));
+        };
+
+        // Turn line numbers on in the first container's logs
+        press(&mut state, KeyCode::Char('l'));
+        press(&mut state, KeyCode::Char('n'));
+        assert!(state.show_log_line_numbers);
+        press(&mut state, KeyCode::Char('h'));
+
+        // ...and they are still on for the next container
+        press(&mut state, KeyCode::Down);
+        press(&mut state, KeyCode::Char('l'));
+        assert!(state.show_log_line_numbers);
+
+        let key = state.sorted_container_keys[1].clone();
+        state.show_log_timestamps = false;
+        for i in 0..12 {
+            let line = format!("2025-10-29T10:15:{:02}Z Log line {}", i, i);
+            state.handle_event(AppEvent::LogLine(
+                key.clone(),
+                LogEntry::parse(&line).unwrap(),
+            ));
+        }
+
+        let styles = UiStyles::default();
+        let mut terminal = Terminal::new(TestBackend::new(60, 10)).unwrap();
+        terminal
+            .draw(|f| render_ui(f, &mut state, &styles))
+            .unwrap();
+        let output = buffer_to_string(terminal.backend().buffer());
+
+        assert!(output.contains("12 Log line 11"));
+        assert!(!output.contains("2025-10-29"));
+        assert_snapshot_with_redaction!(output);
+    }
+
+    #[test]
+    fn test_fleet_totals_in_title() {
+        let mut state = create_test_app_state();
+        for (id, host, cpu, memory) in [
+            ("aaa111111111", "local", 150.0, 40.0),
+            ("bbb222222222", "server1", 50.0, 80.0),
+        ] {
+            let container = create_test_container(id, id, host, cpu, memory, 0.0, 0.0);
+            let key = ContainerKey::new(container.host_id.clone(), container.id.clone());
+            state.containers.insert(key.clone(), container);
+            state.sorted_container_keys.push(key);
+        }
+        // Stopped containers don't count towards the totals
+        let mut stopped =
+            create_test_container("ccc333333333", "ccc", "server1", 90.0, 90.0, 0.0, 0.0);
+        stopped.state = ContainerState::Exited;
+        let key = ContainerKey::new(stopped.host_id.clone(), stopped.id.clone());
+        state.containers.insert(key, stopped);
+
+        let styles = UiStyles::default();
+        let render_title = |state: &mut AppState| {
+            let mut terminal = Terminal::new(TestBackend::new(120, 10)).unwrap();
+            terminal.draw(|f| render_ui(f, state, &styles)).unwrap();
+            buffer_to_string(terminal.backend().buffer())
+                .lines()
+                .next()
+                .unwrap()
+                .to_string()
+        };
+
+        assert!(!render_title(&mut state).contains("fleet"));
+
+        state.show_fleet_totals = true;
+        assert!(render_title(&mut state).contains("fleet: 200% CPU, 60% mem avg"));
+    }
+
+    #[test]
+    fn test_sort_by_network_traffic_key() {
+        let mut state = create_test_app_state();
+        populate_containers(&mut state, 3);
+
+        // c0 transmits the most and c2 receives the most, but c1 has the highest total
+        let rates = [(500.0, 0.0), (300.0, 300.0), (0.0, 550.0)];
+        for (i, (tx, rx)) in rates.into_iter().enumerate() {
+            let key = state.sorted_container_keys[i].clone();
+            let stats = &mut state.containers.get_mut(&key).unwrap().stats;
+            stats.network_tx_bytes_per_sec = tx;
+            stats.network_rx_bytes_per_sec = rx;
+        }
+
+        state.handle_event(AppEvent::KeyInput(KeyEvent::new(
+            KeyCode::Char('t'),
+            KeyModifiers::NONE,
+        )));
+        assert_eq!(state.sort_state.field, Column::NetIo);
+
+        let names: Vec<&str> = state
+            .sorted_container_keys
+            .iter()
+            .map(|key| state.containers[key].name.as_str())
+            .collect();
+        assert_eq!(names, vec!["c1", "c2", "c0"]);
+
+        // The Net I/O column is hidden, so the arrow goes on Net TX and Net RX
+        let styles = UiStyles::default();
+        let mut terminal = Terminal::new(TestBackend::new(120, 10)).unwrap();
+        terminal
+            .draw(|f| render_ui(f, &mut state, &styles))
+            .unwrap();
+        let output = buffer_to_string(terminal.backend().buffer());
+        assert!(output.contains("Net TX ▼"));
+        assert!(output.contains("Net RX ▼"));
+    }
+
+    #[test]
+    fn test_host_separators_group_by_host() {
+        let mut state = create_test_app_state();
+        for (id, name, host, cpu) in [
+            ("aaa111111111", "web", "local", 50.0),
+            ("bbb222222222", "db", "server1", 40.0),
+            ("ccc333333333", "cache", "local", 30.0),
+        ] {
+            let container = create_test_container(id, name, host, cpu, 10.0, 0.0, 0.0);
+            let key = ContainerKey::new(container.host_id.clone(), container.id.clone());
+            state.containers.insert(key, container);
+        }
+        state.sort_state = crate::core::types::SortState::new(Column::Cpu);
+        state.host_separators = true;
+        state.force_sort_containers();
+
+        // Hosts stay together, sorted by CPU within each host
+        let names: Vec<_> = state
+            .sorted_container_keys
+            .iter()
+            .map(|key| state.containers[key].name.as_str())
+            .collect();
+        assert_eq!(names, ["web", "cache", "db"]);
+
+        // Select "db": the highlight must land on its row, past both separators
+        state.table_state.select(Some(2));
+        let styles = UiStyles::default();
+        let mut terminal = Terminal::new(TestBackend::new(100, 12)).unwrap();
+        terminal
+            .draw(|f| render_ui(f, &mut state, &styles))
+            .unwrap();
+
+        let buffer = terminal.backend().buffer();
+        let output = buffer_to_string(buffer);
+        let db_line = output.lines().position(|l| l.contains(" db ")).unwrap();
+        assert_eq!(buffer[(2, db_line as u16)].bg, styles.selected.bg.unwrap());
+        assert_snapshot_with_redaction!(output);
+    }
+
+    #[test]
+    fn test_jump_to_row_number() {
+        let mut state = create_test_app_state();
+        populate_containers(&mut state, 30);
+        let key = |code| AppEvent::KeyInput(KeyEvent::new(code, KeyModifiers::NONE));
+
+        state.handle_event(key(KeyCode::Char('1')));
+        state.handle_event(key(KeyCode::Char('2')));
+        assert_eq!(state.pending_row_number, Some(12));
+        assert_eq!(state.table_state.selected(), Some(0));
+
+        state.handle_event(key(KeyCode::Enter));
+        assert_eq!(state.table_state.selected(), Some(11));
+        assert_eq!(state.pending_row_number, None);
+        assert_eq!(state.view_state, ViewState::ContainerList);
+
+        // Past the end selects the last row; G works like Enter
+        state.handle_event(key(KeyCode::Char('9')));
+        state.handle_event(key(KeyCode::Char('9')));
+        state.handle_event(key(KeyCode::Char('G')));
+        assert_eq!(state.table_state.selected(), Some(29));
+
+        // Esc cancels without moving
+        state.handle_event(key(KeyCode::Char('3')));
+        state.handle_event(key(KeyCode::Esc));
+        state.handle_event(key(KeyCode::Enter));
+        assert_eq!(state.table_state.selected(), Some(29));
+        assert!(matches!(state.view_state, ViewState::ActionMenu(_)));
+    }
+
+    #[test]
+    fn test_row_numbers_gutter() {
+        let mut state = create_test_app_state();
+        populate_containers(&mut state, 3);
+        state.show_row_numbers = true;
+
+        let styles = UiStyles::default();
+        let mut terminal = Terminal::new(TestBackend::new(100, 10)).unwrap();
+        terminal
+            .draw(|f| render_ui(f, &mut state, &styles))
+            .unwrap();
+        assert_snapshot_with_redaction!(buffer_to_string(terminal.backend().buffer()));
+    }
+
+    #[test]
+    fn test_summary_follows_filter_and_height() {
+        let mut state = create_test_app_state();
+        for (id, name, cpu, tx) in [
+            ("aaa111111111", "web-1", 20.0, 1024.0),
+            ("bbb222222222", "web-2", 30.0, 2048.0),
+            ("ccc333333333", "db", 40.0, 4096.0),
+        ] {
+            let container = create_test_container(id, name, "local", cpu, 10.0, tx, 0.0);
+            let key = ContainerKey::new(container.host_id.clone(), container.id.clone());
+            state.containers.insert(key, container);
+        }
+        state.search_input = tui_input::Input::new("web".to_string());
+        state.force_sort_containers();
+
+        let styles = UiStyles::default();
+        let mut render = |height| {
+            let mut terminal = Terminal::new(TestBackend::new(100, height)).unwrap();
+            terminal
+                .draw(|f| render_ui(f, &mut state, &styles))
+                .unwrap();
+            buffer_to_string(terminal.backend().buffer())
+        };
+
+        // Only the filtered containers are summed
+        let output = render(12);
+        assert!(output.contains("Total (2 running): CPU 50.0%  Mem 20.0%  Net TX 3.0KB/s"));
+
+        // Too short to spare a row
+        assert!(!render(8).contains("Total ("));
+    }
+
+    #[test]
+    fn test_log_view_host_disconnected() {
+        use crate::docker::logs::LogEntry;
+
+        let mut state = create_test_app_state();
+        populate_containers(&mut state, 1);
+        let key = state.sorted_container_keys[0].clone();
+        let press = |state: &mut AppState, code: KeyCode| {
+            state.handle_event(AppEvent::KeyInput(KeyEvent::new(code, KeyModifiers::NONE)));
+        };
+
+        // Losing the host while its logs are open marks the view, keeping what arrived
+        press(&mut state, KeyCode::Char('l'));
+        state.handle_event(AppEvent::LogLine(
+            key.clone(),
+            LogEntry::parse("2025-10-29T10:15:00Z Last words").unwrap(),
+        ));
+        state.handle_event(AppEvent::ConnectionError(
+            key.host_id.clone(),
+            "Lost connection to Docker daemon".to_string(),
+        ));
+        let log_state = state.log_state.as_ref().unwrap();
+        assert!(log_state.host_disconnected);
+        assert_eq!(log_state.log_entries.len(), 1);
+
+        // Reopening the logs says why they are empty
+        press(&mut state, KeyCode::Char('h'));
+        press(&mut state, KeyCode::Char('l'));
+        assert!(state.log_state.as_ref().unwrap().host_disconnected);
+
+        // Once the connection error toast has expired
+        state.connection_errors.clear();
+        let styles = UiStyles::default();
+        let mut terminal = Terminal::new(TestBackend::new(80, 8)).unwrap();
+        terminal
+            .draw(|f| {
+                render_ui(f, &mut state, &styles);
+            })
+            .unwrap();
+
+        let output = buffer_to_string(terminal.backend().buffer());
+        assert!(output.contains("[HOST DISCONNECTED]"));
+        assert_snapshot_with_redaction!(output);
+    }
+
+    #[test]
+    fn test_terminal_title_counts_running_containers() {
+        use crate::ui::render::terminal_title;
+
+        let mut state = create_test_app_state();
+        assert_eq!(terminal_title(&state), "dtop — 0 containers");
+
+        populate_containers(&mut state, 3);
+        assert_eq!(terminal_title(&state), "dtop — 3 containers");
+
+        // Stopped containers don't count
+        for container in state.containers.values_mut().skip(1) {
+            container.state = ContainerState::Exited;
+        }
+        assert_eq!(terminal_title(&state), "dtop — 1 container");
+    }
+
+    #[test]
+    fn test_toggle_log_timestamps_reformats_lines() {
+        use crate::docker::logs::LogEntry;
+
+        let mut state = create_test_app_state();
+        populate_containers(&mut state, 1);
+        let key = state.sorted_container_keys[0].clone();
+        let press = |state: &mut AppState, code: KeyCode| {
+            state.handle_event(AppEvent::KeyInput(KeyEvent::new(code, KeyModifiers::NONE)));
+        };
+        let first_line =
+            |state: &AppState| state.log_state.as_ref().unwrap().formatted_lines[0].to_string();
+
+        press(&mut state, KeyCode::Char('l'));
+        state.handle_event(AppEvent::LogLine(
+            key.clone(),
+            LogEntry::parse("2025-10-29T10:15:00Z Server started").unwrap(),
+        ));
+        let with_timestamp = first_line(&state);
+        assert!(with_timestamp.ends_with("Server started"));
+        assert_ne!(with_timestamp, "Server started");
+
+        // Lines already shown lose their timestamp, and new lines follow suit
+        press(&mut state, KeyCode::Char('T'));
+        assert!(!state.show_log_timestamps);
+        assert_eq!(first_line(&state), "Server started");
+        state.handle_event(AppEvent::LogLine(
+            key.clone(),
+            LogEntry::parse("2025-10-29T10:15:01Z Ready").unwrap(),
+        ));
+        assert_eq!(
+            state.log_state.as_ref().unwrap().formatted_lines[1].to_string(),
+            "Ready"
+        );
+
+        press(&mut state, KeyCode::Char('T'));
+        assert_eq!(first_line(&state), with_timestamp);
+
+        // Only the log view reacts to 'T'
+        press(&mut state, KeyCode::Char('h'));
+        press(&mut state, KeyCode::Char('T'));
+        assert!(state.show_log_timestamps);
+    }
+
+    #[test]
+    fn test_absolute_created_times_honor_timezone() {
+        use crate::core::types::{DisplayTimezone, TimeFormat};
+        use crate::docker::logs::LogEntry;
+
+        let mut state = create_test_app_state();
+        populate_containers(&mut state, 1);
+        let key = state.sorted_container_keys[0].clone();
+        state.containers.get_mut(&key).unwrap().created = Some(
+            chrono::DateTime::parse_from_rfc3339("2025-10-29T23:15:00Z")
+                .unwrap()
+                .with_timezone(&chrono::Utc),
+        );
+        state.time_format = TimeFormat::Absolute;
+        state.timezone = DisplayTimezone::Utc;
+
+        let styles = UiStyles::default();
+        let mut terminal = Terminal::new(TestBackend::new(120, 8)).unwrap();
+        terminal
+            .draw(|f| {
+                render_ui(f, &mut state, &styles);
+            })
+            .unwrap();
+        assert!(buffer_to_string(terminal.backend().buffer()).contains("Oct 29 23:15"));
+
+        // Log timestamps use the same zone
+        state.handle_event(AppEvent::KeyInput(KeyEvent::new(
+            KeyCode::Char('l'),
+            KeyModifiers::NONE,
+        )));
+        state.handle_event(AppEvent::LogLine(
+            key,
+            LogEntry::parse("2025-10-29T23:15:00Z Started").unwrap(),
+        ));
+        assert_eq!(
+            state.log_state.as_ref().unwrap().formatted_lines[0].to_string(),
+            "2025-10-29 23:15:00 Started"
+        );
+    }
+
+    #[test]
+    fn test_log_buffer_drops_oldest_lines_past_cap() {
+        use crate::docker::logs::LogEntry;
+
+        let mut state = create_test_app_state();
+        populate_containers(&mut state, 1);
+        let key = state.sorted_container_keys[0].clone();
+        state.log_max_lines = 20;
+        state.handle_event(AppEvent::KeyInput(KeyEvent::new(
+            KeyCode::Char('l'),
+            KeyModifiers::NONE,
+        )));
+
+        for i in 0..20 {
+            let line = format!("2025-10-29T10:15:{:02}Z Line {}", i, i);
+            state.handle_event(AppEvent::LogLine(
+                key.clone(),
+                LogEntry::parse(&line).unwrap(),
+            ));
+        }
+        assert_eq!(state.log_state.as_ref().unwrap().log_entries.len(), 20);
+        assert!(!state.log_state.as_ref().unwrap().truncated);
+
+        // One line over the cap trims to 90% of it, keeping the newest lines
+        state.handle_event(AppEvent::LogLine(
+            key.clone(),
+            LogEntry::parse("2025-10-29T10:15:20Z Line 20").unwrap(),
+        ));
+        let log_state = state.log_state.as_ref().unwrap();
+        assert_eq!(log_state.log_entries.len(), 18);
+        assert_eq!(log_state.formatted_lines.len(), 18);
+        assert!(log_state.formatted_lines[0].to_string().ends_with("Line 3"));
+        assert!(
+            log_state.formatted_lines[17]
+                .to_string()
+                .ends_with("Line 20")
+        );
+        assert_eq!(
+            log_state.oldest_timestamp,
+            Some(log_state.log_entries[0].timestamp)
+        );
+        assert!(log_state.has_more_history);
+        assert!(log_state.truncated);
+
+        // The title says older lines were dropped until they are fetched again
+        let styles = UiStyles::default();
+        let mut terminal = Terminal::new(TestBackend::new(100, 10)).unwrap();
+        terminal
+            .draw(|f| render_ui(f, &mut state, &styles))
+            .unwrap();
+        let output = buffer_to_string(terminal.backend().buffer());
+        assert!(output.contains("(local) (truncated) - Press ESC to return"));
+
+        // Still truncated while older lines are missing
+        state.handle_event(AppEvent::LogBatchPrepend(
+            key.clone(),
+            vec![LogEntry::parse("2025-10-29T10:15:02Z Line 2").unwrap()],
+            true,
+        ));
+        assert!(state.log_state.as_ref().unwrap().truncated);
+
+        // Fetching the rest of the history within the cap clears the label
+        state.log_max_lines = 21;
+        state.handle_event(AppEvent::LogBatchPrepend(
+            key,
+            vec![
+                LogEntry::parse("2025-10-29T10:15:00Z Line 0").unwrap(),
+                LogEntry::parse("2025-10-29T10:15:01Z Line 1").unwrap(),
+            ],
+            false,
+        ));
+        let log_state = state.log_state.as_ref().unwrap();
+        assert_eq!(log_state.log_entries.len(), 21);
+        assert!(!log_state.truncated);
+    }
+
+    #[test]
+    fn test_log_batch_prepend_keeps_under_cap() {
+        use crate::docker::logs::LogEntry;
+
+        let mut state = create_test_app_state();
+        populate_containers(&mut state, 1);
+        let key = state.sorted_container_keys[0].clone();
+        state.log_max_lines = 5;
+        state.handle_event(AppEvent::KeyInput(KeyEvent::new(
+            KeyCode::Char('l'),
+            KeyModifiers::NONE,
+        )));
+        let batch = |lines: std::ops::Range<usize>| -> Vec<LogEntry> {
+            lines
+                .map(|i| {
+                    LogEntry::parse(&format!("2025-10-29T10:15:{:02}Z Line {}", i, i)).unwrap()
+                })
+                .collect()
+        };
+
+        // The initial load keeps the newest lines
+        state.handle_event(AppEvent::LogBatchPrepend(key.clone(), batch(3..10), false));
+        let log_state = state.log_state.as_ref().unwrap();
+        assert_eq!(log_state.log_entries.len(), 5);
+        assert!(log_state.formatted_lines[0].to_string().ends_with("Line 5"));
+        assert!(log_state.has_more_history);
+        assert!(log_state.truncated);
+
+        // Paging back drops the newest lines instead, which can't come back
+        state.handle_event(AppEvent::LogBatchPrepend(key.clone(), batch(3..5), true));
+        let log_state = state.log_state.as_ref().unwrap();
+        assert_eq!(log_state.log_entries.len(), 5);
+        assert_eq!(log_state.formatted_lines.len(), 5);
+        assert!(log_state.formatted_lines[0].to_string().ends_with("Line 3"));
+        assert!(log_state.formatted_lines[4].to_string().ends_with("Line 7"));
+
+        state.handle_event(AppEvent::LogBatchPrepend(key, batch(0..3), false));
+        let log_state = state.log_state.as_ref().unwrap();
+        assert_eq!(log_state.log_entries.len(), 5);
+        assert!(log_state.formatted_lines[0].to_string().ends_with("Line 0"));
+        assert!(!log_state.has_more_history);
+        assert!(log_state.truncated);
+    }
+
+    #[test]
+    fn test_action_menu_title_names_container_state_and_host() {
+        let mut state = create_test_app_state();
+        let styles = UiStyles::default();
+        for (id, name, host) in [
+            ("abc123456789", "nginx", "local"),
+            ("def123456789", "postgres", "server1"),
+        ] {
+            let mut container = create_test_container(id, name, host, 1.0, 1.0, 0.0, 0.0);
+            if name == "postgres" {
+                container.state = ContainerState::Paused;
+            }
+            let key = ContainerKey::new(host.to_string(), id.to_string());
+            state.containers.insert(key.clone(), container);
+            state.sorted_container_keys.push(key);
+        }
+        state.view_state = ViewState::ActionMenu(state.sorted_container_keys[1].clone());
+        state.action_menu_state.select(Some(0));
+
+        let mut terminal = Terminal::new(TestBackend::new(100, 20)).unwrap();
+        terminal
+            .draw(|f| {
+                render_ui(f, &mut state, &styles);
+            })
+            .unwrap();
+
+        let output = buffer_to_string(terminal.backend().buffer());
+        assert!(output.contains(" Actions: postgres (paused, server1) "));
+    }
+
+    #[test]
+    fn test_log_filter_hides_non_matching_lines() {
+        use crate::docker::logs::LogEntry;
+
+        let mut state = create_test_app_state();
+        populate_containers(&mut state, 1);
+        let key = state.sorted_container_keys[0].clone();
+        state.show_log_timestamps = false;
+        let press = |state: &mut AppState, code: KeyCode| {
+            state.handle_event(AppEvent::KeyInput(KeyEvent::new(code, KeyModifiers::NONE)));
+        };
+        let log_line = |state: &mut AppState, second: u32, text: &str| {
+            let line = format!("2025-10-29T10:15:{:02}Z {}", second, text);
+            state.handle_event(AppEvent::LogLine(
+                key.clone(),
+                LogEntry::parse(&line).unwrap(),
+            ));
+        };
+        let lines = |state: &AppState| -> Vec<String> {
+            let log_state = state.log_state.as_ref().unwrap();
+            log_state
+                .formatted_lines
+                .iter()
+                .map(|l| l.to_string())
+                .collect()
+        };
+
+        press(&mut state, KeyCode::Char('l'));
+        log_line(&mut state, 0, "GET /health 200");
+        log_line(&mut state, 1, "ERROR db timeout");
+        log_line(&mut state, 2, "GET /api 500");
+
+        // Filters as you type, ignoring case
+        press(&mut state, KeyCode::Char('f'));
+        for c in "get".chars() {
+            press(&mut state, KeyCode::Char(c));
+        }
+        assert_eq!(lines(&state), vec!["GET /health 200", "GET /api 500"]);
+        assert_eq!(state.log_state.as_ref().unwrap().line_entries, vec![0, 2]);
+
+        // Enter keeps the filter, and new lines respect it
+        press(&mut state, KeyCode::Enter);
+        assert!(!state.log_filter_editing);
+        log_line(&mut state, 3, "error again");
+        log_line(&mut state, 4, "GET /ready 200");
+        assert_eq!(
+            lines(&state),
+            vec!["GET /health 200", "GET /api 500", "GET /ready 200"]
+        );
+
+        let styles = UiStyles::default();
+        let mut terminal = Terminal::new(TestBackend::new(80, 6)).unwrap();
+        terminal
+            .draw(|f| {
+                render_ui(f, &mut state, &styles);
+            })
+            .unwrap();
+        assert!(buffer_to_string(terminal.backend().buffer()).contains("[filter: get]"));
+
+        // Esc while editing clears it and brings every line back
+        press(&mut state, KeyCode::Char('f'));
+        press(&mut state, KeyCode::Esc);
+        assert_eq!(state.log_filter, None);
+        assert_eq!(lines(&state).len(), 5);
+    }
+
+    #[test]
+    fn test_stopped_container_keeps_last_stats() {
+        let mut state = create_test_app_state();
+        state.show_last_stats = true;
+        let container =
+            create_test_container("abc123456789", "web", "local", 42.5, 61.0, 2048.0, 1024.0);
+        let key = ContainerKey::new(container.host_id.clone(), container.id.clone());
+        state.handle_event(AppEvent::InitialContainerList(
+            "local".to_string(),
+            vec![container],
+        ));
+
+        state.handle_event(AppEvent::ContainerStateChanged(
+            key.clone(),
+            ContainerState::Exited,
+        ));
+        let last = state.containers[&key].last_stats.clone().unwrap();
+        assert_eq!(last.cpu, 42.5);
+        assert_eq!(last.memory, 61.0);
+
+        // Stopped containers only show with show-all; their last stats are dimmed
+        state.handle_event(AppEvent::KeyInput(KeyEvent::new(
+            KeyCode::Char('a'),
+            KeyModifiers::NONE,
+        )));
+        let styles = UiStyles::default();
+        let mut terminal = Terminal::new(TestBackend::new(100, 12)).unwrap();
+        terminal
+            .draw(|f| render_ui(f, &mut state, &styles))
+            .unwrap();
+        let output = buffer_to_string(terminal.backend().buffer());
+        assert!(output.contains("last: 42.5%"), "{}", output);
+        assert!(output.contains("last: 61.0%"), "{}", output);
+
+        // Starting again drops them
+        state.handle_event(AppEvent::ContainerStateChanged(
+            key.clone(),
+            ContainerState::Running,
+        ));
+        assert!(state.containers[&key].last_stats.is_none());
+    }
+
+    #[test]
+    fn test_search_regex_query() {
+        let mut state = create_test_app_state();
+        for (i, name) in ["web-1", "web-2", "webhook", "db"].into_iter().enumerate() {
+            let c = create_test_container(&format!("{:012}", i), name, "local", 0.0, 0.0, 0.0, 0.0);
+            state
+                .containers
+                .insert(ContainerKey::new(c.host_id.clone(), c.id.clone()), c);
+        }
+        let names = |state: &AppState| -> Vec<String> {
+            let mut names: Vec<_> = state
+                .sorted_container_keys
+                .iter()
+                .map(|key| state.containers[key].name.clone())
+                .collect();
+            names.sort();
+            names
+        };
+
+        // A leading '/' makes the query a case-insensitive regex
+        state.search_input = tui_input::Input::new("/^WEB-\\d$".to_string());
+        state.force_sort_containers();
+        assert_eq!(names(&state), vec!["web-1", "web-2"]);
+        assert!(!state.search_regex_invalid());
+
+        // Without it the query is still a plain substring
+        state.search_input = tui_input::Input::new("web-".to_string());
+        state.force_sort_containers();
+        assert_eq!(names(&state), vec!["web-1", "web-2"]);
+
+        // ...unless search_regex is set
+        state.search_regex = true;
+        state.search_input = tui_input::Input::new("^(db|webhook)$".to_string());
+        state.force_sort_containers();
+        assert_eq!(names(&state), vec!["db", "webhook"]);
+    }
+
+    #[test]
+    fn test_search_invalid_regex_falls_back_to_substring() {
+        let mut state = create_test_app_state();
+        let styles = UiStyles::default();
+        for (id, name) in [("abc123456789", "web(1)"), ("def987654321", "web")] {
+            let c = create_test_container(id, name, "local", 0.0, 0.0, 0.0, 0.0);
+            state
+                .containers
+                .insert(ContainerKey::new(c.host_id.clone(), c.id.clone()), c);
+        }
+
+        state.view_state = ViewState::SearchMode;
+        state.search_input = tui_input::Input::new("/web(".to_string());
+        state.force_sort_containers();
+        assert!(state.search_regex_invalid());
+        assert_eq!(state.sorted_container_keys.len(), 1);
+        assert_eq!(
+            state.containers[&state.sorted_container_keys[0]].name,
+            "web(1)"
+        );
+
+        // The search bar is dimmed while the pattern doesn't compile
+        let mut terminal = Terminal::new(TestBackend::new(80, 12)).unwrap();
+        terminal
+            .draw(|f| render_ui(f, &mut state, &styles))
+            .unwrap();
+        let buffer = terminal.backend().buffer();
+        let bar = (0..buffer.area.height)
+            .find(|&y| buffer[(0, y)].symbol() == "/")
+            .unwrap();
+        assert_eq!(buffer[(1, bar)].fg, ratatui::style::Color::DarkGray);
+    }
+
+    #[tokio::test]
+    async fn test_inspect_view_scrolls_and_closes() {
+        use crate::docker::connection::DockerHost;
+
+        let docker = bollard::Docker::connect_with_http(
+            "tcp://localhost:2375",
+            4,
+            bollard::API_DEFAULT_VERSION,
+        )
+        .unwrap();
+        let host = DockerHost::new("local".to_string(), docker, None, HashMap::new());
+
+        let mut state = create_test_app_state();
+        state.connected_hosts.insert("local".to_string(), host);
+        populate_containers(&mut state, 1);
+        state.table_state.select(Some(0));
+        let key = state.sorted_container_keys[0].clone();
+        let press = |state: &mut AppState, code| {
+            state.handle_event(AppEvent::KeyInput(KeyEvent::new(code, KeyModifiers::NONE)))
+        };
+        let styles = UiStyles::default();
+        let mut terminal = Terminal::new(TestBackend::new(60, 10)).unwrap();
+        let mut render = |state: &mut AppState| {
+            terminal.draw(|f| render_ui(f, state, &styles)).unwrap();
+            buffer_to_string(terminal.backend().buffer())
+        };
+
+        press(&mut state, KeyCode::Char('i'));
+        assert_eq!(state.view_state, ViewState::Inspect(key.clone()));
+        assert!(render(&mut state).contains("Inspecting..."));
+
+        let text: String = (0..30).map(|i| format!("line {}\n", i)).collect();
+        state.handle_event(AppEvent::InspectResult(key.clone(), text));
+        let output = render(&mut state);
+        assert!(output.contains("Inspect: c0 (local)"), "{}", output);
+        assert!(output.contains("line 0"));
+
+        press(&mut state, KeyCode::Down);
+        press(&mut state, KeyCode::Char('j'));
+        let output = render(&mut state);
+        assert!(output.contains("line 2") && !output.contains("line 1\n"));
+
+        // End stops at the last page instead of scrolling past the text
+        press(&mut state, KeyCode::End);
+        let output = render(&mut state);
+        assert_eq!(state.inspect_view.as_ref().unwrap().scroll_offset, 21);
+        assert!(output.contains("line 29"));
+
+        press(&mut state, KeyCode::Esc);
+        assert_eq!(state.view_state, ViewState::ContainerList);
+        assert!(state.inspect_view.is_none());
+
+        // A result arriving after the view closed is dropped
+        state.handle_event(AppEvent::InspectResult(key, "late".to_string()));
+        assert!(state.inspect_view.is_none());
+    }
+
+    #[test]
+    fn test_stats_history_is_bounded_and_dropped_with_container() {
+        let mut state = create_test_app_state();
+        state.stats_history_len = 5;
+        populate_containers(&mut state, 1);
+        let key = state.sorted_container_keys[0].clone();
+
+        for i in 0..8 {
+            let stats = ContainerStats {
+                cpu: i as f64 * 10.0,
+                memory: 40.0,
+                ..Default::default()
+            };
+            state.handle_event(AppEvent::ContainerStat(key.clone(), stats));
+        }
+        let history = &state.stats_history[&key];
+        assert_eq!(history.cpu, [30.0, 40.0, 50.0, 60.0, 70.0]);
+        assert_eq!(history.memory.len(), 5);
+
+        // The inspect view charts them above the summary
+        state.view_state = ViewState::Inspect(key.clone());
+        state.inspect_view = Some(crate::core::types::InspectView {
+            text: Some("Image:    nginx".to_string()),
+            scroll_offset: 0,
+        });
+        let styles = UiStyles::default();
+        let mut terminal = Terminal::new(TestBackend::new(80, 10)).unwrap();
+        terminal
+            .draw(|f| render_ui(f, &mut state, &styles))
+            .unwrap();
+        let output = buffer_to_string(terminal.backend().buffer());
+        assert!(output.contains("CPU 70.0% (last 5)"), "{}", output);
+        assert!(output.contains("Memory 40.0% (last 5)"));
+        assert!(output.contains("Image:    nginx"));
+
+        // Stats for unknown containers aren't kept, and removal drops the history
+        let gone = ContainerKey::new("local".to_string(), "nope".to_string());
+        state.handle_event(AppEvent::ContainerStat(
+            gone.clone(),
+            ContainerStats::default(),
+        ));
+        assert!(!state.stats_history.contains_key(&gone));
+        state.handle_event(AppEvent::ContainerDestroyed(key.clone()));
+        assert!(state.stats_history.is_empty());
+    }
+
+    #[test]
+    fn test_copy_full_container_id() {
+        use crate::core::types::RenderAction;
+
+        let mut state = create_test_app_state();
+        let mut container =
+            create_test_container("abc123456789", "web", "local", 0.0, 0.0, 0.0, 0.0);
+        container.full_id = format!("abc123456789{}", "f".repeat(52));
+        state.handle_event(AppEvent::InitialContainerList(
+            "local".to_string(),
+            vec![container],
+        ));
+        let press = |state: &mut AppState| {
+            state.handle_event(AppEvent::KeyInput(KeyEvent::new(
+                KeyCode::Char('y'),
+                KeyModifiers::NONE,
+            )))
+        };
+
+        assert_eq!(
+            press(&mut state),
+            RenderAction::CopyToClipboard(format!("abc123456789{}", "f".repeat(52)))
+        );
+        assert_eq!(
+            state.notification.as_ref().map(|(text, _)| text.as_str()),
+            Some("Copied ID of web to clipboard")
+        );
+
+        // Nothing to copy outside the list
+        state.view_state = ViewState::Inspect(state.sorted_container_keys[0].clone());
+        assert_eq!(press(&mut state), RenderAction::None);
+    }
+
+    #[test]
+    fn test_exited_container_shows_exit_code() {
+        let mut state = create_test_app_state();
+        state.show_all_containers = true;
+        let ok = create_test_container("abc123456789", "migrate", "local", 0.0, 0.0, 0.0, 0.0);
+        let killed = create_test_container("def987654321", "worker", "local", 0.0, 0.0, 0.0, 0.0);
+        let killed_key = ContainerKey::new("local".to_string(), killed.id.clone());
+        let ok_key = ContainerKey::new("local".to_string(), ok.id.clone());
+        state.handle_event(AppEvent::InitialContainerList(
+            "local".to_string(),
+            vec![ok, killed],
+        ));
+
+        for (key, code) in [(&ok_key, 0), (&killed_key, 137)] {
+            state.handle_event(AppEvent::ContainerExitCode(key.clone(), code));
+            state.handle_event(AppEvent::ContainerStateChanged(
+                key.clone(),
+                ContainerState::Exited,
+            ));
+        }
+
+        let styles = UiStyles::default();
+        let mut terminal = Terminal::new(TestBackend::new(120, 10)).unwrap();
+        terminal
+            .draw(|f| render_ui(f, &mut state, &styles))
+            .unwrap();
+        let buffer = terminal.backend().buffer();
+        let output = buffer_to_string(buffer);
+        assert!(output.contains("Exited (0)"), "{}", output);
+        assert!(output.contains("Exited (137)"));
+
+        // Non-zero exit codes stand out
+        let find = |text: &str| {
+            let (y, line) = output
+                .lines()
+                .enumerate()
+                .find(|(_, line)| line.contains(text))
+                .unwrap();
+            let x = line[..line.find(text).unwrap()].chars().count();
+            buffer[(x as u16, y as u16)].fg
+        };
+        assert_eq!(find("Exited (137)"), styles.high.fg.unwrap());
+        assert_ne!(find("Exited (0)"), styles.high.fg.unwrap());
+
+        // Starting again forgets the old exit code
+        state.handle_event(AppEvent::ContainerStateChanged(
+            killed_key.clone(),
+            ContainerState::Running,
+        ));
+        assert_eq!(state.containers[&killed_key].exit_code, None);
+    }
+
+    #[test]
+    fn test_log_wrap_toggle_and_horizontal_scroll() {
+        use crate::docker::logs::LogEntry;
+
+        let mut state = create_test_app_state();
+        state.show_log_timestamps = false;
+        populate_containers(&mut state, 1);
+        let key = state.sorted_container_keys[0].clone();
+        let press = |state: &mut AppState, code: KeyCode| {
+            state.handle_event(AppEvent::KeyInput(KeyEvent::new(code, KeyModifiers::NONE)))
+        };
+        let styles = UiStyles::default();
+        let mut terminal = Terminal::new(TestBackend::new(40, 8)).unwrap();
+        let mut render = |state: &mut AppState| {
+            terminal.draw(|f| render_ui(f, state, &styles)).unwrap();
+            buffer_to_string(terminal.backend().buffer())
+        };
+
+        press(&mut state, KeyCode::Char('l'));
+        let wide = format!("START{}END", "x".repeat(50));
+        state.handle_event(AppEvent::LogLine(
+            key.clone(),
+            LogEntry::parse(&format!("2025-10-29T10:15:00Z {}", wide)).unwrap(),
+        ));
+
+        // Wrapped (the default), the line's end shows on the next row
+        assert!(render(&mut state).contains("END"));
+
+        press(&mut state, KeyCode::Char('w'));
+        assert!(!state.log_wrap);
+        let output = render(&mut state);
+        assert!(
+            output.contains("START") && !output.contains("END"),
+            "{}",
+            output
+        );
+
+        // → scrolls sideways instead of doing nothing, ← instead of leaving
+        press(&mut state, KeyCode::Right);
+        assert!(!render(&mut state).contains("START"));
+        for _ in 0..10 {
+            press(&mut state, KeyCode::Right);
+        }
+        let output = render(&mut state);
+        // Clamped so the end of the widest line sits at the right edge
+        assert_eq!(state.log_state.as_ref().unwrap().horizontal_offset, 58 - 40);
+        assert!(output.contains("xEN"), "{}", output);
+        press(&mut state, KeyCode::Left);
+        assert!(matches!(state.view_state, ViewState::LogView(_)));
+
+        // Wrapping again starts from the left edge
+        press(&mut state, KeyCode::Char('w'));
+        assert_eq!(state.log_state.as_ref().unwrap().horizontal_offset, 0);
+        press(&mut state, KeyCode::Left);
+        assert_eq!(state.view_state, ViewState::ContainerList);
+    }
+
+    #[tokio::test]
+    async fn test_exec_command_prompt_and_scrollable_output() {
+        use crate::core::types::ContainerAction;
+        use crate::docker::connection::DockerHost;
+
+        let mut state = create_test_app_state();
+        let docker = bollard::Docker::connect_with_http(
+            "tcp://localhost:2375",
+            4,
+            bollard::API_DEFAULT_VERSION,
+        )
+        .unwrap();
+        state.connected_hosts.insert(
+            "local".to_string(),
+            DockerHost::new("local".to_string(), docker, None, HashMap::new()),
+        );
+        let container =
+            create_test_container("abc123456789", "nginx", "local", 25.5, 45.2, 1024.0, 2048.0);
+        let key = ContainerKey::new(container.host_id.clone(), container.id.clone());
+        state.containers.insert(key.clone(), container);
+        state.sorted_container_keys.push(key.clone());
+        state.table_state.select(Some(0));
+
+        // Exec comes right after Shell in a running container's menu
+        state.view_state = ViewState::ActionMenu(key.clone());
+        state.action_menu_state.select(Some(1));
+        state.handle_event(AppEvent::KeyInput(KeyEvent::new(
+            KeyCode::Enter,
+            KeyModifiers::NONE,
+        )));
+        assert_eq!(
+            state.action_prompt,
+            Some((key.clone(), ContainerAction::Exec))
+        );
+        assert_eq!(state.view_state, ViewState::ContainerList);
+
+        for c in "ls -l".chars() {
+            state.handle_event(AppEvent::KeyInput(KeyEvent::new(
+                KeyCode::Char(c),
+                KeyModifiers::NONE,
+            )));
+        }
+        let styles = UiStyles::default();
+        let mut terminal = Terminal::new(TestBackend::new(100, 20)).unwrap();
+        terminal
+            .draw(|f| render_ui(f, &mut state, &styles))
+            .unwrap();
+        let output = buffer_to_string(terminal.backend().buffer());
+        assert!(output.contains("Exec in nginx: ls -l"));
+
+        state.handle_event(AppEvent::KeyInput(KeyEvent::new(
+            KeyCode::Enter,
+            KeyModifiers::NONE,
+        )));
+        assert!(state.action_prompt.is_none());
+        assert_eq!(state.view_state, ViewState::CustomActionOutput);
+        assert_eq!(
+            state
+                .custom_action_run
+                .as_ref()
+                .map(|run| run.label.as_str()),
+            Some("Exec ls -l")
+        );
+
+        // Long output shows its tail; scrolling up reveals the start
+        let lines: Vec<String> = (1..=40).map(|i| format!("line {i}")).collect();
+        state.handle_event(AppEvent::CustomActionFinished(
+            key,
+            "Exec ls -l".to_string(),
+            Ok(lines.join("\n")),
+        ));
+        terminal
+            .draw(|f| render_ui(f, &mut state, &styles))
+            .unwrap();
+        let output = buffer_to_string(terminal.backend().buffer());
+        assert!(output.contains("line 40"));
+        assert!(!output.contains("line 1 "));
+
+        state.handle_event(AppEvent::KeyInput(KeyEvent::new(
+            KeyCode::Home,
+            KeyModifiers::NONE,
+        )));
+        terminal
+            .draw(|f| render_ui(f, &mut state, &styles))
+            .unwrap();
+        let output = buffer_to_string(terminal.backend().buffer());
+        assert!(output.contains("line 1 "));
+        assert!(!output.contains("line 40"));
+
+        state.handle_event(AppEvent::KeyInput(KeyEvent::new(
+            KeyCode::Esc,
+            KeyModifiers::NONE,
+        )));
+        assert!(state.custom_action_run.is_none());
+    }
+
+    #[test]
+    fn test_image_column_needs_wide_terminal() {
+        let mut state = create_test_app_state();
+        let mut container =
+            create_test_container("abc123456789", "web", "local", 25.5, 45.2, 1024.0, 2048.0);
+        container.image = "registry.example.com/team/web-frontend:2024.10.1".to_string();
+        let key = ContainerKey::new(container.host_id.clone(), container.id.clone());
+        state.containers.insert(key.clone(), container);
+        state.sorted_container_keys.push(key);
+        state.table_state.select(Some(0));
+        let image_idx = state
+            .column_config
+            .columns
+            .iter()
+            .position(|(col, _)| *col == Column::Image)
+            .unwrap();
+        state.column_config.toggle(image_idx);
+
+        let styles = UiStyles::default();
+        let mut terminal = Terminal::new(TestBackend::new(170, 10)).unwrap();
+        terminal
+            .draw(|f| render_ui(f, &mut state, &styles))
+            .unwrap();
+        let output = buffer_to_string(terminal.backend().buffer());
+        assert!(output.contains("Image"));
+        assert!(output.contains("registry.example.com/team/web…"));
+
+        let mut terminal = Terminal::new(TestBackend::new(150, 10)).unwrap();
+        terminal
+            .draw(|f| render_ui(f, &mut state, &styles))
+            .unwrap();
+        let output = buffer_to_string(terminal.backend().buffer());
+        assert!(!output.contains("Image"));
+        assert!(!output.contains("registry.example.com"));
+    }
+
+    #[tokio::test]
+    async fn test_rename_prompt_validates_name() {
+        use crate::core::types::ContainerAction;
+        use crate::docker::connection::DockerHost;
+
+        let mut state = create_test_app_state();
+        let docker = bollard::Docker::connect_with_http(
+            "tcp://localhost:2375",
+            4,
+            bollard::API_DEFAULT_VERSION,
+        )
+        .unwrap();
+        state.connected_hosts.insert(
+            "local".to_string(),
+            DockerHost::new("local".to_string(), docker, None, HashMap::new()),
+        );
+        let container =
+            create_test_container("abc123456789", "nginx", "local", 25.5, 45.2, 1024.0, 2048.0);
+        let key = ContainerKey::new(container.host_id.clone(), container.id.clone());
+        state.containers.insert(key.clone(), container);
+        state.sorted_container_keys.push(key.clone());
+        state.table_state.select(Some(0));
+
+        // Rename follows Restart in a running container's menu and starts from the current name
+        state.view_state = ViewState::ActionMenu(key.clone());
+        state.action_menu_state.select(Some(4));
+        state.handle_event(AppEvent::KeyInput(KeyEvent::new(
+            KeyCode::Enter,
+            KeyModifiers::NONE,
+        )));
+        assert_eq!(
+            state.action_prompt,
+            Some((key.clone(), ContainerAction::Rename))
+        );
+        assert_eq!(state.action_prompt_input.value(), "nginx");
+
+        // Names with characters Docker rejects stay in the prompt with a hint
+        state.handle_event(AppEvent::KeyInput(KeyEvent::new(
+            KeyCode::Char('!'),
+            KeyModifiers::NONE,
+        )));
+        assert!(state.action_prompt_error().is_some());
+        let styles = UiStyles::default();
+        let mut terminal = Terminal::new(TestBackend::new(120, 10)).unwrap();
+        terminal
+            .draw(|f| render_ui(f, &mut state, &styles))
+            .unwrap();
+        let output = buffer_to_string(terminal.backend().buffer());
+        assert!(output.contains("Rename nginx to: nginx!  (names need 2+ characters"));
+        state.handle_event(AppEvent::KeyInput(KeyEvent::new(
+            KeyCode::Enter,
+            KeyModifiers::NONE,
+        )));
+        assert!(state.action_prompt.is_some());
+
+        for code in [
+            KeyCode::Backspace,
+            KeyCode::Char('-'),
+            KeyCode::Char('2'),
+            KeyCode::Enter,
+        ] {
+            state.handle_event(AppEvent::KeyInput(KeyEvent::new(code, KeyModifiers::NONE)));
+        }
+        assert!(state.action_prompt.is_none());
+
+        // The new name shows as soon as Docker confirms the rename
+        state.handle_event(AppEvent::ContainerRenamed(
+            key.clone(),
+            "nginx-2".to_string(),
+        ));
+        assert_eq!(state.containers[&key].name, "nginx-2");
+    }
+
+    #[test]
+    fn test_title_shows_host_status_strip() {
+        use crate::core::types::HostStatus;
+
+        let mut state = create_test_app_state();
+        state
+            .host_status
+            .insert("local".to_string(), HostStatus::Connected);
+        state
+            .host_status
+            .insert("prod".to_string(), HostStatus::Failed);
+        state
+            .host_status
+            .insert("staging".to_string(), HostStatus::Connecting);
+        populate_containers(&mut state, 2);
+
+        let styles = UiStyles::default();
+        let mut terminal = Terminal::new(TestBackend::new(140, 10)).unwrap();
+        terminal
+            .draw(|f| render_ui(f, &mut state, &styles))
+            .unwrap();
+        let buffer = terminal.backend().buffer();
+        let output = buffer_to_string(buffer);
+        assert!(output.contains("● local ● prod ● staging"));
+
+        // Each dot is colored by its host's status
+        let title: Vec<String> = (0..140)
+            .map(|x| buffer[(x, 0)].symbol().to_string())
+            .collect();
+        let dot_color = |host: &str| {
+            let first = host.chars().next().unwrap().to_string();
+            let x = (0..title.len() - 2)
+                .find(|&x| title[x] == "●" && title[x + 2] == first)
+                .unwrap();
+            buffer[(x as u16, 0)].fg
+        };
+        assert_eq!(dot_color("local"), styles.low.fg.unwrap());
+        assert_eq!(dot_color("prod"), styles.high.fg.unwrap());
+        assert_eq!(dot_color("staging"), styles.medium.fg.unwrap());
+
+        // The strip is left out when it would run into the title
+        let mut narrow = Terminal::new(TestBackend::new(90, 10)).unwrap();
+        narrow.draw(|f| render_ui(f, &mut state, &styles)).unwrap();
+        let output = buffer_to_string(narrow.backend().buffer());
+        assert!(output.contains("'q' to quit)"));
+        assert!(!output.contains("● local"));
+
+        // A single host needs no strip
+        state.host_status.retain(|host, _| host == "local");
+        terminal
+            .draw(|f| render_ui(f, &mut state, &styles))
+            .unwrap();
+        let output = buffer_to_string(terminal.backend().buffer());
+        assert!(!output.contains("● local"));
+    }
+
+    #[test]
+    fn test_quit_asks_while_actions_are_in_flight() {
+        use crate::core::types::ContainerAction;
+
+        let mut state = create_test_app_state();
+        populate_containers(&mut state, 1);
+        let key = state.sorted_container_keys[0].clone();
+        let press = |state: &mut AppState, c| {
+            state.handle_event(AppEvent::KeyInput(KeyEvent::new(
+                KeyCode::Char(c),
+                KeyModifiers::NONE,
+            )))
+        };
+
+        state.handle_event(AppEvent::ActionInProgress(
+            key.clone(),
+            ContainerAction::Remove,
+        ));
+        assert_eq!(state.actions_in_flight, 1);
+
+        // 'q' asks first; anything but 'y' keeps dtop running
+        press(&mut state, 'q');
+        assert!(!state.should_quit);
+        assert!(state.quit_confirm_pending);
+        assert_eq!(
+            state.notification.as_ref().map(|(msg, _)| msg.as_str()),
+            Some("1 action(s) in progress, quit anyway? (y/n)")
+        );
+        press(&mut state, 'n');
+        assert!(!state.should_quit);
+        assert!(!state.quit_confirm_pending);
+
+        press(&mut state, 'q');
+        press(&mut state, 'y');
+        assert!(state.should_quit);
+
+        // Once the action finishes, 'q' quits right away
+        state.should_quit = false;
+        state.handle_event(AppEvent::ActionError(
+            key,
+            ContainerAction::Remove,
+            "container is running".to_string(),
+        ));
+        assert_eq!(state.actions_in_flight, 0);
+        press(&mut state, 'q');
+        assert!(state.should_quit);
+    }
+
+    #[test]
+    fn test_action_results_show_colored_toasts() {
+        use crate::core::types::ContainerAction;
+        use ratatui::style::Color;
+
+        let mut state = create_test_app_state();
+        populate_containers(&mut state, 1);
+        let key = state.sorted_container_keys[0].clone();
+        let styles = UiStyles::default();
+        let mut terminal = Terminal::new(TestBackend::new(100, 12)).unwrap();
+
+        // Finds the toast's text and the color it is drawn in
+        let mut toast = |state: &mut AppState, text: &str| {
+            terminal.draw(|f| render_ui(f, state, &styles)).unwrap();
+            let buffer = terminal.backend().buffer();
+            let output = buffer_to_string(buffer);
+            let row = output.lines().position(|line| line.contains(text))?;
+            let col = output.lines().nth(row)?.find(text)?;
+            Some(buffer[(col as u16, row as u16)].fg)
+        };
+
+        state.handle_event(AppEvent::ActionInProgress(
+            key.clone(),
+            ContainerAction::Remove,
+        ));
+        state.handle_event(AppEvent::ActionError(
+            key.clone(),
+            ContainerAction::Remove,
+            "container is running".to_string(),
+        ));
+        assert_eq!(
+            toast(&mut state, "[local] Remove c0 failed: container is running"),
+            Some(Color::Red)
+        );
+
+        state.handle_event(AppEvent::ActionInProgress(
+            key.clone(),
+            ContainerAction::Restart,
+        ));
+        state.handle_event(AppEvent::ActionSuccess(key, ContainerAction::Restart));
+        assert_eq!(
+            toast(&mut state, "[local] Restart c0: done"),
+            Some(Color::Green)
+        );
+    }
+
+    #[test]
+    fn test_state_filter_cycles_and_composes_with_search() {
+        use crate::core::types::StateFilter;
+
+        let mut state = create_test_app_state();
+        for (id, name, container_state, health) in [
+            ("aaa111111111", "web", ContainerState::Running, None),
+            (
+                "bbb222222222",
+                "worker",
+                ContainerState::Running,
+                Some(HealthStatus::Unhealthy),
+            ),
+            ("ccc333333333", "web-old", ContainerState::Exited, None),
+            ("ddd444444444", "migrate", ContainerState::Exited, None),
+        ] {
+            let mut container = create_test_container(id, name, "local", 1.0, 1.0, 0.0, 0.0);
+            container.state = container_state;
+            container.health = health;
+            let key = ContainerKey::new("local".to_string(), id.to_string());
+            state.containers.insert(key, container);
+        }
+        state.force_sort_containers();
+        let listed = |state: &AppState| {
+            let mut names: Vec<_> = state
+                .sorted_container_keys
+                .iter()
+                .map(|key| state.containers[key].name.clone())
+                .collect();
+            names.sort();
+            names
+        };
+        let press = |state: &mut AppState| {
+            state.handle_event(AppEvent::KeyInput(KeyEvent::new(
+                KeyCode::Char('p'),
+                KeyModifiers::NONE,
+            )))
+        };
+        assert_eq!(listed(&state), ["web", "worker"]);
+
+        press(&mut state);
+        assert_eq!(state.state_filter, StateFilter::Running);
+        assert_eq!(listed(&state), ["web", "worker"]);
+
+        // Exited containers show up even though stopped ones are hidden
+        press(&mut state);
+        assert_eq!(state.state_filter, StateFilter::Exited);
+        assert!(!state.show_all_containers);
+        assert_eq!(listed(&state), ["migrate", "web-old"]);
+
+        let styles = UiStyles::default();
+        let mut terminal = Terminal::new(TestBackend::new(120, 10)).unwrap();
+        terminal
+            .draw(|f| render_ui(f, &mut state, &styles))
+            .unwrap();
+        let output = buffer_to_string(terminal.backend().buffer());
+        assert!(output.contains("2 running / 4 total - only exited"));
+
+        // The search filter still applies on top
+        state.search_input = tui_input::Input::new("web".to_string());
+        state.force_sort_containers();
+        assert_eq!(listed(&state), ["web-old"]);
+        state.search_input = tui_input::Input::default();
+
+        press(&mut state);
+        assert_eq!(state.state_filter, StateFilter::Unhealthy);
+        state.force_sort_containers();
+        assert_eq!(listed(&state), ["worker"]);
+
+        press(&mut state);
+        assert_eq!(state.state_filter, StateFilter::All);
+        assert_eq!(listed(&state), ["web", "worker"]);
+    }
+
+    #[tokio::test]
+    async fn test_refresh_relists_containers_and_keeps_selection() {
+        use crate::docker::connection::DockerHost;
+
+        let mut state = create_test_app_state();
+        let docker = bollard::Docker::connect_with_http(
+            "tcp://localhost:2375",
+            4,
+            bollard::API_DEFAULT_VERSION,
+        )
+        .unwrap();
+        let host = DockerHost::new("local".to_string(), docker, None, HashMap::new());
+        let refresh = host.refresh.clone();
+        state.connected_hosts.insert("local".to_string(), host);
+
+        let container = |id: &str, name: &str, host: &str, cpu: f64| {
+            create_test_container(id, name, host, cpu, 0.0, 0.0, 0.0)
+        };
+        state.handle_event(AppEvent::InitialContainerList(
+            "local".to_string(),
+            vec![
+                container("aaa", "api", "local", 50.0),
+                container("bbb", "web", "local", 40.0),
+                container("ccc", "gone", "local", 30.0),
+            ],
+        ));
+        state.handle_event(AppEvent::InitialContainerList(
+            "remote".to_string(),
+            vec![container("ddd", "db", "remote", 20.0)],
+        ));
+        let web_key = ContainerKey::new("local".to_string(), "bbb".to_string());
+        let gone_key = ContainerKey::new("local".to_string(), "ccc".to_string());
+        let selected = state
+            .sorted_container_keys
+            .iter()
+            .position(|k| *k == web_key)
+            .unwrap();
+        state.table_state.select(Some(selected));
+
+        state.handle_event(AppEvent::KeyInput(KeyEvent::new(
+            KeyCode::Char('r'),
+            KeyModifiers::NONE,
+        )));
+        assert_eq!(
+            state.notification.as_ref().map(|(text, _)| text.as_str()),
+            Some("Refreshing containers...")
+        );
+        // The manager was woken (the permit is stored until it waits)
+        tokio::time::timeout(std::time::Duration::from_millis(100), refresh.notified())
+            .await
+            .expect("refresh should be notified");
+
+        // The host lists again: one container is gone, one is new, and the
+        // fresh entries have no stats yet
+        state.handle_event(AppEvent::InitialContainerList(
+            "local".to_string(),
+            vec![
+                container("new", "new", "local", 0.0),
+                container("bbb", "web", "local", 0.0),
+                container("aaa", "api", "local", 0.0),
+            ],
+        ));
+
+        assert_eq!(state.containers.len(), 4);
+        assert_eq!(state.sorted_container_keys.len(), 4);
+        assert!(!state.containers.contains_key(&gone_key));
+        assert!(!state.stats_history.contains_key(&gone_key));
+        assert_eq!(state.containers[&web_key].stats.cpu, 40.0);
+        assert!(
+            state
+                .containers
+                .contains_key(&ContainerKey::new("remote".to_string(), "ddd".to_string()))
+        );
+        let selected = state.table_state.selected().unwrap();
+        assert_eq!(state.sorted_container_keys[selected], web_key);
+    }
+
+    #[test]
+    fn test_container_stat_batch_updates_each_container() {
+        use crate::core::types::RenderAction;
+
+        let mut state = create_test_app_state();
+        populate_containers(&mut state, 2);
+        let keys = state.sorted_container_keys.clone();
+        let stats = |cpu: f64| ContainerStats {
+            cpu,
+            ..Default::default()
+        };
+
+        let action = state.handle_event(AppEvent::ContainerStatBatch(
+            "local".to_string(),
+            vec![
+                (keys[0].container_id.clone(), stats(11.0)),
+                (keys[1].container_id.clone(), stats(22.0)),
+                ("unknown".to_string(), stats(33.0)),
+            ],
+        ));
+
+        // Like single samples, a batch waits for the next periodic draw
+        assert_eq!(action, RenderAction::None);
+        assert_eq!(state.containers[&keys[0]].stats.cpu, 11.0);
+        assert_eq!(state.containers[&keys[1]].stats.cpu, 22.0);
+        assert_eq!(state.containers.len(), 2);
+        assert_eq!(state.stats_history[&keys[1]].cpu, [22.0]);
+    }
+
+    #[tokio::test]
+    async fn test_host_name_replaces_host_id_in_display() {
+        use crate::docker::connection::DockerHost;
+
+        let mut state = create_test_app_state();
+        let docker = bollard::Docker::connect_with_http(
+            "tcp://localhost:2375",
+            4,
+            bollard::API_DEFAULT_VERSION,
+        )
+        .unwrap();
+        let mut host = DockerHost::new("192.168.1.100".to_string(), docker, None, HashMap::new());
+        host.name = Some("prod-db".to_string());
+        state.connected_hosts.insert(host.host_id.clone(), host);
+        state.handle_event(AppEvent::InitialContainerList(
+            "192.168.1.100".to_string(),
+            vec![create_test_container(
+                "abc123456789",
+                "postgres",
+                "192.168.1.100",
+                0.0,
+                0.0,
+                0.0,
+                0.0,
+            )],
+        ));
+        state.handle_event(AppEvent::InitialContainerList(
+            "local".to_string(),
+            vec![create_test_container(
+                "def987654321",
+                "web",
+                "local",
+                0.0,
+                0.0,
+                0.0,
+                0.0,
+            )],
+        ));
+
+        let styles = UiStyles::default();
+        let mut terminal = Terminal::new(TestBackend::new(120, 10)).unwrap();
+        terminal
+            .draw(|f| render_ui(f, &mut state, &styles))
+            .unwrap();
+        let output = buffer_to_string(terminal.backend().buffer());
+        assert!(output.contains("prod-db"), "{}", output);
+        assert!(!output.contains("192.168.1.100"), "{}", output);
+        // Hosts without a name keep showing their ID
+        assert!(output.contains("local"), "{}", output);
+
+        // The log view title names the host the same way
+        let key = ContainerKey::new("192.168.1.100".to_string(), "abc123456789".to_string());
+        state.log_state = Some(crate::core::types::LogState::new(key.clone()));
+        state.view_state = ViewState::LogView(key);
+        terminal
+            .draw(|f| render_ui(f, &mut state, &styles))
+            .unwrap();
+        let output = buffer_to_string(terminal.backend().buffer());
+        assert!(output.contains("Logs: postgres (prod-db)"), "{}", output);
+    }
+
+    #[tokio::test]
+    async fn test_split_logs_follow_the_selection() {
+        use crate::docker::connection::DockerHost;
+
+        let mut state = create_test_app_state();
+        let docker = bollard::Docker::connect_with_http(
+            "tcp://localhost:2375",
+            4,
+            bollard::API_DEFAULT_VERSION,
+        )
+        .unwrap();
+        let host = DockerHost::new("local".to_string(), docker, None, HashMap::new());
+        state.connected_hosts.insert(host.host_id.clone(), host);
+        state.handle_event(AppEvent::InitialContainerList(
+            "local".to_string(),
+            vec![
+                create_test_container("aaa111111111", "api", "local", 0.0, 0.0, 0.0, 0.0),
+                create_test_container("bbb222222222", "web", "local", 0.0, 0.0, 0.0, 0.0),
+            ],
+        ));
+        let selected_key = |state: &AppState| {
+            state.sorted_container_keys[state.table_state.selected().unwrap()].clone()
+        };
+        let log_key = |state: &AppState| {
+            state
+                .log_state
+                .as_ref()
+                .map(|log_state| log_state.container_key.clone())
+        };
+
+        state.handle_event(AppEvent::KeyInput(KeyEvent::new(
+            KeyCode::Char('v'),
+            KeyModifiers::NONE,
+        )));
+        assert!(state.split_logs);
+        assert_eq!(state.view_state, ViewState::ContainerList);
+        assert_eq!(log_key(&state), Some(selected_key(&state)));
+
+        let styles = UiStyles::default();
+        let mut terminal = Terminal::new(TestBackend::new(100, 20)).unwrap();
+        terminal
+            .draw(|f| render_ui(f, &mut state, &styles))
+            .unwrap();
+        let output = buffer_to_string(terminal.backend().buffer());
+        let lines: Vec<&str> = output.lines().collect();
+        assert!(lines[..10].iter().any(|l| l.contains("api")), "{}", output);
+        assert!(lines[10].contains("Logs: "), "{}", output);
+
+        // Moving the selection switches the pane to the other container
+        let first = selected_key(&state);
+        state.handle_event(AppEvent::KeyInput(KeyEvent::new(
+            KeyCode::Char('j'),
+            KeyModifiers::NONE,
+        )));
+        assert_ne!(selected_key(&state), first);
+        assert_eq!(log_key(&state), Some(selected_key(&state)));
+
+        // The full log view and back keep the pane open
+        state.handle_event(AppEvent::KeyInput(KeyEvent::new(
+            KeyCode::Char('l'),
+            KeyModifiers::NONE,
+        )));
+        state.handle_event(AppEvent::KeyInput(KeyEvent::new(
+            KeyCode::Char('h'),
+            KeyModifiers::NONE,
+        )));
+        assert_eq!(log_key(&state), Some(selected_key(&state)));
+
+        state.handle_event(AppEvent::KeyInput(KeyEvent::new(
+            KeyCode::Char('v'),
+            KeyModifiers::NONE,
+        )));
+        assert!(!state.split_logs);
+        assert!(state.log_state.is_none());
+    }
+
+    #[test]
+    fn test_monochrome_styles_render_without_colors() {
+        use crate::ui::icons::IconStyle;
+        use ratatui::style::{Color, Modifier};
+
+        let mut state = create_test_app_state();
+        populate_containers(&mut state, 3);
+        state.show_help = true;
+        let styles = UiStyles::monochrome(IconStyle::Unicode);
+
+        let mut terminal = Terminal::new(TestBackend::new(120, 30)).unwrap();
+        terminal
+            .draw(|f| render_ui(f, &mut state, &styles))
+            .unwrap();
+        let buffer = terminal.backend().buffer();
+        assert!(
+            buffer
+                .content
+                .iter()
+                .all(|cell| cell.fg == Color::Reset && cell.bg == Color::Reset)
+        );
+
+        // The selected row stays visible through reverse video
+        state.show_help = false;
+        terminal
+            .draw(|f| render_ui(f, &mut state, &styles))
+            .unwrap();
+        let buffer = terminal.backend().buffer();
+        assert!(
+            buffer
+                .content
+                .iter()
+                .any(|cell| cell.modifier.contains(Modifier::REVERSED))
+        );
+    }
+
+    #[test]
+    fn test_high_restart_count_is_highlighted() {
+        let mut state = create_test_app_state();
+        let mut flapping =
+            create_test_container("abc123456789", "worker", "local", 0.0, 0.0, 0.0, 0.0);
+        flapping.restart_count = Some(12);
+        let mut stable = create_test_container("def987654321", "web", "local", 0.0, 0.0, 0.0, 0.0);
+        stable.restart_count = Some(3);
+        state.handle_event(AppEvent::InitialContainerList(
+            "local".to_string(),
+            vec![flapping, stable],
+        ));
+        let restarts_idx = state
+            .column_config
+            .columns
+            .iter()
+            .position(|(col, _)| *col == Column::Restarts)
+            .unwrap();
+        state.column_config.toggle(restarts_idx);
+
+        let styles = UiStyles::default();
+        let mut terminal = Terminal::new(TestBackend::new(140, 10)).unwrap();
+        terminal
+            .draw(|f| render_ui(f, &mut state, &styles))
+            .unwrap();
+        let buffer = terminal.backend().buffer();
+        let output = buffer_to_string(buffer);
+        let restarts_fg = |name: &str| {
+            let (y, line) = output
+                .lines()
+                .enumerate()
+                .find(|(_, line)| line.contains(name))
+                .unwrap();
+            let count = line.trim_end_matches([' ', '│']).chars().count() - 1;
+            buffer[(count as u16, y as u16)].fg
+        };
+        assert_eq!(restarts_fg("worker"), styles.high.fg.unwrap());
+        assert_ne!(restarts_fg("web"), styles.high.fg.unwrap());
+    }
+
+    #[test]
+    fn test_selection_follows_container_across_sorts_and_filters() {
+        let mut state = create_test_app_state();
+        state.handle_event(AppEvent::InitialContainerList(
+            "local".to_string(),
+            vec![
+                create_test_container("aaa111111111", "zeta", "local", 90.0, 0.0, 0.0, 0.0),
+                create_test_container("bbb222222222", "alpha", "local", 10.0, 0.0, 0.0, 0.0),
+                create_test_container("ccc333333333", "mid", "local", 50.0, 0.0, 0.0, 0.0),
+            ],
+        ));
+        let selected_name = |state: &AppState| {
+            let key = &state.sorted_container_keys[state.table_state.selected().unwrap()];
+            state.containers[key].name.clone()
+        };
+        let zeta = state
+            .sorted_container_keys
+            .iter()
+            .position(|key| key.container_id == "aaa111111111")
+            .unwrap();
+        state.table_state.select(Some(zeta));
+
+        for field in [Column::Name, Column::Cpu] {
+            state.handle_event(AppEvent::SetSortField(field));
+            assert_eq!(selected_name(&state), "zeta");
+        }
+
+        state.handle_event(AppEvent::KeyInput(KeyEvent::new(
+            KeyCode::Char('a'),
+            KeyModifiers::NONE,
+        )));
+        assert_eq!(selected_name(&state), "zeta");
+
+        // A search that still matches keeps it highlighted
+        state.handle_event(AppEvent::KeyInput(KeyEvent::new(
+            KeyCode::Char('/'),
+            KeyModifiers::NONE,
+        )));
+        state.handle_event(AppEvent::KeyInput(KeyEvent::new(
+            KeyCode::Char('t'),
+            KeyModifiers::NONE,
+        )));
+        assert_eq!(state.sorted_container_keys.len(), 1);
+        assert_eq!(selected_name(&state), "zeta");
+    }
+
+    #[test]
+    fn test_log_follow_pauses_at_bottom() {
+        use crate::docker::logs::LogEntry;
+
+        let mut state = create_test_app_state();
+        populate_containers(&mut state, 1);
+        let key = state.sorted_container_keys[0].clone();
+        state.handle_event(AppEvent::KeyInput(KeyEvent::new(
+            KeyCode::Char('l'),
+            KeyModifiers::NONE,
+        )));
+        let add_line = |state: &mut AppState, i: usize| {
+            let line = format!("2025-10-29T10:15:{:02}Z Line {}", i % 60, i);
+            state.handle_event(AppEvent::LogLine(
+                key.clone(),
+                LogEntry::parse(&line).unwrap(),
+            ));
+        };
+        for i in 0..20 {
+            add_line(&mut state, i);
+        }
+
+        let styles = UiStyles::default();
+        let mut terminal = Terminal::new(TestBackend::new(100, 10)).unwrap();
+        let mut render = |state: &mut AppState| {
+            terminal.draw(|f| render_ui(f, state, &styles)).unwrap();
+            buffer_to_string(terminal.backend().buffer())
+        };
+        let output = render(&mut state);
+        assert!(output.contains("[FOLLOW]"), "{}", output);
+        assert!(output.contains("Line 19"));
+
+        // Paused at the bottom, new lines don't move the view
+        state.handle_event(AppEvent::KeyInput(KeyEvent::new(
+            KeyCode::Char('F'),
+            KeyModifiers::NONE,
+        )));
+        for i in 20..25 {
+            add_line(&mut state, i);
+        }
+        let output = render(&mut state);
+        assert!(output.contains("[PAUSED]"), "{}", output);
+        assert!(output.contains("Line 19"));
+        assert!(!output.contains("Line 24"));
+
+        // Following again jumps to the newest line
+        state.handle_event(AppEvent::KeyInput(KeyEvent::new(
+            KeyCode::Char('F'),
+            KeyModifiers::NONE,
+        )));
+        let output = render(&mut state);
+        assert!(output.contains("[FOLLOW]"), "{}", output);
+        assert!(output.contains("Line 24"));
+    }
+
+    #[test]
+    fn test_host_picker_shows_usage_per_host() {
+        use crate::core::types::HostStatus;
+
+        let mut state = create_test_app_state();
+        state.show_all_containers = true;
+        let mut stopped =
+            create_test_container("ccc333333333", "migrate", "local", 0.0, 0.0, 0.0, 0.0);
+        stopped.state = ContainerState::Exited;
+        state.handle_event(AppEvent::InitialContainerList(
+            "local".to_string(),
+            vec![
+                create_test_container("aaa111111111", "web", "local", 30.0, 20.0, 0.0, 0.0),
+                create_test_container("bbb222222222", "api", "local", 15.0, 40.0, 0.0, 0.0),
+                stopped,
+            ],
+        ));
+        state.handle_event(AppEvent::InitialContainerList(
+            "server1".to_string(),
+            vec![create_test_container(
+                "ddd444444444",
+                "db",
+                "server1",
+                5.0,
+                50.0,
+                0.0,
+                0.0,
+            )],
+        ));
+        state
+            .host_status
+            .insert("local".to_string(), HostStatus::Connected);
+        state
+            .host_status
+            .insert("server1".to_string(), HostStatus::Failed);
+        // Long names are cut so every row's usage lines up
+        let long_host = "ssh://deploy@build-runner-01.eu-west.example.internal";
+        state
+            .host_status
+            .insert(long_host.to_string(), HostStatus::Connecting);
+
+        state.handle_event(AppEvent::KeyInput(KeyEvent::new(
+            KeyCode::Char('H'),
+            KeyModifiers::NONE,
+        )));
+        let styles = UiStyles::default();
+        let mut terminal = Terminal::new(TestBackend::new(120, 20)).unwrap();
+        terminal
+            .draw(|f| render_ui(f, &mut state, &styles))
+            .unwrap();
+        let output = buffer_to_string(terminal.backend().buffer());
+        assert!(
+            output.contains(&format!(
+                "● {:<30}  2/3 running  45% CPU  30% mem avg",
+                "local"
+            )),
+            "{}",
+            output
+        );
+        assert!(output.contains(&format!(
+            "● {:<30}  1/1 running  5% CPU  50% mem avg",
+            "server1"
+        )));
+        assert!(output.contains("● ssh://deploy@build-runner-01.…  0/0 running"));
+    }
+
+    #[tokio::test]
+    async fn test_remove_asks_for_confirmation() {
+        use crate::core::types::ContainerAction;
+        use crate::docker::connection::DockerHost;
+
+        let mut state = create_test_app_state();
+        let docker = bollard::Docker::connect_with_http(
+            "tcp://localhost:2375",
+            4,
+            bollard::API_DEFAULT_VERSION,
+        )
+        .unwrap();
+        state.connected_hosts.insert(
+            "local".to_string(),
+            DockerHost::new("local".to_string(), docker, None, HashMap::new()),
+        );
+        let container =
+            create_test_container("abc123456789", "nginx", "local", 25.5, 45.2, 1024.0, 2048.0);
+        let key = ContainerKey::new(container.host_id.clone(), container.id.clone());
+        state.containers.insert(key.clone(), container);
+        state.sorted_container_keys.push(key.clone());
+        state.table_state.select(Some(0));
+        let enter = KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE);
+
+        // Remove is sixth in a running container's menu
+        state.view_state = ViewState::ActionMenu(key.clone());
+        state.action_menu_state.select(Some(5));
+        state.handle_event(AppEvent::KeyInput(enter));
+        assert_eq!(
+            state.view_state,
+            ViewState::ConfirmAction(key.clone(), ContainerAction::Remove)
+        );
+
+        let styles = UiStyles::default();
+        let mut terminal = Terminal::new(TestBackend::new(100, 20)).unwrap();
+        terminal
+            .draw(|f| render_ui(f, &mut state, &styles))
+            .unwrap();
+        let output = buffer_to_string(terminal.backend().buffer());
+        assert!(output.contains("Remove nginx on local?"));
+        assert!(output.contains("y: Remove  n/Esc: Cancel"));
+
+        // Like every popup, it follows NO_COLOR
+        let monochrome = UiStyles::monochrome(crate::ui::icons::IconStyle::Unicode);
+        terminal
+            .draw(|f| render_ui(f, &mut state, &monochrome))
+            .unwrap();
+        assert!(terminal.backend().buffer().content.iter().all(|cell| {
+            cell.fg == ratatui::style::Color::Reset && cell.bg == ratatui::style::Color::Reset
+        }));
+
+        // Other keys are ignored; Esc cancels without running anything
+        state.handle_event(AppEvent::KeyInput(KeyEvent::new(
+            KeyCode::Char('j'),
+            KeyModifiers::NONE,
+        )));
+        assert!(matches!(state.view_state, ViewState::ConfirmAction(..)));
+        state.handle_event(AppEvent::KeyInput(KeyEvent::new(
+            KeyCode::Esc,
+            KeyModifiers::NONE,
+        )));
+        assert_eq!(state.view_state, ViewState::ContainerList);
+
+        state.view_state = ViewState::ActionMenu(key.clone());
+        state.action_menu_state.select(Some(5));
+        state.handle_event(AppEvent::KeyInput(enter));
+        state.handle_event(AppEvent::KeyInput(KeyEvent::new(
+            KeyCode::Char('y'),
+            KeyModifiers::NONE,
+        )));
+        assert_eq!(state.view_state, ViewState::ContainerList);
+
+        // With confirm_destructive off, Remove runs straight from the menu
+        state.confirm_destructive = false;
+        state.view_state = ViewState::ActionMenu(key.clone());
+        state.action_menu_state.select(Some(5));
+        state.handle_event(AppEvent::KeyInput(enter));
+        assert_eq!(state.view_state, ViewState::ContainerList);
+    }
+
+    #[test]
+    fn test_host_without_updates_is_marked_stale() {
+        use crate::core::types::HostStatus;
+        use std::time::{Duration, Instant};
+
+        let mut state = create_test_app_state();
+        populate_containers(&mut state, 1);
+        state
+            .host_status
+            .insert("local".to_string(), HostStatus::Connected);
+        state.host_last_update.insert(
+            "local".to_string(),
+            Instant::now() - Duration::from_secs(60),
+        );
+        assert!(state.is_host_stale("local"));
+
+        let styles = UiStyles::default();
+        let mut terminal = Terminal::new(TestBackend::new(140, 10)).unwrap();
+        terminal
+            .draw(|f| render_ui(f, &mut state, &styles))
+            .unwrap();
+        let buffer = terminal.backend().buffer();
+        let output = buffer_to_string(buffer);
+        assert!(output.contains("- stale: local"));
+        let (row, line) = output
+            .lines()
+            .enumerate()
+            .find(|(_, line)| line.contains("id0000000000"))
+            .unwrap();
+        let x = line[..line.find("id0000000000").unwrap()].chars().count();
+        assert!(
+            buffer[(x as u16, row as u16)]
+                .modifier
+                .contains(ratatui::style::Modifier::DIM)
+        );
+
+        // A new sample clears it; so does having nothing running to report on
+        state.handle_event(AppEvent::ContainerStat(
+            ContainerKey::new("local".to_string(), "id0000000000".to_string()),
+            ContainerStats::default(),
+        ));
+        assert!(!state.is_host_stale("local"));
+        state.host_last_update.insert(
+            "local".to_string(),
+            Instant::now() - Duration::from_secs(60),
+        );
+        for container in state.containers.values_mut() {
+            container.state = ContainerState::Exited;
+        }
+        assert!(!state.is_host_stale("local"));
+    }
+
+    #[test]
+    fn test_nerd_icons_cover_every_state_and_render_in_list() {
+        use crate::core::types::HealthStatus;
+        use crate::ui::icons::{IconStyle, Icons};
+
+        let states = [
+            ContainerState::Running,
+            ContainerState::Paused,
+            ContainerState::Restarting,
+            ContainerState::Removing,
+            ContainerState::Exited,
+            ContainerState::Dead,
+            ContainerState::Created,
+            ContainerState::Unknown,
+        ];
+        let health = [
+            HealthStatus::Healthy,
+            HealthStatus::Unhealthy,
+            HealthStatus::Starting,
+        ];
+        for style in [IconStyle::Unicode, IconStyle::Nerd] {
+            let icons = Icons::new(style);
+            assert!(states.iter().all(|state| !icons.state(state).is_empty()));
+            assert!(health.iter().all(|status| !icons.health(status).is_empty()));
+        }
+
+        // The configured style reaches the Status column
+        let mut state = create_test_app_state();
+        populate_containers(&mut state, 1);
+        let styles = UiStyles::with_theme(IconStyle::Nerd, &Default::default()).unwrap();
+        let mut terminal = Terminal::new(TestBackend::new(120, 10)).unwrap();
+        terminal
+            .draw(|f| render_ui(f, &mut state, &styles))
+            .unwrap();
+        let output = buffer_to_string(terminal.backend().buffer());
+        assert!(output.contains(Icons::new(IconStyle::Nerd).state(&ContainerState::Running)));
+        assert!(!output.contains(Icons::new(IconStyle::Unicode).state(&ContainerState::Running)));
+    }
+
+    #[test]
+    fn test_all_hosts_failing_while_connecting_exits_with_error() {
+        let mut state = create_test_app_state();
+        state.start_connecting(vec!["local".to_string(), "server1".to_string()]);
+
+        state.handle_event(AppEvent::ConnectionError(
+            "local".to_string(),
+            "Docker daemon ping timeout".to_string(),
+        ));
+        assert!(!state.should_quit);
+
+        state.handle_event(AppEvent::ConnectionError(
+            "server1".to_string(),
+            "Connection refused".to_string(),
+        ));
+        assert!(state.should_quit);
+        assert!(state.exit_error);
+        assert!(
+            state
+                .exit_message
+                .as_deref()
+                .unwrap()
+                .starts_with("Failed to connect to any Docker hosts")
+        );
+    }
+
+    #[test]
+    fn test_unreported_fields_show_na() {
+        let mut state = create_test_app_state();
+        let mut container =
+            create_test_container("abc123456789", "legacy", "local", 1.0, 1.0, 0.0, 0.0);
+        container.stats.disk_io_unavailable = true;
+        container.restart_count = None;
+        container.size = None;
+        state.handle_event(AppEvent::InitialContainerList(
+            "local".to_string(),
+            vec![container],
+        ));
+        for (col, visible) in &mut state.column_config.columns {
+            *visible = matches!(
+                col,
+                Column::Name | Column::DiskIo | Column::Restarts | Column::Size
+            );
+        }
+
+        let styles = UiStyles::default();
+        let mut terminal = Terminal::new(TestBackend::new(100, 10)).unwrap();
+        terminal
+            .draw(|f| render_ui(f, &mut state, &styles))
+            .unwrap();
+        let output = buffer_to_string(terminal.backend().buffer());
+        let row = output.lines().find(|line| line.contains("legacy")).unwrap();
+        assert_eq!(row.matches("N/A").count(), 3, "{}", row);
+    }
+
+    #[test]
+    fn test_quit_on_all_disconnected() {
+        let mut state = create_test_app_state();
+        state.quit_on_all_disconnected = true;
+
+        state.handle_event(AppEvent::ConnectionError(
+            "local".to_string(),
+            "Lost connection to Docker daemon".to_string(),
+        ));
+        assert!(state.should_quit);
+        assert!(state.exit_message.is_some());
+        assert!(!state.exit_error);
+    }
+
+    #[test]
+    fn test_connection_error_does_not_quit_by_default() {
+        let mut state = create_test_app_state();
+
+        state.handle_event(AppEvent::ConnectionError(
+            "local".to_string(),
+            "Lost connection to Docker daemon".to_string(),
+        ));
+        assert!(!state.should_quit);
+        assert_eq!(
+            state.host_status.get("local"),
+            Some(&crate::core::types::HostStatus::Failed)
+        );
+    }
+
+    #[test]
+    fn test_paused_container_drops_frozen_stats() {
+        let mut state = create_test_app_state();
+        let container = create_test_container("abc123", "nginx", "local", 42.0, 30.0, 0.0, 0.0);
+        let key = ContainerKey::new("local".to_string(), "abc123".to_string());
+        state.containers.insert(key.clone(), container);
+
+        state.handle_event(AppEvent::ContainerStateChanged(
+            key.clone(),
+            ContainerState::Paused,
+        ));
+        assert_eq!(state.containers[&key].stats.cpu, 0.0);
+
+        // Samples still in flight from the stopped stream are ignored
+        let stale = ContainerStats {
+            cpu: 42.0,
+            ..Default::default()
+        };
+        state.handle_event(AppEvent::ContainerStat(key.clone(), stale.clone()));
+        assert_eq!(state.containers[&key].stats.cpu, 0.0);
+
+        // Once resumed, stats flow again
+        state.handle_event(AppEvent::ContainerStateChanged(
+            key.clone(),
+            ContainerState::Running,
+        ));
+        state.handle_event(AppEvent::ContainerStat(key.clone(), stale));
+        assert_eq!(state.containers[&key].stats.cpu, 42.0);
+    }
+
+    #[test]
+    fn test_container_sizes_update_known_containers() {
+        use crate::core::types::ContainerSize;
+
+        let mut state = create_test_app_state();
+        let container = create_test_container("abc123", "nginx", "local", 1.0, 1.0, 0.0, 0.0);
+        let key = ContainerKey::new("local".to_string(), "abc123".to_string());
+        state.containers.insert(key.clone(), container);
+
+        let size = ContainerSize {
+            rw: 1024,
+            root_fs: 4096,
+        };
+        state.handle_event(AppEvent::ContainerSizes(
+            "local".to_string(),
+            vec![("abc123".to_string(), size), ("unknown".to_string(), size)],
+        ));
+
+        assert_eq!(state.containers[&key].size, Some(size));
+        assert_eq!(state.containers.len(), 1);
+    }
+
+    #[test]
+    fn test_container_list_with_footer() {
+        let mut state = create_test_app_state();
+        populate_containers(&mut state, 3);
+
+        // 'f' toggles the key legend footer
+        state.handle_event(AppEvent::KeyInput(KeyEvent::new(
+            KeyCode::Char('f'),
+            KeyModifiers::NONE,
+        )));
+        assert!(state.show_footer);
+
+        let styles = UiStyles::default();
+        let backend = TestBackend::new(100, 20);
+        let mut terminal = Terminal::new(backend).unwrap();
+
+        terminal
+            .draw(|f| {
+                render_ui(f, &mut state, &styles);
+            })
+            .unwrap();
+
+        let buffer = terminal.backend().buffer().clone();
+        let output = buffer_to_string(&buffer);
+        assert_snapshot_with_redaction!(output);
+    }
+
+    fn create_exited_container(id: &str, name: &str) -> Container {
+        let mut container = create_test_container(id, name, "local", 0.0, 0.0, 0.0, 0.0);
+        container.state = ContainerState::Exited;
+        container
+    }
+
+    #[test]
+    fn test_toggle_show_all_selects_first_row() {
+        let mut state = create_test_app_state();
+        state.handle_event(AppEvent::InitialContainerList(
+            "local".to_string(),
+            vec![create_exited_container("abc123", "stopped")],
+        ));
+        // Only a stopped container, hidden by default
+        assert!(state.sorted_container_keys.is_empty());
+        assert_eq!(state.table_state.selected(), None);
+
+        state.handle_event(AppEvent::KeyInput(KeyEvent::new(
+            KeyCode::Char('a'),
+            KeyModifiers::NONE,
+        )));
+        assert_eq!(state.sorted_container_keys.len(), 1);
+        assert_eq!(state.table_state.selected(), Some(0));
+
+        state.handle_event(AppEvent::KeyInput(KeyEvent::new(
+            KeyCode::Char('a'),
+            KeyModifiers::NONE,
+        )));
+        assert_eq!(state.table_state.selected(), None);
+    }
+
+    #[test]
+    fn test_search_filter_restores_selection() {
+        let mut state = create_test_app_state();
+        state.handle_event(AppEvent::InitialContainerList(
+            "local".to_string(),
+            vec![create_test_container(
+                "abc123", "nginx", "local", 1.0, 1.0, 0.0, 0.0,
+            )],
+        ));
+        assert_eq!(state.table_state.selected(), Some(0));
+
+        let key = |code| AppEvent::KeyInput(KeyEvent::new(code, KeyModifiers::NONE));
+        state.handle_event(key(KeyCode::Char('/')));
+        state.handle_event(key(KeyCode::Char('z')));
+        assert!(state.sorted_container_keys.is_empty());
+        assert_eq!(state.table_state.selected(), None);
+
+        state.handle_event(key(KeyCode::Backspace));
+        assert_eq!(state.sorted_container_keys.len(), 1);
+        assert_eq!(state.table_state.selected(), Some(0));
+    }
+
+    #[test]
+    fn test_first_running_container_is_selected_when_others_hidden() {
+        let mut state = create_test_app_state();
+        state.handle_event(AppEvent::InitialContainerList(
+            "local".to_string(),
+            vec![create_exited_container("abc123", "stopped")],
+        ));
+        assert_eq!(state.table_state.selected(), None);
+
+        state.handle_event(AppEvent::ContainerCreated(create_test_container(
+            "def456", "nginx", "local", 1.0, 1.0, 0.0, 0.0,
+        )));
+        assert_eq!(state.sorted_container_keys.len(), 1);
+        assert_eq!(state.table_state.selected(), Some(0));
+    }
+
+    #[test]
+    fn test_title_counts_ignore_show_all() {
+        use crate::core::types::TitleCount;
+
+        let mut state = create_test_app_state();
+        state.handle_event(AppEvent::InitialContainerList(
+            "local".to_string(),
+            vec![
+                create_test_container("abc123", "nginx", "local", 1.0, 1.0, 0.0, 0.0),
+                create_exited_container("def456", "stopped"),
+            ],
+        ));
+
+        let styles = UiStyles::default();
+        let mut terminal = Terminal::new(TestBackend::new(100, 10)).unwrap();
+        let mut render_title = |state: &mut AppState| {
+            terminal.draw(|f| render_ui(f, state, &styles)).unwrap();
+            buffer_to_string(terminal.backend().buffer())
+        };
+
+        // Only the running container is listed, but both are counted
+        assert_eq!(state.sorted_container_keys.len(), 1);
+        assert!(render_title(&mut state).contains("1 running / 2 total"));
+
+        state.title_count = TitleCount::Total;
+        assert!(render_title(&mut state).contains("2 total / 1 running"));
+    }
+
+    #[test]
+    fn test_click_header_sorts_by_column() {
+        let mut state = create_test_app_state();
+        populate_containers(&mut state, 3);
+
+        let styles = UiStyles::default();
+        let mut terminal = Terminal::new(TestBackend::new(100, 20)).unwrap();
+        terminal
+            .draw(|f| render_ui(f, &mut state, &styles))
+            .unwrap();
+
+        // The recorded header cell lines up with the rendered "Name" label
+        let (area, _) = *state
+            .header_columns
+            .iter()
+            .find(|(_, col)| *col == Column::Name)
+            .expect("Name column should be in the header");
+        let buffer = terminal.backend().buffer();
+        let label: String = (area.x..area.x + 4)
+            .map(|x| buffer[(x, area.y)].symbol())
+            .collect();
+        assert_eq!(label, "Name");
+
+        state.handle_event(AppEvent::MouseClick(area.x + 1, area.y));
+        assert_eq!(state.sort_state.field, Column::Name);
+        let direction = state.sort_state.direction;
+
+        // Clicking the same header again flips the direction
+        state.handle_event(AppEvent::MouseClick(area.x + 1, area.y));
+        assert_eq!(state.sort_state.direction, direction.toggle());
+
+        // Clicks outside the header are ignored
+        state.handle_event(AppEvent::MouseClick(area.x + 1, area.y + 2));
+        assert_eq!(state.sort_state.field, Column::Name);
+    }
+
+    #[test]
+    fn test_log_follow_pins_new_lines_after_scrolling_up() {
+        use crate::core::types::LogState;
+        use crate::docker::logs::LogEntry;
+
+        let log_line = |i: usize| {
+            LogEntry::parse(&format!("2025-10-29T10:15:{:02}Z Log line {}", i % 60, i)).unwrap()
+        };
+
+        let mut state = create_test_app_state();
+        let styles = UiStyles::default();
+        let key = ContainerKey::new("local".to_string(), "abc123".to_string());
+        state.view_state = ViewState::LogView(key.clone());
+
+        let mut log_state = LogState::new(key.clone());
+        log_state.set_entries((0..50).map(log_line).collect());
+        state.log_state = Some(log_state);
+
+        let mut terminal = Terminal::new(TestBackend::new(80, 20)).unwrap();
+        let mut render = |state: &mut AppState| {
+            terminal.draw(|f| render_ui(f, state, &styles)).unwrap();
+            buffer_to_string(terminal.backend().buffer())
+        };
+        render(&mut state);
+        assert!(state.is_at_bottom);
+
+        // Scrolled up, the view stays put until new lines arrive
+        for _ in 0..10 {
+            state.handle_event(AppEvent::KeyInput(KeyEvent::new(
+                KeyCode::Up,
+                KeyModifiers::NONE,
+            )));
+        }
+        let output = render(&mut state);
+        let output_again = render(&mut state);
+        assert_eq!(output, output_again);
+        assert!(!state.is_at_bottom);
+        assert!(output.contains("[FOLLOW]"), "{}", output);
+        assert!(!output.contains('%'), "{}", output);
+
+        // Following, any new line pins the view to the bottom
+        state.handle_event(AppEvent::LogLine(key.clone(), log_line(50)));
+        let output = render(&mut state);
+        assert!(state.is_at_bottom);
+        assert!(output.contains("Log line 50"));
+
+        // Paused, new lines leave the scrolled-up view alone
+        state.handle_event(AppEvent::KeyInput(KeyEvent::new(
+            KeyCode::Char('F'),
+            KeyModifiers::NONE,
+        )));
+        for _ in 0..10 {
+            state.handle_event(AppEvent::KeyInput(KeyEvent::new(
+                KeyCode::Up,
+                KeyModifiers::NONE,
+            )));
+        }
+        render(&mut state);
+        let offset = state.log_state.as_ref().unwrap().scroll_offset;
+        state.handle_event(AppEvent::LogLine(key.clone(), log_line(51)));
+        let output = render(&mut state);
+        assert!(!state.is_at_bottom);
+        assert_eq!(state.log_state.as_ref().unwrap().scroll_offset, offset);
+        assert!(output.contains("[PAUSED]"), "{}", output);
+    }
+
+    #[test]
+    fn test_connection_progress_screen() {
+        use crate::core::types::HostStatus;
+        use crate::docker::connection::DockerHost;
+
+        let mut state = create_test_app_state();
+        state.start_connecting(vec![
+            "local".to_string(),
+            "server1".to_string(),
+            "server2".to_string(),
+        ]);
+        state
+            .host_status
+            .insert("local".to_string(), HostStatus::Connected);
+        let docker = bollard::Docker::connect_with_http(
+            "tcp://localhost:2375",
+            4,
+            bollard::API_DEFAULT_VERSION,
+        )
+        .unwrap();
+        let mut host = DockerHost::new("local".to_string(), docker, None, HashMap::new());
+        host.api_version = Some("1.41".to_string());
+        state.connected_hosts.insert("local".to_string(), host);
+        state.handle_event(AppEvent::ConnectionError(
+            "server2".to_string(),
+            "Docker daemon ping timeout for host 'ssh://server2' (>10s)".to_string(),
+        ));
+
+        let styles = UiStyles::default();
+        let mut terminal = Terminal::new(TestBackend::new(100, 20)).unwrap();
+        terminal
+            .draw(|f| render_ui(f, &mut state, &styles))
+            .unwrap();
+
+        let output = buffer_to_string(terminal.backend().buffer());
+        assert_snapshot_with_redaction!(output);
+
+        // The first container list replaces the progress screen with the table
+        state.handle_event(AppEvent::InitialContainerList(
+            "local".to_string(),
+            vec![create_test_container(
+                "abc123", "nginx", "local", 1.0, 1.0, 0.0, 0.0,
+            )],
+        ));
+        assert!(!state.connecting);
+    }
+
+    #[test]
+    fn test_pager_action_opens_pager() {
+        use crate::core::types::RenderAction;
+        use crate::docker::connection::DockerHost;
+
+        let docker = bollard::Docker::connect_with_http(
+            "tcp://localhost:2375",
+            4,
+            bollard::API_DEFAULT_VERSION,
+        )
+        .unwrap();
+        let host = DockerHost::new("local".to_string(), docker, None, HashMap::new());
+
+        let mut state = create_test_app_state();
+        state.connected_hosts.insert("local".to_string(), host);
+        let mut container = create_test_container("abc123", "nginx", "local", 0.0, 0.0, 0.0, 0.0);
+        container.state = ContainerState::Exited;
+        state.handle_event(AppEvent::InitialContainerList(
+            "local".to_string(),
+            vec![container],
+        ));
+        state.show_all_containers = true;
+        state.force_sort_containers();
+        state.table_state.select(Some(0));
+        let key = ContainerKey::new("local".to_string(), "abc123".to_string());
+
+        // Stopped containers still have logs: Start, Rename, Remove, then the pager
+        let press = |state: &mut AppState, code| {
+            state.handle_event(AppEvent::KeyInput(KeyEvent::new(code, KeyModifiers::NONE)))
+        };
+        press(&mut state, KeyCode::Enter);
+        press(&mut state, KeyCode::Down);
+        press(&mut state, KeyCode::Down);
+        press(&mut state, KeyCode::Down);
+        match press(&mut state, KeyCode::Enter) {
+            RenderAction::OpenPager(pager_key) => assert_eq!(pager_key, key),
+            other => panic!("expected OpenPager, got {:?}", other),
+        }
+        assert_eq!(state.view_state, ViewState::ContainerList);
+    }
+
+    #[test]
+    fn test_shell_on_confirm_shell_host_asks_first() {
+        use crate::core::types::RenderAction;
+        use crate::docker::connection::DockerHost;
+
+        let docker = bollard::Docker::connect_with_http(
+            "tcp://localhost:2375",
+            4,
+            bollard::API_DEFAULT_VERSION,
+        )
+        .unwrap();
+        let mut host = DockerHost::new("prod".to_string(), docker, None, HashMap::new());
+        host.confirm_shell = true;
+
+        let mut state = create_test_app_state();
+        state.connected_hosts.insert("prod".to_string(), host);
+        state.handle_event(AppEvent::InitialContainerList(
+            "prod".to_string(),
+            vec![create_test_container(
+                "abc123", "nginx", "prod", 1.0, 1.0, 0.0, 0.0,
+            )],
+        ));
+        let key = ContainerKey::new("prod".to_string(), "abc123".to_string());
+        let enter = || AppEvent::KeyInput(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE));
+        let char_key = |c| AppEvent::KeyInput(KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE));
+
+        // Shell is the first action for a running container
+        state.handle_event(enter());
+        assert!(matches!(state.handle_event(enter()), RenderAction::Render));
+        assert_eq!(state.shell_confirm_pending, Some(key.clone()));
+        assert!(state.notification.as_ref().unwrap().0.contains("[prod]"));
+
+        // Anything but `y` cancels
+        assert!(matches!(
+            state.handle_event(char_key('n')),
+            RenderAction::Render
+        ));
+        assert_eq!(state.shell_confirm_pending, None);
+
+        state.handle_event(enter());
+        state.handle_event(enter());
+        match state.handle_event(char_key('y')) {
+            RenderAction::StartShell(shell_key) => assert_eq!(shell_key, key),
+            _ => panic!("expected the shell to start after confirming"),
+        }
+    }
 }